@@ -0,0 +1,823 @@
+use std::{
+    f64::consts::TAU,
+    rc::Rc,
+};
+
+use web_sys::{
+    FocusEvent,
+    MouseEvent,
+};
+use yew::{
+    function_component,
+    html,
+    use_state_eq,
+    AttrValue,
+    Callback,
+    Html,
+    Properties,
+    UseStateHandle,
+};
+
+use crate::types::{
+    locale::Locale,
+    money::Money,
+};
+
+const PALETTE: [&str; 8] = [
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948",
+    "#b07aa1", "#9c755f",
+];
+const PIE_SIZE: f64 = 200.0;
+const PIE_RADIUS: f64 = 90.0;
+const DONUT_RADIUS: f64 = 50.0;
+const WIDTH: f64 = 320.0;
+const HEIGHT: f64 = 200.0;
+const PADDING: f64 = 24.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ChartDatum {
+    pub(crate) label: AttrValue,
+    pub(crate) value: Money,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ChartSeries {
+    pub(crate) label: AttrValue,
+    pub(crate) values: Vec<Money>,
+}
+
+pub(crate) fn color(index: usize) -> &'static str {
+    PALETTE[index % PALETTE.len()]
+}
+
+fn number(value: f64) -> String {
+    format!("{:.2}", (value * 100.0).round() / 100.0 + 0.0)
+}
+
+fn point(angle: f64, radius: f64) -> String {
+    let center = PIE_SIZE / 2.0;
+    format!(
+        "{} {}",
+        number(center + radius * angle.sin()),
+        number(center - radius * angle.cos())
+    )
+}
+
+fn amount(money: Money, locale: Locale) -> String {
+    format!("{} {}", money.format(locale), money.currency)
+}
+
+pub(crate) fn pie_angles(values: &[Money]) -> Vec<(f64, f64)> {
+    let total: f64 = values.iter().map(|v| v.minor.max(0) as f64).sum();
+    let mut start = 0.0;
+    values
+        .iter()
+        .map(|value| {
+            let sweep = match total > 0.0 {
+                true => value.minor.max(0) as f64 / total * TAU,
+                false => 0.0,
+            };
+            let angles = (start, start + sweep);
+            start += sweep;
+            angles
+        })
+        .collect()
+}
+
+pub(crate) fn slice_path(start: f64, end: f64, inner: f64) -> String {
+    let middle = (start + end) / 2.0;
+    let outer = number(PIE_RADIUS);
+    let outer_arc = format!(
+        "M {} A {} {} 0 0 1 {} A {} {} 0 0 1 {}",
+        point(start, PIE_RADIUS),
+        outer,
+        outer,
+        point(middle, PIE_RADIUS),
+        outer,
+        outer,
+        point(end, PIE_RADIUS)
+    );
+    match inner > 0.0 {
+        true => format!(
+            "{} L {} A {} {} 0 0 0 {} A {} {} 0 0 0 {} Z",
+            outer_arc,
+            point(end, inner),
+            number(inner),
+            number(inner),
+            point(middle, inner),
+            number(inner),
+            number(inner),
+            point(start, inner)
+        ),
+        false => format!("{} L {} Z", outer_arc, point(0.0, 0.0)),
+    }
+}
+
+pub(crate) fn share(value: Money, values: &[Money]) -> i64 {
+    let total: i128 = values.iter().map(|v| i128::from(v.minor.max(0))).sum();
+    match total {
+        0 => 0,
+        _ => (value.minor.max(0) as f64 / total as f64 * 100.0).round() as i64,
+    }
+}
+
+pub(crate) fn stack_totals(
+    series: &[ChartSeries],
+    bar_count: usize,
+) -> Option<Vec<i64>> {
+    (0..bar_count)
+        .map(|bar| {
+            series
+                .iter()
+                .filter_map(|series| series.values.get(bar))
+                .try_fold(0_i64, |total, value| {
+                    total.checked_add(value.minor.max(0))
+                })
+        })
+        .collect()
+}
+
+pub(crate) fn line_points(values: &[Money]) -> Vec<(f64, f64)> {
+    let min = values.iter().map(|v| v.minor).min().unwrap_or_default();
+    let max = values.iter().map(|v| v.minor).max().unwrap_or_default();
+    let plot_width = WIDTH - 2.0 * PADDING;
+    let plot_height = HEIGHT - 2.0 * PADDING;
+    let step = match values.len() {
+        0 | 1 => 0.0,
+        n => plot_width / (n - 1) as f64,
+    };
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let x = match values.len() {
+                1 => WIDTH / 2.0,
+                _ => PADDING + step * index as f64,
+            };
+            let y = match max - min {
+                0 => HEIGHT / 2.0,
+                range => {
+                    PADDING
+                        + (max - value.minor) as f64 / range as f64
+                            * plot_height
+                },
+            };
+            (x, y)
+        })
+        .collect()
+}
+
+pub(crate) fn bar_height(value: Money, max: i64) -> f64 {
+    match max {
+        0 => 0.0,
+        _ => value.minor.max(0) as f64 / max as f64 * (HEIGHT - 2.0 * PADDING),
+    }
+}
+
+struct TooltipHandlers {
+    onmouseover: Callback<MouseEvent>,
+    onmouseout: Callback<MouseEvent>,
+    onfocus: Callback<FocusEvent>,
+    onblur: Callback<FocusEvent>,
+}
+
+fn tooltip_handlers(
+    active: &UseStateHandle<Option<usize>>,
+    index: usize,
+) -> TooltipHandlers {
+    let set = |value: Option<usize>| {
+        let active = active.clone();
+        Callback::from(move |_: ()| active.set(value))
+    };
+    TooltipHandlers {
+        onmouseover: set(Some(index)).reform(|_| ()),
+        onmouseout: set(None).reform(|_| ()),
+        onfocus: set(Some(index)).reform(|_| ()),
+        onblur: set(None).reform(|_| ()),
+    }
+}
+
+fn tooltip(id: &AttrValue, text: Option<String>) -> Html {
+    html! {
+        <p id={format!("{}_tooltip", id)} class={"chart-tooltip"} role={"status"}>
+            { text.unwrap_or_default() }
+        </p>
+    }
+}
+
+fn data_table(id: &AttrValue, rows: Vec<(String, String)>) -> Html {
+    html! {
+        <details id={format!("{}_data", id)}>
+            <summary>{ "Show data" }</summary>
+            <table id={format!("{}_table", id)}>
+                <tbody>
+                {
+                    rows.into_iter().map(|(label, value)| html! {
+                        <tr>
+                            <th scope={"row"}>{ label }</th>
+                            <td>{ value }</td>
+                        </tr>
+                    }).collect::<Html>()
+                }
+                </tbody>
+            </table>
+        </details>
+    }
+}
+
+// PIE CHART
+#[derive(Properties, PartialEq)]
+pub(crate) struct PieChartProps {
+    pub(crate) id: AttrValue,
+    pub(crate) title: AttrValue,
+    pub(crate) data: Rc<[ChartDatum]>,
+    #[prop_or_default]
+    pub(crate) donut: bool,
+    #[prop_or_default]
+    pub(crate) locale: Locale,
+}
+
+#[function_component(PieChart)]
+pub(crate) fn pie_chart(props: &PieChartProps) -> Html {
+    let active = use_state_eq(|| None::<usize>);
+    let id = &props.id;
+    let values: Vec<Money> = props.data.iter().map(|d| d.value).collect();
+    let describe = |datum: &ChartDatum| {
+        format!(
+            "{}: {} ({}%)",
+            datum.label,
+            amount(datum.value, props.locale),
+            share(datum.value, &values)
+        )
+    };
+    let description = match props.data.is_empty() {
+        true => String::from("No data"),
+        false => {
+            let parts: Vec<String> = props.data.iter().map(describe).collect();
+            parts.join(", ")
+        },
+    };
+    let inner = if props.donut { DONUT_RADIUS } else { 0.0 };
+    let size = number(PIE_SIZE);
+
+    html! {
+        <figure id={id.clone()} class={"chart"}>
+            <svg
+                id={format!("{}_svg", id)}
+                viewBox={format!("0 0 {} {}", size, size)}
+                role={"img"}
+                aria-labelledby={format!("{}_title {}_desc", id, id)}
+            >
+                <title id={format!("{}_title", id)}>{ props.title.clone() }</title>
+                <desc id={format!("{}_desc", id)}>{ description }</desc>
+                {
+                    props.data.iter().zip(pie_angles(&values)).enumerate()
+                        .filter(|(_, (_, (start, end)))| end > start)
+                        .map(|(index, (datum, (start, end)))| {
+                            let TooltipHandlers { onmouseover, onmouseout, onfocus, onblur } = tooltip_handlers(&active, index);
+                            html! {
+                                <path
+                                    id={format!("{}_slice_{}", id, index)}
+                                    d={slice_path(start, end, inner)}
+                                    fill={color(index)}
+                                    tabindex={"0"}
+                                    {onmouseover}
+                                    {onmouseout}
+                                    {onfocus}
+                                    {onblur}
+                                >
+                                    <title>{ describe(datum) }</title>
+                                </path>
+                            }
+                        }).collect::<Html>()
+                }
+            </svg>
+            { tooltip(id, active.and_then(|i| props.data.get(i)).map(describe)) }
+            { data_table(id, props.data.iter().map(|d| (d.label.to_string(), amount(d.value, props.locale))).collect()) }
+        </figure>
+    }
+}
+
+// STACKED BAR CHART
+#[derive(Properties, PartialEq)]
+pub(crate) struct StackedBarChartProps {
+    pub(crate) id: AttrValue,
+    pub(crate) title: AttrValue,
+    pub(crate) labels: Rc<[AttrValue]>,
+    pub(crate) series: Rc<[ChartSeries]>,
+    #[prop_or_default]
+    pub(crate) locale: Locale,
+}
+
+#[function_component(StackedBarChart)]
+pub(crate) fn stacked_bar_chart(props: &StackedBarChartProps) -> Html {
+    let active = use_state_eq(|| None::<usize>);
+    let id = &props.id;
+    let bar_count = props.labels.len();
+    let series_count = props.series.len();
+    let value_at =
+        |series: &ChartSeries, bar: usize| series.values.get(bar).copied();
+    let Some(totals) = stack_totals(&props.series, bar_count) else {
+        return html! {
+            <figure id={id.clone()} class={"chart"}>
+                <p id={format!("{}_error", id)} role={"alert"}>
+                    { "Totals are too large to chart" }
+                </p>
+            </figure>
+        };
+    };
+    let max = totals.iter().copied().max().unwrap_or_default();
+    let describe = |bar: usize, series: &ChartSeries| {
+        value_at(series, bar).map(|value| {
+            format!(
+                "{}, {}: {}",
+                props.labels[bar],
+                series.label,
+                amount(value, props.locale)
+            )
+        })
+    };
+    let description = match bar_count {
+        0 => String::from("No data"),
+        _ => format!(
+            "{} bars from {} to {} stacked by {}",
+            bar_count,
+            props.labels[0],
+            props.labels[bar_count - 1],
+            props
+                .series
+                .iter()
+                .map(|s| s.label.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let slot = (WIDTH - 2.0 * PADDING) / bar_count.max(1) as f64;
+    let bar_width = slot * 0.6;
+    let bottom = HEIGHT - PADDING;
+
+    let mut bars = Vec::new();
+    for bar in 0..bar_count {
+        let x = PADDING + slot * bar as f64 + (slot - bar_width) / 2.0;
+        let mut y = bottom;
+        for (index, series) in props.series.iter().enumerate() {
+            let Some(value) = value_at(series, bar) else {
+                continue;
+            };
+            let height = bar_height(value, max);
+            y -= height;
+            let TooltipHandlers {
+                onmouseover,
+                onmouseout,
+                onfocus,
+                onblur,
+            } = tooltip_handlers(&active, bar * series_count + index);
+            bars.push(html! {
+                <rect
+                    id={format!("{}_bar_{}_{}", id, bar, index)}
+                    x={number(x)}
+                    y={number(y)}
+                    width={number(bar_width)}
+                    height={number(height)}
+                    fill={color(index)}
+                    tabindex={"0"}
+                    {onmouseover}
+                    {onmouseout}
+                    {onfocus}
+                    {onblur}
+                >
+                    <title>{ describe(bar, series).unwrap_or_default() }</title>
+                </rect>
+            });
+        }
+        bars.push(html! {
+            <text
+                x={number(x + bar_width / 2.0)}
+                y={number(HEIGHT - PADDING / 3.0)}
+                text-anchor={"middle"}
+                font-size={"10"}
+            >
+                { props.labels[bar].clone() }
+            </text>
+        });
+    }
+    let active_text = active.and_then(|i| {
+        let series = props.series.get(i % series_count.max(1))?;
+        describe(i / series_count.max(1), series)
+    });
+    let rows = (0..bar_count)
+        .flat_map(|bar| {
+            props.series.iter().filter_map(move |series| {
+                let value = value_at(series, bar)?;
+                Some((
+                    format!("{}, {}", props.labels[bar], series.label),
+                    amount(value, props.locale),
+                ))
+            })
+        })
+        .collect();
+
+    html! {
+        <figure id={id.clone()} class={"chart"}>
+            <svg
+                id={format!("{}_svg", id)}
+                viewBox={format!("0 0 {} {}", number(WIDTH), number(HEIGHT))}
+                role={"img"}
+                aria-labelledby={format!("{}_title {}_desc", id, id)}
+            >
+                <title id={format!("{}_title", id)}>{ props.title.clone() }</title>
+                <desc id={format!("{}_desc", id)}>{ description }</desc>
+                <line
+                    x1={number(PADDING)}
+                    y1={number(bottom)}
+                    x2={number(WIDTH - PADDING)}
+                    y2={number(bottom)}
+                    stroke={"currentColor"}
+                />
+                { for bars }
+            </svg>
+            <ul id={format!("{}_legend", id)} class={"chart-legend"}>
+            {
+                props.series.iter().enumerate().map(|(index, series)| html! {
+                    <li style={format!("color: {}", color(index))}>{ series.label.clone() }</li>
+                }).collect::<Html>()
+            }
+            </ul>
+            { tooltip(id, active_text) }
+            { data_table(id, rows) }
+        </figure>
+    }
+}
+
+// LINE CHART
+#[derive(Properties, PartialEq)]
+pub(crate) struct LineChartProps {
+    pub(crate) id: AttrValue,
+    pub(crate) title: AttrValue,
+    pub(crate) data: Rc<[ChartDatum]>,
+    #[prop_or_default]
+    pub(crate) locale: Locale,
+}
+
+#[function_component(LineChart)]
+pub(crate) fn line_chart(props: &LineChartProps) -> Html {
+    let active = use_state_eq(|| None::<usize>);
+    let id = &props.id;
+    let values: Vec<Money> = props.data.iter().map(|d| d.value).collect();
+    let points = line_points(&values);
+    let describe = |datum: &ChartDatum| {
+        format!("{}: {}", datum.label, amount(datum.value, props.locale))
+    };
+    let lowest = props.data.iter().min_by_key(|d| d.value.minor);
+    let highest = props.data.iter().max_by_key(|d| d.value.minor);
+    let description =
+        match (props.data.first(), props.data.last(), lowest, highest) {
+            (Some(first), Some(last), Some(lowest), Some(highest)) => format!(
+                "From {} to {}, lowest {}, highest {}",
+                describe(first),
+                describe(last),
+                describe(lowest),
+                describe(highest)
+            ),
+            _ => String::from("No data"),
+        };
+    let polyline: Vec<String> = points
+        .iter()
+        .map(|(x, y)| format!("{},{}", number(*x), number(*y)))
+        .collect();
+
+    html! {
+        <figure id={id.clone()} class={"chart"}>
+            <svg
+                id={format!("{}_svg", id)}
+                viewBox={format!("0 0 {} {}", number(WIDTH), number(HEIGHT))}
+                role={"img"}
+                aria-labelledby={format!("{}_title {}_desc", id, id)}
+            >
+                <title id={format!("{}_title", id)}>{ props.title.clone() }</title>
+                <desc id={format!("{}_desc", id)}>{ description }</desc>
+                <polyline
+                    id={format!("{}_line", id)}
+                    points={polyline.join(" ")}
+                    fill={"none"}
+                    stroke={color(0)}
+                    stroke-width={"2"}
+                />
+                {
+                    props.data.iter().zip(points.iter()).enumerate().map(|(index, (datum, (x, y)))| {
+                        let TooltipHandlers { onmouseover, onmouseout, onfocus, onblur } = tooltip_handlers(&active, index);
+                        html! {
+                            <circle
+                                id={format!("{}_point_{}", id, index)}
+                                cx={number(*x)}
+                                cy={number(*y)}
+                                r={"3"}
+                                fill={color(0)}
+                                tabindex={"0"}
+                                {onmouseover}
+                                {onmouseout}
+                                {onfocus}
+                                {onblur}
+                            >
+                                <title>{ describe(datum) }</title>
+                            </circle>
+                        }
+                    }).collect::<Html>()
+                }
+            </svg>
+            { tooltip(id, active.and_then(|i| props.data.get(i)).map(describe)) }
+            { data_table(id, props.data.iter().map(|d| (d.label.to_string(), amount(d.value, props.locale))).collect()) }
+        </figure>
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        f64::consts::PI,
+        rc::Rc,
+        time::Duration,
+    };
+
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use web_sys::{
+        Event,
+        EventInit,
+    };
+    use yew::{
+        function_component,
+        html,
+        AttrValue,
+        Html,
+    };
+
+    use super::{
+        bar_height,
+        line_points,
+        pie_angles,
+        share,
+        slice_path,
+        stack_totals,
+        ChartDatum,
+        ChartSeries,
+        LineChart,
+        PieChart,
+        StackedBarChart,
+    };
+    use crate::{
+        dom::DOM,
+        types::money::{
+            eur,
+            Money,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn make_data(values: &[(&'static str, i64)]) -> Rc<[ChartDatum]> {
+        values
+            .iter()
+            .map(|&(label, minor)| ChartDatum {
+                label: AttrValue::from(label),
+                value: eur(minor),
+            })
+            .collect()
+    }
+
+    async fn dispatch_event(id: &str, event_type: &str) {
+        let mut init = EventInit::new();
+        init.bubbles(true);
+        let event = Event::new_with_event_init_dict(event_type, &init)
+            .expect("valid event");
+        DOM::get_element_by_id(id)
+            .expect("Element to exist")
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    #[function_component(TestCharts)]
+    fn test_charts() -> Html {
+        let labels: Rc<[AttrValue]> =
+            Rc::from([AttrValue::from("May"), AttrValue::from("June")]);
+        let series: Rc<[ChartSeries]> = Rc::from([
+            ChartSeries {
+                label: AttrValue::from("Food"),
+                values: vec![eur(3000), eur(1000)],
+            },
+            ChartSeries {
+                label: AttrValue::from("Rent"),
+                values: vec![eur(1000), eur(1000)],
+            },
+        ]);
+        html! {
+            <>
+                <PieChart
+                    id={"pie"}
+                    title={"Spending by category"}
+                    data={make_data(&[("Food", 7500), ("Rent", 2500)])}
+                    donut={true}
+                />
+                <StackedBarChart
+                    id={"bars"}
+                    title={"Spending by month"}
+                    {labels}
+                    {series}
+                />
+                <LineChart
+                    id={"line"}
+                    title={"Running balance"}
+                    data={make_data(&[("June 1", 0), ("June 2", 1000), ("June 3", -1000)])}
+                />
+            </>
+        }
+    }
+
+    async fn render_charts() {
+        let output = DOM::get_element_by_id("output")
+            .expect("Element `output` to exist");
+        yew::Renderer::<TestCharts>::with_root(output).render();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    fn attribute(id: &str, name: &str) -> Option<String> {
+        DOM::get_element_by_id(id)?.get_attribute(name)
+    }
+
+    #[wasm_bindgen_test]
+    fn pie_angles_are_proportional() {
+        let angles = pie_angles(&[eur(100), eur(0), eur(300), eur(-50)]);
+
+        assert_eq!(
+            angles,
+            vec![
+                (0.0, PI / 2.0),
+                (PI / 2.0, PI / 2.0),
+                (PI / 2.0, 2.0 * PI),
+                (2.0 * PI, 2.0 * PI)
+            ]
+        );
+        assert_eq!(pie_angles(&[eur(0)]), vec![(0.0, 0.0)]);
+    }
+
+    #[wasm_bindgen_test]
+    fn slice_path_is_deterministic() {
+        let tests = vec![
+            (
+                (0.0, PI / 2.0, 0.0),
+                "M 100.00 10.00 A 90.00 90.00 0 0 1 163.64 36.36 A 90.00 \
+                 90.00 0 0 1 190.00 100.00 L 100.00 100.00 Z",
+            ),
+            (
+                (0.0, 2.0 * PI, 50.0),
+                "M 100.00 10.00 A 90.00 90.00 0 0 1 100.00 190.00 A 90.00 \
+                 90.00 0 0 1 100.00 10.00 L 100.00 50.00 A 50.00 50.00 0 0 \
+                 0 100.00 150.00 A 50.00 50.00 0 0 0 100.00 50.00 Z",
+            ),
+        ];
+
+        for ((start, end, inner), expected) in tests {
+            assert_eq!(slice_path(start, end, inner), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn share_rounds_to_whole_percent() {
+        let values = [eur(1), eur(1), eur(1)];
+        let tests = vec![(eur(1), 33), (eur(0), 0), (eur(-1), 0)];
+
+        for (value, expected) in tests {
+            assert_eq!(share(value, &values), expected);
+        }
+        assert_eq!(share(eur(1), &[]), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn share_does_not_overflow_with_large_values() {
+        let values = [eur(i64::MAX), eur(i64::MAX)];
+
+        assert_eq!(share(eur(i64::MAX), &values), 50);
+    }
+
+    #[wasm_bindgen_test]
+    fn stack_totals_are_none_on_overflow() {
+        let series = |values: Vec<Money>| ChartSeries {
+            label: AttrValue::from("Test"),
+            values,
+        };
+        let tests = vec![
+            (vec![series(vec![eur(1), eur(2)])], Some(vec![1, 2])),
+            (
+                vec![series(vec![eur(1), eur(-5)]), series(vec![eur(3)])],
+                Some(vec![4, 0]),
+            ),
+            (vec![series(vec![eur(i64::MAX)]), series(vec![eur(1)])], None),
+            (vec![], Some(vec![0, 0])),
+        ];
+
+        for (series, expected) in tests {
+            assert_eq!(stack_totals(&series, 2), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn line_points_span_plot_area() {
+        let tests = vec![
+            (
+                vec![eur(0), eur(1000), eur(-1000)],
+                vec![(24.0, 100.0), (160.0, 24.0), (296.0, 176.0)],
+            ),
+            (vec![eur(500)], vec![(160.0, 100.0)]),
+            (vec![eur(5), eur(5)], vec![(24.0, 100.0), (296.0, 100.0)]),
+            (vec![], vec![]),
+        ];
+
+        for (values, expected) in tests {
+            assert_eq!(line_points(&values), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn bar_height_is_relative_to_max() {
+        let tests = vec![
+            (eur(4000), 4000, 152.0),
+            (eur(1000), 4000, 38.0),
+            (eur(-1000), 4000, 0.0),
+            (eur(1000), 0, 0.0),
+        ];
+
+        for (value, max, expected) in tests {
+            assert_eq!(bar_height(value, max), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn charts_have_text_alternatives() {
+        render_charts().await;
+
+        let tests = vec![
+            ("pie", "Food: 75.00 EUR (75%), Rent: 25.00 EUR (25%)"),
+            ("bars", "2 bars from May to June stacked by Food, Rent"),
+            (
+                "line",
+                "From June 1: 0.00 EUR to June 3: -10.00 EUR, lowest June 3: \
+                 -10.00 EUR, highest June 2: 10.00 EUR",
+            ),
+        ];
+
+        for (id, description) in tests {
+            let svg_id = format!("{}_svg", id);
+            let desc = DOM::get_element_by_id(&format!("{}_desc", id))
+                .expect("Description to exist");
+
+            assert_eq!(attribute(&svg_id, "role"), Some("img".to_string()));
+            assert_eq!(
+                attribute(&svg_id, "aria-labelledby"),
+                Some(format!("{}_title {}_desc", id, id))
+            );
+            assert_eq!(desc.text_content(), Some(description.to_string()));
+            assert!(DOM::get_element_by_id(&format!("{}_table", id)).is_some());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn charts_render_deterministic_shapes() {
+        render_charts().await;
+
+        assert_eq!(
+            attribute("pie_slice_1", "d"),
+            Some(slice_path(1.5 * PI, 2.0 * PI, 50.0))
+        );
+        assert_eq!(attribute("bars_bar_0_0", "height"), Some("114.00".into()));
+        assert_eq!(attribute("bars_bar_0_1", "y"), Some("24.00".into()));
+        assert_eq!(attribute("bars_bar_1_1", "height"), Some("38.00".into()));
+        assert_eq!(
+            attribute("line_line", "points"),
+            Some("24.00,100.00 160.00,24.00 296.00,176.00".into())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn tooltip_follows_hovered_shape() {
+        render_charts().await;
+
+        let tooltip = || {
+            DOM::get_element_by_id("bars_tooltip")
+                .and_then(|element| element.text_content())
+                .unwrap_or_default()
+        };
+
+        assert_eq!(tooltip(), "");
+
+        dispatch_event("bars_bar_1_0", "mouseover").await;
+
+        assert_eq!(tooltip(), "June, Food: 10.00 EUR");
+
+        dispatch_event("bars_bar_1_0", "mouseout").await;
+
+        assert_eq!(tooltip(), "");
+    }
+}
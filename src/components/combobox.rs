@@ -0,0 +1,773 @@
+use std::rc::Rc;
+
+use web_sys::{
+    FocusEvent,
+    HtmlInputElement,
+    InputEvent,
+    KeyboardEvent,
+    MouseEvent,
+};
+use yew::{
+    function_component,
+    html,
+    use_effect_with_deps,
+    use_state,
+    use_state_eq,
+    AttrValue,
+    Callback,
+    Html,
+    Properties,
+    TargetCast,
+};
+
+use crate::types::select::SelectOption;
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct ComboboxProps {
+    pub(crate) id: AttrValue,
+    pub(crate) label: AttrValue,
+    pub(crate) options: Rc<[SelectOption]>,
+    #[prop_or_default]
+    pub(crate) placeholder: Option<AttrValue>,
+    #[prop_or_default]
+    pub(crate) onselect: Option<Callback<AttrValue>>,
+    #[prop_or_default]
+    pub(crate) oncreate: Option<Callback<AttrValue>>,
+    #[prop_or_default]
+    pub(crate) onkeydown: Option<Callback<KeyboardEvent>>,
+    #[prop_or_default]
+    pub(crate) clear_on_choose: bool,
+    #[prop_or_default]
+    pub(crate) error: Option<AttrValue>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ComboboxItem {
+    Option(SelectOption),
+    Create(AttrValue),
+}
+
+impl ComboboxItem {
+    fn text(&self) -> String {
+        match self {
+            Self::Option(option) => option.text(),
+            Self::Create(name) => format!("Create ‘{}’", name),
+        }
+    }
+
+    fn text_without_action(&self) -> String {
+        match self {
+            Self::Option(option) => option.text(),
+            Self::Create(name) => name.to_string(),
+        }
+    }
+}
+
+#[function_component(Combobox)]
+pub(crate) fn combobox(props: &ComboboxProps) -> Html {
+    let query = use_state(|| selected_text(&props.options).unwrap_or_default());
+    // Text of the chosen item, restored when the input loses focus
+    let chosen =
+        use_state(|| selected_text(&props.options).unwrap_or_default());
+    let expanded = use_state_eq(|| false);
+    let active = use_state_eq(|| None::<usize>);
+    {
+        let query = query.clone();
+        let chosen = chosen.clone();
+        use_effect_with_deps(
+            move |options| {
+                if let Some(text) = selected_text(options) {
+                    query.set(text.clone());
+                    chosen.set(text);
+                }
+            },
+            props.options.clone(),
+        );
+    }
+
+    let items = Rc::new(combobox_items(
+        &props.options,
+        &query,
+        props.oncreate.is_some(),
+    ));
+    let listbox_id = format!("{}_listbox", props.id);
+    let option_id = {
+        let id = props.id.clone();
+        move |index: usize| format!("{}_option_{}", id, index)
+    };
+
+    let choose = {
+        let query = query.clone();
+        let chosen = chosen.clone();
+        let expanded = expanded.clone();
+        let active = active.clone();
+        let onselect = props.onselect.clone();
+        let oncreate = props.oncreate.clone();
+        let clear_on_choose = props.clear_on_choose;
+
+        Callback::from(move |item: ComboboxItem| {
+            expanded.set(false);
+            active.set(None);
+            let text = match clear_on_choose {
+                true => String::default(),
+                false => item.text_without_action(),
+            };
+            query.set(text.clone());
+            chosen.set(text);
+            match item {
+                ComboboxItem::Option(option) => {
+                    if let Some(onselect) = &onselect {
+                        onselect.emit(option.value);
+                    }
+                },
+                ComboboxItem::Create(name) => {
+                    if let Some(oncreate) = &oncreate {
+                        oncreate.emit(name);
+                    }
+                },
+            }
+        })
+    };
+
+    let oninput = {
+        let query = query.clone();
+        let expanded = expanded.clone();
+        let active = active.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                query.set(input.value());
+                expanded.set(true);
+                active.set(None);
+            }
+        })
+    };
+
+    let onkeydown = {
+        let query = query.clone();
+        let expanded = expanded.clone();
+        let active = active.clone();
+        let items = items.clone();
+        let choose = choose.clone();
+        let onkeydown = props.onkeydown.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if let Some(onkeydown) = &onkeydown {
+                onkeydown.emit(e.clone());
+            }
+            match e.key().as_str() {
+                "ArrowDown" | "ArrowUp" => {
+                    e.prevent_default();
+                    let forward = e.key() == "ArrowDown";
+                    expanded.set(true);
+                    active.set(move_active(*active, items.len(), forward));
+                },
+                "Enter" if *expanded => {
+                    let item = active
+                        .and_then(|i| items.get(i))
+                        .or_else(|| default_item(&items, &query));
+                    if let Some(item) = item {
+                        e.prevent_default();
+                        choose.emit(item.clone());
+                    } else if !query.trim().is_empty() {
+                        e.prevent_default();
+                    }
+                },
+                "Escape" if *expanded => {
+                    expanded.set(false);
+                    active.set(None);
+                },
+                "Escape" => query.set(String::default()),
+                _ => {},
+            }
+        })
+    };
+
+    let onfocus = {
+        let expanded = expanded.clone();
+        Callback::from(move |_: FocusEvent| expanded.set(true))
+    };
+
+    let onblur = {
+        let query = query.clone();
+        let expanded = expanded.clone();
+        let active = active.clone();
+        Callback::from(move |_: FocusEvent| {
+            expanded.set(false);
+            active.set(None);
+            query.set((*chosen).clone());
+        })
+    };
+
+    let is_open = *expanded && !items.is_empty();
+    let error_id = format!("{}_error", props.id);
+    let is_invalid = props.error.is_some();
+
+    html! {
+        <section id={format!("{}_section", props.id)}>
+            <label
+                id={format!("{}_label", props.id)}
+                for={props.id.clone()}
+            >
+                { props.label.clone() }
+            </label>
+            <input
+                id={props.id.clone()}
+                type="text"
+                role="combobox"
+                autocomplete="off"
+                aria-autocomplete="list"
+                aria-expanded={is_open.to_string()}
+                aria-controls={listbox_id.clone()}
+                aria-activedescendant={active.map(&option_id)}
+                aria-invalid={is_invalid.then_some("true")}
+                aria-describedby={is_invalid.then_some(error_id.clone())}
+                placeholder={props.placeholder.clone()}
+                value={(*query).clone()}
+                {oninput}
+                {onkeydown}
+                {onfocus}
+                {onblur}
+            />
+            if is_open {
+                <ul
+                    id={listbox_id}
+                    role="listbox"
+                    aria-labelledby={format!("{}_label", props.id)}
+                >
+                {
+                    items.iter().enumerate().map(|(index, item)| {
+                        let onmousedown = {
+                            let choose = choose.clone();
+                            let item = item.clone();
+                            Callback::from(move |e: MouseEvent| {
+                                e.prevent_default();
+                                choose.emit(item.clone());
+                            })
+                        };
+                        let selected = *active == Some(index);
+                        html! {
+                            <li
+                                id={option_id(index)}
+                                role="option"
+                                aria-selected={selected.to_string()}
+                                {onmousedown}
+                            >
+                                { item.text() }
+                            </li>
+                        }
+                    }).collect::<Html>()
+                }
+                </ul>
+            }
+            if let Some(error) = props.error.clone() {
+                <p id={error_id} class={"error"}>{ error }</p>
+            }
+        </section>
+    }
+}
+
+fn selected_text(options: &[SelectOption]) -> Option<String> {
+    options
+        .iter()
+        .find(|option| option.selected)
+        .map(|option| option.text())
+}
+
+fn combobox_items(
+    options: &[SelectOption],
+    query: &str,
+    creatable: bool,
+) -> Vec<ComboboxItem> {
+    let query = query.trim();
+    let needle = query.to_lowercase();
+    let mut items: Vec<ComboboxItem> = options
+        .iter()
+        .filter(|option| !option.disabled)
+        .filter(|option| option.text().to_lowercase().contains(&needle))
+        .cloned()
+        .map(ComboboxItem::Option)
+        .collect();
+
+    let exists = options
+        .iter()
+        .any(|option| option.text().to_lowercase() == needle);
+    if creatable && !query.is_empty() && !exists {
+        items.push(ComboboxItem::Create(AttrValue::from(query.to_owned())));
+    }
+    items
+}
+
+fn default_item<'a>(
+    items: &'a [ComboboxItem],
+    query: &str,
+) -> Option<&'a ComboboxItem> {
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return None;
+    }
+    let exact = items.iter().find(|item| match item {
+        ComboboxItem::Option(option) => option.text().to_lowercase() == needle,
+        ComboboxItem::Create(_) => false,
+    });
+    let create = || {
+        items
+            .iter()
+            .find(|item| matches!(item, ComboboxItem::Create(_)))
+    };
+    exact.or_else(create).or_else(|| items.first())
+}
+
+fn move_active(
+    active: Option<usize>,
+    length: usize,
+    forward: bool,
+) -> Option<usize> {
+    match (active, forward) {
+        _ if length == 0 => None,
+        (None, true) => Some(0),
+        (None, false) => Some(length - 1),
+        (Some(index), true) => Some((index + 1) % length),
+        (Some(0), false) => Some(length - 1),
+        (Some(index), false) => Some(index - 1),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+        time::Duration,
+    };
+
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use web_sys::{
+        Event,
+        HtmlInputElement,
+        KeyboardEvent,
+        KeyboardEventInit,
+    };
+    use yew::{
+        AttrValue,
+        Callback,
+    };
+
+    use super::{
+        combobox_items,
+        default_item,
+        move_active,
+        Combobox,
+        ComboboxItem,
+        ComboboxProps,
+    };
+    use crate::{
+        dom::DOM,
+        types::select::SelectOption,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    async fn render_combobox(props: ComboboxProps) {
+        let output = DOM::get_element_by_id("output")
+            .expect("Element `output` to exist");
+        yew::Renderer::<Combobox>::with_root_and_props(output, props).render();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    fn combobox_props_with_id(id: &str) -> ComboboxProps {
+        ComboboxProps {
+            id: AttrValue::from(id.to_owned()),
+            label: AttrValue::from(""),
+            options: ["Groceries", "Restaurants", "Rent", "Travel"]
+                .into_iter()
+                .map(SelectOption::from)
+                .collect(),
+            placeholder: None,
+            onselect: None,
+            oncreate: None,
+            onkeydown: None,
+            clear_on_choose: false,
+            error: None,
+        }
+    }
+
+    fn get_combobox_input(id: &str) -> HtmlInputElement {
+        DOM::get_input_by_id(id)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input")
+    }
+
+    fn get_listbox_options(id: &str) -> Vec<String> {
+        let listbox = DOM::get_element_by_id(&format!("{}_listbox", id));
+        let mut options = vec![];
+        if let Some(listbox) = listbox {
+            let children = listbox.children();
+            for index in 0..children.length() {
+                let child = children.item(index).expect("Element to exist");
+                options.push(child.inner_html());
+            }
+        }
+        options
+    }
+
+    async fn type_query(target: &HtmlInputElement, query: &str) {
+        target.set_value(query);
+        let event = Event::new("input").expect("valid event");
+        target
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    async fn dispatch_blur_event(target: &HtmlInputElement) {
+        let event = Event::new("blur").expect("valid event");
+        target
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    async fn dispatch_keydown_event(target: &HtmlInputElement, key: &str) {
+        let mut init = KeyboardEventInit::new();
+        init.key(key);
+        let event =
+            KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init)
+                .expect("valid event");
+        target
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    static TEST_ID: &str = "test_combobox";
+
+    #[wasm_bindgen_test]
+    fn combobox_items_are_filtered_by_query() {
+        let options: Vec<SelectOption> =
+            ["Groceries", "Restaurants", "Rent", "Travel"]
+                .into_iter()
+                .map(SelectOption::from)
+                .collect();
+        let option = |s: &str| ComboboxItem::Option(SelectOption::from(s));
+        let create =
+            |s: &str| ComboboxItem::Create(AttrValue::from(s.to_owned()));
+
+        let tests = vec![
+            (
+                "",
+                false,
+                options.iter().cloned().map(ComboboxItem::Option).collect(),
+            ),
+            ("re", false, vec![option("Restaurants"), option("Rent")]),
+            ("  RE", false, vec![option("Restaurants"), option("Rent")]),
+            ("tra", false, vec![option("Travel")]),
+            ("ies", false, vec![option("Groceries")]),
+            ("xyz", false, vec![]),
+            ("xyz", true, vec![create("xyz")]),
+            (" Tr ", true, vec![option("Travel"), create("Tr")]),
+            ("rent", true, vec![option("Rent")]),
+            (
+                "",
+                true,
+                options.iter().cloned().map(ComboboxItem::Option).collect(),
+            ),
+        ];
+
+        for (query, creatable, expected) in tests {
+            assert_eq!(combobox_items(&options, query, creatable), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn combobox_items_skip_disabled_options() {
+        let options = vec![
+            SelectOption::from("Rent").disabled(true),
+            SelectOption::from("Restaurants"),
+        ];
+        let expected =
+            vec![ComboboxItem::Option(SelectOption::from("Restaurants"))];
+
+        assert_eq!(combobox_items(&options, "re", false), expected);
+    }
+
+    #[wasm_bindgen_test]
+    fn default_item_prefers_exact_match_then_create_then_first() {
+        let options: Vec<SelectOption> = ["Food", "Food court", "Rent"]
+            .into_iter()
+            .map(SelectOption::from)
+            .collect();
+        let tests = vec![
+            ("food", Some("Food")),
+            ("foo", Some("Create ‘foo’")),
+            ("ren", Some("Create ‘ren’")),
+            ("  ", None),
+        ];
+
+        for (query, expected) in tests {
+            let items = combobox_items(&options, query, true);
+            let item = default_item(&items, query).map(ComboboxItem::text);
+            assert_eq!(item.as_deref(), expected, "{}", query);
+        }
+
+        let items = combobox_items(&options, "ren", false);
+        assert_eq!(
+            default_item(&items, "ren").map(ComboboxItem::text),
+            Some(String::from("Rent"))
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn combobox_items_match_group_path() {
+        let options = vec![
+            SelectOption::from("Food"),
+            SelectOption {
+                value: AttrValue::from("Food › Groceries"),
+                inner_html: AttrValue::from("Groceries"),
+                group: Some(AttrValue::from("Food")),
+                ..Default::default()
+            },
+            SelectOption::from("Rent"),
+        ];
+        let items: Vec<String> = combobox_items(&options, "food", true)
+            .iter()
+            .map(|item| item.text())
+            .collect();
+
+        assert_eq!(items, vec!["Food", "Food › Groceries"]);
+    }
+
+    #[wasm_bindgen_test]
+    fn move_active_wraps_around() {
+        let tests = vec![
+            ((None, 3, true), Some(0)),
+            ((None, 3, false), Some(2)),
+            ((Some(0), 3, true), Some(1)),
+            ((Some(2), 3, true), Some(0)),
+            ((Some(0), 3, false), Some(2)),
+            ((Some(2), 3, false), Some(1)),
+            ((None, 0, true), None),
+            ((Some(1), 0, false), None),
+        ];
+
+        for ((active, length, forward), expected) in tests {
+            assert_eq!(move_active(active, length, forward), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_has_combobox_role_and_label() {
+        let mut props = combobox_props_with_id(TEST_ID);
+        props.label = AttrValue::from("Category");
+        render_combobox(props).await;
+
+        let element = get_combobox_input(TEST_ID);
+        let label =
+            DOM::get_label_by_for(TEST_ID).expect("Label Element to exist");
+
+        assert_eq!(element.get_attribute("role"), Some("combobox".into()));
+        assert_eq!(
+            element.get_attribute("aria-controls"),
+            Some(format!("{}_listbox", TEST_ID))
+        );
+        assert_eq!(
+            element.get_attribute("aria-expanded"),
+            Some("false".into())
+        );
+        assert_eq!(&label.inner_html(), "Category");
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_is_described_by_given_error() {
+        let mut props = combobox_props_with_id(TEST_ID);
+        props.error = Some(AttrValue::from("This field is required"));
+        render_combobox(props).await;
+
+        let element = get_combobox_input(TEST_ID);
+        let error_id = format!("{}_error", TEST_ID);
+        let error =
+            DOM::get_element_by_id(&error_id).expect("Error Element to exist");
+
+        assert_eq!(element.get_attribute("aria-invalid"), Some("true".into()));
+        assert_eq!(element.get_attribute("aria-describedby"), Some(error_id));
+        assert_eq!(&error.inner_html(), "This field is required");
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_has_selected_option_as_value() {
+        let mut props = combobox_props_with_id(TEST_ID);
+        props.options = ["Groceries", "Rent"]
+            .into_iter()
+            .map(|s| SelectOption::from(s).selected(s == "Rent"))
+            .collect();
+        render_combobox(props).await;
+
+        assert_eq!(get_combobox_input(TEST_ID).value(), "Rent");
+    }
+
+    #[wasm_bindgen_test]
+    async fn query_is_reset_to_selected_option_on_blur_event() {
+        let tests = vec![
+            (Some("Rent"), "gro", "Rent"),
+            (Some("Rent"), "", "Rent"),
+            (None, "gro", ""),
+        ];
+
+        for (selected, query, expected) in tests {
+            let mut props = combobox_props_with_id(TEST_ID);
+            props.options = ["Groceries", "Rent"]
+                .into_iter()
+                .map(|s| SelectOption::from(s).selected(Some(s) == selected))
+                .collect();
+            render_combobox(props).await;
+
+            let element = get_combobox_input(TEST_ID);
+            type_query(&element, query).await;
+            dispatch_blur_event(&element).await;
+
+            assert_eq!(element.value(), expected);
+            assert_eq!(
+                element.get_attribute("aria-expanded"),
+                Some("false".into())
+            );
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn query_is_reset_to_chosen_option_on_blur_event() {
+        let props = combobox_props_with_id(TEST_ID);
+        render_combobox(props).await;
+
+        let element = get_combobox_input(TEST_ID);
+        type_query(&element, "rent").await;
+        dispatch_keydown_event(&element, "Enter").await;
+        type_query(&element, "tra").await;
+        dispatch_blur_event(&element).await;
+
+        assert_eq!(element.value(), "Rent");
+    }
+
+    #[wasm_bindgen_test]
+    async fn listbox_is_filtered_on_input_event() {
+        let props = combobox_props_with_id(TEST_ID);
+        render_combobox(props).await;
+
+        let element = get_combobox_input(TEST_ID);
+        type_query(&element, "re").await;
+
+        assert_eq!(element.get_attribute("aria-expanded"), Some("true".into()));
+        assert_eq!(get_listbox_options(TEST_ID), vec!["Restaurants", "Rent"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn keyboard_navigation_selects_active_option() {
+        let selected = Rc::new(RefCell::new(None));
+        let onselect = {
+            let selected = selected.clone();
+            Callback::from(move |value: AttrValue| {
+                *selected.borrow_mut() = Some(value);
+            })
+        };
+        let mut props = combobox_props_with_id(TEST_ID);
+        props.onselect = Some(onselect);
+        render_combobox(props).await;
+
+        let element = get_combobox_input(TEST_ID);
+        type_query(&element, "re").await;
+        dispatch_keydown_event(&element, "ArrowDown").await;
+        dispatch_keydown_event(&element, "ArrowDown").await;
+        dispatch_keydown_event(&element, "ArrowUp").await;
+
+        assert_eq!(
+            element.get_attribute("aria-activedescendant"),
+            Some(format!("{}_option_0", TEST_ID))
+        );
+
+        dispatch_keydown_event(&element, "Enter").await;
+
+        assert_eq!(*selected.borrow(), Some(AttrValue::from("Restaurants")));
+        assert_eq!(element.value(), "Restaurants");
+        assert_eq!(
+            element.get_attribute("aria-expanded"),
+            Some("false".into())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn escape_closes_listbox() {
+        let props = combobox_props_with_id(TEST_ID);
+        render_combobox(props).await;
+
+        let element = get_combobox_input(TEST_ID);
+        type_query(&element, "re").await;
+        dispatch_keydown_event(&element, "Escape").await;
+
+        assert_eq!(
+            element.get_attribute("aria-expanded"),
+            Some("false".into())
+        );
+        assert!(get_listbox_options(TEST_ID).is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    async fn create_option_executes_given_oncreate() {
+        let created = Rc::new(RefCell::new(None));
+        let oncreate = {
+            let created = created.clone();
+            Callback::from(move |value: AttrValue| {
+                *created.borrow_mut() = Some(value);
+            })
+        };
+        let mut props = combobox_props_with_id(TEST_ID);
+        props.oncreate = Some(oncreate);
+        render_combobox(props).await;
+
+        let element = get_combobox_input(TEST_ID);
+        type_query(&element, "Pets").await;
+
+        assert_eq!(get_listbox_options(TEST_ID), vec!["Create ‘Pets’"]);
+
+        dispatch_keydown_event(&element, "ArrowDown").await;
+        dispatch_keydown_event(&element, "Enter").await;
+
+        assert_eq!(*created.borrow(), Some(AttrValue::from("Pets")));
+        assert_eq!(element.value(), "Pets");
+    }
+
+    #[wasm_bindgen_test]
+    async fn enter_without_active_item_chooses_default_item() {
+        let created = Rc::new(RefCell::new(None));
+        let oncreate = {
+            let created = created.clone();
+            Callback::from(move |value: AttrValue| {
+                *created.borrow_mut() = Some(value);
+            })
+        };
+        let mut props = combobox_props_with_id(TEST_ID);
+        props.oncreate = Some(oncreate);
+        render_combobox(props).await;
+
+        let element = get_combobox_input(TEST_ID);
+        type_query(&element, "Pets").await;
+        dispatch_keydown_event(&element, "Enter").await;
+
+        assert_eq!(*created.borrow(), Some(AttrValue::from("Pets")));
+    }
+
+    #[wasm_bindgen_test]
+    async fn query_is_cleared_on_choose_when_requested() {
+        let mut props = combobox_props_with_id(TEST_ID);
+        props.clear_on_choose = true;
+        render_combobox(props).await;
+
+        let element = get_combobox_input(TEST_ID);
+        type_query(&element, "rent").await;
+        dispatch_keydown_event(&element, "ArrowDown").await;
+        dispatch_keydown_event(&element, "Enter").await;
+
+        assert_eq!(element.value(), "");
+    }
+}
@@ -46,6 +46,8 @@ pub(crate) struct DateTimeSelectProps {
     #[prop_or_default]
     pub(crate) ondatetimechange:
         Option<Callback<Option<DateTime<FixedOffset>>>>,
+    #[prop_or_default]
+    pub(crate) error: Option<AttrValue>,
 }
 
 #[function_component(DateTimeSelect)]
@@ -231,6 +233,7 @@ pub(crate) fn datetime_select(props: &DateTimeSelectProps) -> Html {
                 label={"Day"}
                 options={Rc::from(days)}
                 onchange={onchange_day}
+                error={props.error.clone()}
             />
         </section>
     }
@@ -291,6 +294,7 @@ mod test {
             range: Rc::new(DateTimeRange::from(date1, date2)),
             preselect: Rc::new(make_now()),
             ondatetimechange: None,
+            error: None,
         }
     }
 
@@ -1098,4 +1102,21 @@ mod test {
             assert_eq!(onchange_output, expected_selected.to_rfc2822());
         }
     }
+
+    #[wasm_bindgen_test]
+    async fn day_select_element_is_described_by_given_error() {
+        let mut props = datetime_select_props_with_id(TEST_ID);
+        props.error = Some(AttrValue::from("Must be within the allowed dates"));
+        render_datetime_select(props).await;
+
+        let day_id = format!("{}_day", TEST_ID);
+        let element =
+            DOM::get_select_by_id(&day_id).expect("Select Element to exist");
+        let error_id = format!("{}_error", day_id);
+        let error =
+            DOM::get_element_by_id(&error_id).expect("Error Element to exist");
+
+        assert_eq!(element.get_attribute("aria-describedby"), Some(error_id));
+        assert_eq!(&error.inner_html(), "Must be within the allowed dates");
+    }
 }
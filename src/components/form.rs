@@ -0,0 +1,498 @@
+use std::collections::BTreeMap;
+
+use wasm_bindgen::{
+    closure::Closure,
+    JsCast,
+};
+use web_sys::{
+    BeforeUnloadEvent,
+    Element,
+    Event,
+    HtmlFormElement,
+    HtmlInputElement,
+    HtmlSelectElement,
+    InputEvent,
+    SubmitEvent,
+};
+use yew::{
+    function_component,
+    html,
+    use_effect,
+    use_effect_with_deps,
+    use_mut_ref,
+    use_node_ref,
+    use_state_eq,
+    AttrValue,
+    Callback,
+    Children,
+    Html,
+    Properties,
+};
+
+pub(crate) type FormValues = BTreeMap<String, String>;
+
+const LEAVE_MESSAGE: &str =
+    "You have unsaved changes. Are you sure you want to leave this page?";
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct FormProps {
+    pub(crate) id: AttrValue,
+    #[prop_or_default]
+    pub(crate) children: Children,
+    #[prop_or_default]
+    pub(crate) submitting: bool,
+    #[prop_or_default]
+    pub(crate) onsubmit: Option<Callback<FormValues>>,
+    #[prop_or_default]
+    pub(crate) onreset: Option<Callback<()>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FormStatus {
+    Pristine,
+    Dirty,
+    Submitting,
+}
+
+impl std::fmt::Display for FormStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pristine => write!(f, "pristine"),
+            Self::Dirty => write!(f, "dirty"),
+            Self::Submitting => write!(f, "submitting"),
+        }
+    }
+}
+
+#[function_component(Form)]
+pub(crate) fn form(props: &FormProps) -> Html {
+    let form_ref = use_node_ref();
+    let initial_values = use_mut_ref(|| None::<FormValues>);
+    let dirty = use_state_eq(|| false);
+    let generation = use_state_eq(|| 0_u32);
+    {
+        // Fields may receive their values asynchronously, so the baseline is
+        // re-captured after every render until the user changes something.
+        let form_ref = form_ref.clone();
+        let initial_values = initial_values.clone();
+        let is_dirty = *dirty;
+        use_effect(move || {
+            if let (false, Some(form)) =
+                (is_dirty, form_ref.cast::<HtmlFormElement>())
+            {
+                *initial_values.borrow_mut() = Some(collect_values(&form));
+            }
+        });
+    }
+    {
+        use_effect_with_deps(
+            move |&dirty| {
+                let window = web_sys::window();
+                let unload_listener = Closure::<dyn Fn(BeforeUnloadEvent)>::new(
+                    |e: BeforeUnloadEvent| {
+                        e.prevent_default();
+                        e.set_return_value("");
+                    },
+                );
+                let click_listener =
+                    Closure::<dyn Fn(Event)>::new(confirm_leaving);
+                if let (Some(window), true) = (&window, dirty) {
+                    window.set_onbeforeunload(Some(
+                        unload_listener.as_ref().unchecked_ref(),
+                    ));
+                    // Router links navigate without unloading the page, so
+                    // clicks on them are intercepted before they reach Yew.
+                    let _ = window.add_event_listener_with_callback_and_bool(
+                        "click",
+                        click_listener.as_ref().unchecked_ref(),
+                        true,
+                    );
+                }
+                move || {
+                    if let (Some(window), true) = (window, dirty) {
+                        window.set_onbeforeunload(None);
+                        let _ = window
+                            .remove_event_listener_with_callback_and_bool(
+                                "click",
+                                click_listener.as_ref().unchecked_ref(),
+                                true,
+                            );
+                    }
+                    drop(unload_listener);
+                    drop(click_listener);
+                }
+            },
+            *dirty,
+        );
+    }
+
+    let update_dirty = {
+        let form_ref = form_ref.clone();
+        let initial_values = initial_values.clone();
+        let dirty = dirty.clone();
+        Callback::from(move |_| {
+            if let Some(form) = form_ref.cast::<HtmlFormElement>() {
+                let current = collect_values(&form);
+                let is_dirty = match initial_values.borrow().as_ref() {
+                    Some(initial) => *initial != current,
+                    None => false,
+                };
+                dirty.set(is_dirty);
+            }
+        })
+    };
+
+    let onsubmit = {
+        let form_ref = form_ref.clone();
+        let submitting = props.submitting;
+        let onsubmit = props.onsubmit.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let form = form_ref.cast::<HtmlFormElement>();
+            if let (Some(form), Some(onsubmit), false) =
+                (form, &onsubmit, submitting)
+            {
+                onsubmit.emit(collect_values(&form));
+            }
+        })
+    };
+
+    let onreset = {
+        let initial_values = initial_values.clone();
+        let dirty = dirty.clone();
+        let generation = generation.clone();
+        let onreset = props.onreset.clone();
+        Callback::from(move |e: Event| {
+            e.prevent_default();
+            if let Some(onreset) = &onreset {
+                onreset.emit(());
+            }
+            *initial_values.borrow_mut() = None;
+            dirty.set(false);
+            generation.set(*generation + 1);
+        })
+    };
+
+    let status = match (props.submitting, *dirty) {
+        (true, _) => FormStatus::Submitting,
+        (false, true) => FormStatus::Dirty,
+        (false, false) => FormStatus::Pristine,
+    };
+
+    html! {
+        <form
+            id={props.id.clone()}
+            ref={form_ref}
+            novalidate={true}
+            data-status={status.to_string()}
+            aria-busy={props.submitting.to_string()}
+            oninput={update_dirty.reform(|_: InputEvent| ())}
+            onchange={update_dirty.reform(|_: Event| ())}
+            {onsubmit}
+            {onreset}
+        >
+            <div key={*generation}>
+                { for props.children.iter() }
+            </div>
+        </form>
+    }
+}
+
+pub(crate) fn reset_form(id: &str) {
+    let form = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(id))
+        .and_then(|element| element.dyn_into::<HtmlFormElement>().ok());
+    if let Some(form) = form {
+        form.reset();
+    }
+}
+
+fn confirm_leaving(e: Event) {
+    let link = e
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .and_then(|element| element.closest("a[href]").ok().flatten());
+    let confirmed = match (link, web_sys::window()) {
+        (Some(_), Some(window)) => {
+            window.confirm_with_message(LEAVE_MESSAGE).unwrap_or(true)
+        },
+        _ => true,
+    };
+    if !confirmed {
+        e.prevent_default();
+        e.stop_propagation();
+    }
+}
+
+fn collect_values(form: &HtmlFormElement) -> FormValues {
+    let elements = form.elements();
+    (0..elements.length())
+        .filter_map(|index| elements.item(index))
+        .filter_map(|element| {
+            let value = match element.tag_name().as_str() {
+                "INPUT" => element.dyn_ref::<HtmlInputElement>()?.value(),
+                "SELECT" => element.dyn_ref::<HtmlSelectElement>()?.value(),
+                _ => return None,
+            };
+            let key = element
+                .get_attribute("name")
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| element.id());
+            Some((key, value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+        time::Duration,
+    };
+
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use web_sys::{
+        Event,
+        EventInit,
+        HtmlInputElement,
+    };
+    use yew::{
+        function_component,
+        html,
+        use_effect_with_deps,
+        use_state,
+        AttrValue,
+        Callback,
+        Html,
+        Properties,
+    };
+
+    use super::{
+        Form,
+        FormStatus,
+        FormValues,
+    };
+    use crate::{
+        components::Input,
+        dom::DOM,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(Properties, PartialEq)]
+    struct TestFormProps {
+        submitting: bool,
+        onsubmit: Callback<FormValues>,
+        onreset: Callback<()>,
+    }
+
+    #[function_component(TestForm)]
+    fn test_form(props: &TestFormProps) -> Html {
+        html! {
+            <Form
+                id={TEST_ID}
+                submitting={props.submitting}
+                onsubmit={props.onsubmit.clone()}
+                onreset={props.onreset.clone()}
+            >
+                <Input id={"first_input"} label={"First"} />
+                <Input id={"second_input"} label={"Second"} />
+                <button id={"reset_button"} type={"reset"}>{ "Reset" }</button>
+            </Form>
+        }
+    }
+
+    #[function_component(LoadingTestForm)]
+    fn loading_test_form() -> Html {
+        let value = use_state(|| None::<AttrValue>);
+        {
+            let value = value.clone();
+            use_effect_with_deps(
+                move |_| {
+                    yew::platform::spawn_local(async move {
+                        value.set(Some(AttrValue::from("loaded")));
+                    });
+                },
+                (),
+            );
+        }
+        html! {
+            <Form id={TEST_ID}>
+                <Input id={"first_input"} label={"First"} value={(*value).clone()} />
+            </Form>
+        }
+    }
+
+    async fn render_form(props: TestFormProps) {
+        let output = DOM::get_element_by_id("output")
+            .expect("Element `output` to exist");
+        yew::Renderer::<TestForm>::with_root_and_props(output, props).render();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    fn test_form_props() -> TestFormProps {
+        TestFormProps {
+            submitting: false,
+            onsubmit: Callback::from(|_| {}),
+            onreset: Callback::from(|_| {}),
+        }
+    }
+
+    fn get_input(id: &str) -> HtmlInputElement {
+        DOM::get_input_by_id(id)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input")
+    }
+
+    fn get_form_status() -> Option<String> {
+        DOM::get_element_by_id(TEST_ID)
+            .expect("Form Element to exist")
+            .get_attribute("data-status")
+    }
+
+    async fn dispatch_event(target: &web_sys::Element, event_type: &str) {
+        let mut init = EventInit::new();
+        init.bubbles(true);
+        init.cancelable(true);
+        let event = Event::new_with_event_init_dict(event_type, &init)
+            .expect("valid event");
+        target
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    static TEST_ID: &str = "test_form";
+
+    #[wasm_bindgen_test]
+    fn form_status_is_displayed_in_lowercase() {
+        let tests = vec![
+            (FormStatus::Pristine, "pristine"),
+            (FormStatus::Dirty, "dirty"),
+            (FormStatus::Submitting, "submitting"),
+        ];
+
+        for (status, expected) in tests {
+            assert_eq!(status.to_string(), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn component_contains_form_element_with_children() {
+        render_form(test_form_props()).await;
+
+        let element =
+            DOM::get_element_by_id(TEST_ID).expect("Form Element to exist");
+
+        assert_eq!(element.tag_name(), "FORM");
+        assert!(DOM::get_input_by_id("first_input").is_some());
+        assert_eq!(get_form_status(), Some("pristine".into()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn form_is_dirty_after_input_and_pristine_when_reverted() {
+        render_form(test_form_props()).await;
+
+        let input = get_input("first_input");
+        input.set_value("changed");
+        dispatch_event(&input, "input").await;
+
+        assert_eq!(get_form_status(), Some("dirty".into()));
+
+        input.set_value("");
+        dispatch_event(&input, "input").await;
+
+        assert_eq!(get_form_status(), Some("pristine".into()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn form_stays_pristine_when_field_values_load_later() {
+        let output = DOM::get_element_by_id("output")
+            .expect("Element `output` to exist");
+        yew::Renderer::<LoadingTestForm>::with_root(output).render();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+
+        let input = get_input("first_input");
+        assert_eq!(input.value(), "loaded");
+        dispatch_event(&input, "input").await;
+
+        assert_eq!(get_form_status(), Some("pristine".into()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn submit_executes_onsubmit_with_field_values() {
+        let submitted = Rc::new(RefCell::new(None));
+        let mut props = test_form_props();
+        props.onsubmit = {
+            let submitted = submitted.clone();
+            Callback::from(move |values: FormValues| {
+                *submitted.borrow_mut() = Some(values);
+            })
+        };
+        render_form(props).await;
+
+        get_input("first_input").set_value("one");
+        get_input("second_input").set_value("two");
+        let form =
+            DOM::get_element_by_id(TEST_ID).expect("Form Element to exist");
+        dispatch_event(&form, "submit").await;
+
+        let expected: FormValues = [
+            (String::from("first_input"), String::from("one")),
+            (String::from("second_input"), String::from("two")),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(*submitted.borrow(), Some(expected));
+    }
+
+    #[wasm_bindgen_test]
+    async fn submit_is_ignored_while_submitting() {
+        let submitted = Rc::new(RefCell::new(false));
+        let mut props = test_form_props();
+        props.submitting = true;
+        props.onsubmit = {
+            let submitted = submitted.clone();
+            Callback::from(move |_| *submitted.borrow_mut() = true)
+        };
+        render_form(props).await;
+
+        let form =
+            DOM::get_element_by_id(TEST_ID).expect("Form Element to exist");
+        dispatch_event(&form, "submit").await;
+
+        assert!(!*submitted.borrow());
+        assert_eq!(get_form_status(), Some("submitting".into()));
+        assert_eq!(form.get_attribute("aria-busy"), Some("true".into()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn reset_clears_fields_and_executes_onreset() {
+        let reset = Rc::new(RefCell::new(false));
+        let mut props = test_form_props();
+        props.onreset = {
+            let reset = reset.clone();
+            Callback::from(move |_| *reset.borrow_mut() = true)
+        };
+        render_form(props).await;
+
+        let input = get_input("first_input");
+        input.set_value("changed");
+        dispatch_event(&input, "input").await;
+        let form =
+            DOM::get_element_by_id(TEST_ID).expect("Form Element to exist");
+        dispatch_event(&form, "reset").await;
+
+        assert!(*reset.borrow());
+        assert_eq!(get_input("first_input").value(), "");
+        assert_eq!(get_form_status(), Some("pristine".into()));
+    }
+}
@@ -1,4 +1,11 @@
-use web_sys::InputEvent;
+use std::rc::Rc;
+
+use web_sys::{
+    Event,
+    FocusEvent,
+    InputEvent,
+    KeyboardEvent,
+};
 use yew::{
     function_component,
     html,
@@ -8,6 +15,8 @@ use yew::{
     Properties,
 };
 
+use crate::types::select::SelectOption;
+
 #[derive(Properties, PartialEq)]
 pub(crate) struct InputProps {
     pub(crate) id: AttrValue,
@@ -17,15 +26,42 @@ pub(crate) struct InputProps {
     #[prop_or(InputMode::Text)]
     pub(crate) inputmode: InputMode,
     #[prop_or_default]
+    pub(crate) name: Option<AttrValue>,
+    #[prop_or_default]
+    pub(crate) autocomplete: Option<AttrValue>,
+    #[prop_or_default]
     pub(crate) placeholder: Option<AttrValue>,
     #[prop_or_default]
     pub(crate) value: Option<AttrValue>,
     #[prop_or_default]
+    pub(crate) suggestions: Rc<[SelectOption]>,
+    #[prop_or_default]
+    pub(crate) maxlength: Option<usize>,
+    #[prop_or_default]
+    pub(crate) required: bool,
+    #[prop_or_default]
+    pub(crate) disabled: bool,
+    #[prop_or_default]
     pub(crate) oninput: Option<Callback<InputEvent>>,
+    #[prop_or_default]
+    pub(crate) onblur: Option<Callback<FocusEvent>>,
+    #[prop_or_default]
+    pub(crate) onpaste: Option<Callback<Event>>,
+    #[prop_or_default]
+    pub(crate) onkeydown: Option<Callback<KeyboardEvent>>,
+    #[prop_or_default]
+    pub(crate) onkeyup: Option<Callback<KeyboardEvent>>,
+    #[prop_or_default]
+    pub(crate) error: Option<AttrValue>,
 }
 
 #[function_component(Input)]
 pub(crate) fn input(props: &InputProps) -> Html {
+    let error_id = format!("{}_error", props.id);
+    let is_invalid = props.error.is_some();
+    let suggestions_id = format!("{}_suggestions", props.id);
+    let has_suggestions = !props.suggestions.is_empty();
+
     html! {
         <section id={format!("{}_section", props.id)}>
             <label
@@ -38,10 +74,38 @@ pub(crate) fn input(props: &InputProps) -> Html {
                 id={props.id.clone()}
                 type={props.input_type.to_string()}
                 inputmode={props.inputmode.to_string()}
+                name={props.name.clone()}
+                autocomplete={props.autocomplete.clone()}
                 placeholder={props.placeholder.clone()}
                 value={props.value.clone()}
+                list={has_suggestions.then_some(suggestions_id.clone())}
+                maxlength={props.maxlength.map(|max| max.to_string())}
+                required={props.required}
+                disabled={props.disabled}
                 oninput={props.oninput.clone()}
+                onblur={props.onblur.clone()}
+                onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()}
+                onkeyup={props.onkeyup.clone()}
+                aria-invalid={is_invalid.then_some("true")}
+                aria-describedby={is_invalid.then_some(error_id.clone())}
             />
+            if has_suggestions {
+                <datalist id={suggestions_id}>
+                {
+                    props.suggestions.iter().map(|option| html! {
+                        <option
+                            key={option.value.as_str()}
+                            value={option.value.clone()}
+                            label={option.inner_html.clone()}
+                        />
+                    }).collect::<Html>()
+                }
+                </datalist>
+            }
+            if let Some(error) = props.error.clone() {
+                <p id={error_id} class={"error"}>{ error }</p>
+            }
         </section>
     }
 }
@@ -50,6 +114,8 @@ pub(crate) fn input(props: &InputProps) -> Html {
 pub(crate) enum InputType {
     Text,
     Password,
+    Email,
+    Search,
 }
 
 impl std::fmt::Display for InputType {
@@ -57,6 +123,8 @@ impl std::fmt::Display for InputType {
         match self {
             Self::Text => write!(f, "text"),
             Self::Password => write!(f, "password"),
+            Self::Email => write!(f, "email"),
+            Self::Search => write!(f, "search"),
         }
     }
 }
@@ -65,6 +133,9 @@ impl std::fmt::Display for InputType {
 pub(crate) enum InputMode {
     Text,
     Numeric,
+    Decimal,
+    Email,
+    Search,
 }
 
 impl std::fmt::Display for InputMode {
@@ -72,13 +143,19 @@ impl std::fmt::Display for InputMode {
         match self {
             Self::Text => write!(f, "text"),
             Self::Numeric => write!(f, "numeric"),
+            Self::Decimal => write!(f, "decimal"),
+            Self::Email => write!(f, "email"),
+            Self::Search => write!(f, "search"),
         }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use std::time::Duration;
+    use std::{
+        rc::Rc,
+        time::Duration,
+    };
 
     use wasm_bindgen::JsCast;
     use wasm_bindgen_test::{
@@ -87,8 +164,11 @@ mod test {
     };
     use web_sys::{
         Event,
+        FocusEvent,
         HtmlInputElement,
         InputEvent,
+        KeyboardEvent,
+        KeyboardEventInit,
     };
     use yew::{
         AttrValue,
@@ -102,7 +182,10 @@ mod test {
         InputProps,
         InputType,
     };
-    use crate::dom::DOM;
+    use crate::{
+        dom::DOM,
+        types::select::SelectOption,
+    };
 
     wasm_bindgen_test_configure!(run_in_browser);
 
@@ -119,12 +202,39 @@ mod test {
             label: AttrValue::from(""),
             input_type: InputType::Text,
             inputmode: InputMode::Text,
+            name: None,
+            autocomplete: None,
             placeholder: None,
             value: None,
+            suggestions: Rc::new([]),
+            maxlength: None,
+            required: false,
+            disabled: false,
             oninput: None,
+            onblur: None,
+            onpaste: None,
+            onkeydown: None,
+            onkeyup: None,
+            error: None,
         }
     }
 
+    async fn dispatch_blur_event(target: &HtmlInputElement) {
+        let event = Event::new("blur").expect("valid event");
+        target
+            .dispatch_event(&event)
+            .expect("event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    async fn dispatch_paste_event(target: &HtmlInputElement) {
+        let event = Event::new("paste").expect("valid event");
+        target
+            .dispatch_event(&event)
+            .expect("event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
     async fn dispatch_input_event(target: &HtmlInputElement) {
         let event = Event::new("input").expect("valid event");
         target
@@ -186,6 +296,67 @@ mod test {
         );
     }
 
+    #[wasm_bindgen_test]
+    fn input_type_and_inputmode_are_displayed_as_attribute_values() {
+        let types = vec![
+            (InputType::Text, "text"),
+            (InputType::Password, "password"),
+            (InputType::Email, "email"),
+            (InputType::Search, "search"),
+        ];
+        let modes = vec![
+            (InputMode::Text, "text"),
+            (InputMode::Numeric, "numeric"),
+            (InputMode::Decimal, "decimal"),
+            (InputMode::Email, "email"),
+            (InputMode::Search, "search"),
+        ];
+
+        for (input_type, expected) in types {
+            assert_eq!(input_type.to_string(), expected);
+        }
+        for (inputmode, expected) in modes {
+            assert_eq!(inputmode.to_string(), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_has_given_form_attributes() {
+        let mut props = input_props_with_id(TEST_ID);
+        props.name = Some(AttrValue::from("username"));
+        props.autocomplete = Some(AttrValue::from("username"));
+        props.maxlength = Some(255);
+        props.required = true;
+        props.disabled = true;
+        render_input(props).await;
+
+        let element = DOM::get_input_by_id(TEST_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+
+        assert_eq!(element.name(), "username");
+        assert_eq!(element.autocomplete(), "username");
+        assert_eq!(element.max_length(), 255);
+        assert!(element.required());
+        assert!(element.disabled());
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_does_not_have_form_attributes_when_not_given() {
+        let props = input_props_with_id(TEST_ID);
+        render_input(props).await;
+
+        let element =
+            DOM::get_input_by_id(TEST_ID).expect("Input Element to exist");
+
+        assert_eq!(element.get_attribute("name"), None);
+        assert_eq!(element.get_attribute("autocomplete"), None);
+        assert_eq!(element.get_attribute("maxlength"), None);
+        assert_eq!(element.get_attribute("required"), None);
+        assert_eq!(element.get_attribute("disabled"), None);
+    }
+
     #[wasm_bindgen_test]
     async fn input_element_does_not_have_placeholder_when_not_given() {
         let mut props = input_props_with_id(TEST_ID);
@@ -295,6 +466,159 @@ mod test {
         }
     }
 
+    #[wasm_bindgen_test]
+    async fn component_executes_given_onblur() {
+        let substitute_by_hello = Callback::from(|e: FocusEvent| {
+            if let Some(element) = e.target_dyn_into::<HtmlInputElement>() {
+                element.set_value("hello");
+            };
+        });
+        let mut props = input_props_with_id(TEST_ID);
+        props.onblur = Some(substitute_by_hello);
+        render_input(props).await;
+
+        let element = DOM::get_input_by_id(TEST_ID)
+            .expect("Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+
+        element.set_value("test");
+        dispatch_blur_event(&element).await;
+
+        assert_eq!(element.value(), "hello");
+    }
+
+    #[wasm_bindgen_test]
+    async fn component_executes_given_onpaste() {
+        let substitute_by_hello = Callback::from(|e: Event| {
+            if let Some(element) = e.target_dyn_into::<HtmlInputElement>() {
+                element.set_value("hello");
+            };
+        });
+        let mut props = input_props_with_id(TEST_ID);
+        props.onpaste = Some(substitute_by_hello);
+        render_input(props).await;
+
+        let element = DOM::get_input_by_id(TEST_ID)
+            .expect("Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+
+        element.set_value("test");
+        dispatch_paste_event(&element).await;
+
+        assert_eq!(element.value(), "hello");
+    }
+
+    #[wasm_bindgen_test]
+    async fn component_executes_given_keyboard_callbacks() {
+        let to_key = |e: KeyboardEvent| {
+            if let Some(element) = e.target_dyn_into::<HtmlInputElement>() {
+                element.set_value(&format!("{} {}", e.type_(), e.key()));
+            };
+        };
+        let mut props = input_props_with_id(TEST_ID);
+        props.onkeydown = Some(Callback::from(to_key));
+        props.onkeyup = Some(Callback::from(to_key));
+        render_input(props).await;
+
+        let element = DOM::get_input_by_id(TEST_ID)
+            .expect("Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+
+        for event_type in ["keydown", "keyup"] {
+            let mut init = KeyboardEventInit::new();
+            init.key("a");
+            let event = KeyboardEvent::new_with_keyboard_event_init_dict(
+                event_type, &init,
+            )
+            .expect("valid event");
+            element
+                .dispatch_event(&event)
+                .expect("event to be dispatched");
+            yew::platform::time::sleep(Duration::from_millis(10)).await;
+
+            assert_eq!(element.value(), format!("{} a", event_type));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_has_no_list_when_suggestions_not_given() {
+        let props = input_props_with_id(TEST_ID);
+        render_input(props).await;
+
+        let element =
+            DOM::get_input_by_id(TEST_ID).expect("Input Element to exist");
+        let datalist =
+            DOM::get_element_by_id(&format!("{}_suggestions", TEST_ID));
+
+        assert_eq!(element.get_attribute("list"), None);
+        assert!(datalist.is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_lists_given_suggestions() {
+        let suggestions_id = format!("{}_suggestions", TEST_ID);
+        let mut props = input_props_with_id(TEST_ID);
+        props.suggestions = Rc::from(vec![
+            SelectOption::from("Lidl"),
+            SelectOption::from("HSL monthly ticket"),
+        ]);
+        render_input(props).await;
+
+        let element =
+            DOM::get_input_by_id(TEST_ID).expect("Input Element to exist");
+        let datalist = DOM::get_element_by_id(&suggestions_id)
+            .expect("Datalist Element to exist");
+        let values: Vec<Option<String>> = (0..datalist.child_element_count())
+            .filter_map(|i| datalist.children().item(i))
+            .map(|option| option.get_attribute("value"))
+            .collect();
+
+        assert_eq!(element.get_attribute("list"), Some(suggestions_id));
+        assert_eq!(datalist.tag_name(), "DATALIST");
+        assert_eq!(
+            values,
+            vec![
+                Some("Lidl".to_string()),
+                Some("HSL monthly ticket".to_string())
+            ]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_is_valid_when_error_not_given() {
+        let props = input_props_with_id(TEST_ID);
+        render_input(props).await;
+
+        let element =
+            DOM::get_input_by_id(TEST_ID).expect("Input Element to exist");
+        let error = DOM::get_element_by_id(&format!("{}_error", TEST_ID));
+
+        assert_eq!(element.get_attribute("aria-invalid"), None);
+        assert_eq!(element.get_attribute("aria-describedby"), None);
+        assert!(error.is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_is_described_by_given_error() {
+        let mut props = input_props_with_id(TEST_ID);
+        props.error = Some(AttrValue::from("This field is required"));
+        render_input(props).await;
+
+        let element =
+            DOM::get_input_by_id(TEST_ID).expect("Input Element to exist");
+        let error_id = format!("{}_error", TEST_ID);
+        let error =
+            DOM::get_element_by_id(&error_id).expect("Error Element to exist");
+
+        assert_eq!(element.get_attribute("aria-invalid"), Some("true".into()));
+        assert_eq!(element.get_attribute("aria-describedby"), Some(error_id));
+        assert_eq!(&error.inner_html(), "This field is required");
+        assert!(DOM::is_element_visible(&error));
+    }
+
     #[wasm_bindgen_test]
     async fn component_contains_label_element_for_input() {
         let props = input_props_with_id(TEST_ID);
@@ -1,13 +1,31 @@
+mod chart;
+mod combobox;
 mod datetime_select;
+mod form;
 mod input;
 mod monetary_input;
+mod multi_select;
 mod select;
 
+pub(crate) use chart::{
+    ChartDatum,
+    ChartSeries,
+    LineChart,
+    PieChart,
+    StackedBarChart,
+};
+pub(crate) use combobox::Combobox;
 pub(crate) use datetime_select::DateTimeSelect;
+pub(crate) use form::{
+    reset_form,
+    Form,
+    FormValues,
+};
 pub(crate) use input::{
     Input,
     InputMode,
     InputType,
 };
 pub(crate) use monetary_input::MonetaryInput;
+pub(crate) use multi_select::MultiSelect;
 pub(crate) use select::Select;
@@ -1,11 +1,19 @@
-use wasm_bindgen::JsCast;
+use wasm_bindgen::{
+    JsCast,
+    JsValue,
+};
 use web_sys::{
+    DataTransfer,
+    Event,
+    FocusEvent,
     HtmlInputElement,
     InputEvent,
+    MouseEvent,
 };
 use yew::{
     function_component,
     html,
+    use_effect_with_deps,
     use_state,
     AttrValue,
     Callback,
@@ -13,125 +21,403 @@ use yew::{
     Properties,
 };
 
-use crate::components::{
-    Input,
-    InputMode,
+use crate::{
+    components::{
+        Input,
+        InputMode,
+    },
+    types::{
+        currency::Currency,
+        expression::is_expression,
+        locale::Locale,
+        money::Money,
+    },
 };
 
+const OPERATORS: [(&str, &str); 6] = [
+    ("+", "Insert plus"),
+    ("-", "Insert minus"),
+    ("*", "Insert multiplication"),
+    ("/", "Insert division"),
+    ("(", "Insert opening parenthesis"),
+    (")", "Insert closing parenthesis"),
+];
+
 #[derive(Properties, PartialEq)]
 pub(crate) struct MonetaryInputProps {
     pub(crate) id: AttrValue,
     pub(crate) label: AttrValue,
+    #[prop_or_default]
+    pub(crate) currency: Currency,
+    #[prop_or_default]
+    pub(crate) locale: Locale,
+    #[prop_or_default]
+    pub(crate) value: Option<i64>,
+    #[prop_or_default]
+    pub(crate) onvaluechange: Option<Callback<Option<i64>>>,
+    #[prop_or_default]
+    pub(crate) error: Option<AttrValue>,
 }
 
 #[function_component(MonetaryInput)]
 pub(crate) fn monetary_input(props: &MonetaryInputProps) -> Html {
-    let input_value = use_state(|| String::default());
+    let input_value = use_state(|| {
+        props
+            .value
+            .map(|v| Money::new(v, props.currency).format(props.locale))
+            .unwrap_or_default()
+    });
+    {
+        let input_value = input_value.clone();
+        use_effect_with_deps(
+            move |&(value, currency, locale)| {
+                let text = (*input_value).clone();
+                if is_expression(&text)
+                    && (value.is_none()
+                        || parse_amount(&text, currency, locale) == value)
+                {
+                    return;
+                }
+                match value {
+                    Some(value) => input_value
+                        .set(Money::new(value, currency).format(locale)),
+                    None => input_value.set(String::new()),
+                }
+            },
+            (props.value, props.currency, props.locale),
+        );
+    }
 
     let format_input = {
         let input_value = input_value.clone();
+        let currency = props.currency;
+        let locale = props.locale;
+        let onvaluechange = props.onvaluechange.clone();
 
         Callback::from(move |e: InputEvent| {
             let input = e
                 .target()
                 .and_then(|t| t.dyn_into::<HtmlInputElement>().ok());
             if let Some(input) = input {
-                let new_value =
-                    convert_digit_string_to_monetary(&input.value());
+                let value = input.value();
+                let new_value = match is_expression(&value) {
+                    true => value.clone(),
+                    false => convert_digit_string_to_monetary(
+                        &value, currency, locale,
+                    ),
+                };
+                if new_value != value {
+                    let caret = input.selection_start().ok().flatten();
+                    input.set_value(&new_value);
+                    if let Some(caret) = caret {
+                        let caret =
+                            caret_after_reformat(&value, caret, &new_value);
+                        let _ = input.set_selection_range(caret, caret);
+                    }
+                }
+                input_value.set(new_value);
+                if let Some(onvaluechange) = &onvaluechange {
+                    onvaluechange.emit(parse_amount(&value, currency, locale));
+                }
+            }
+        })
+    };
+
+    let evaluate_input = {
+        let input_value = input_value.clone();
+        let currency = props.currency;
+        let locale = props.locale;
+
+        Callback::from(move |e: FocusEvent| {
+            let input = e
+                .target()
+                .and_then(|t| t.dyn_into::<HtmlInputElement>().ok());
+            if let Some(input) = input {
+                let value = input.value();
+                if !is_expression(&value) {
+                    return;
+                }
+                if let Some(money) =
+                    Money::from_expression(&value, currency, locale)
+                {
+                    input_value.set(money.format(locale));
+                }
+            }
+        })
+    };
+
+    let paste_amount = {
+        let input_value = input_value.clone();
+        let currency = props.currency;
+        let locale = props.locale;
+        let onvaluechange = props.onvaluechange.clone();
+
+        Callback::from(move |e: Event| {
+            let money = clipboard_text(&e)
+                .and_then(|text| Money::from_pasted(&text, currency, locale));
+            let input = e
+                .target()
+                .and_then(|t| t.dyn_into::<HtmlInputElement>().ok());
+            if let (Some(money), Some(input)) = (money, input) {
+                e.prevent_default();
+                let value = input.value();
+                let (start, end) = selection(&input, &value);
+                let (pasted, caret) =
+                    insert_text(&value, start, end, &money.format(locale));
+                let (new_value, caret) = match is_expression(&pasted) {
+                    true => (pasted, caret),
+                    false => {
+                        let new_value = convert_digit_string_to_monetary(
+                            &pasted, currency, locale,
+                        );
+                        let caret =
+                            caret_after_reformat(&pasted, caret, &new_value);
+                        (new_value, caret)
+                    },
+                };
+                input.set_value(&new_value);
+                let _ = input.set_selection_range(caret, caret);
+                if let Some(onvaluechange) = &onvaluechange {
+                    onvaluechange
+                        .emit(parse_amount(&new_value, currency, locale));
+                }
                 input_value.set(new_value);
             }
         })
     };
 
+    // The numeric keyboard on phones has no operator keys, so expressions
+    // are typed with these buttons instead.
+    let operator_buttons = OPERATORS
+        .iter()
+        .enumerate()
+        .map(|(index, &(operator, label))| {
+            let input_value = input_value.clone();
+            let id = props.id.clone();
+            let currency = props.currency;
+            let locale = props.locale;
+            let onvaluechange = props.onvaluechange.clone();
+            let onclick = Callback::from(move |_: MouseEvent| {
+                let Some(input) = input_by_id(&id) else {
+                    return;
+                };
+                let value = input.value();
+                let (start, end) = selection(&input, &value);
+                let (new_value, caret) =
+                    insert_text(&value, start, end, operator);
+                input.set_value(&new_value);
+                let _ = input.focus();
+                let _ = input.set_selection_range(caret, caret);
+                if let Some(onvaluechange) = &onvaluechange {
+                    onvaluechange
+                        .emit(parse_amount(&new_value, currency, locale));
+                }
+                input_value.set(new_value);
+            });
+            html! {
+                <button
+                    id={format!("{}_operator_{}", props.id, index)}
+                    type={"button"}
+                    aria-label={label}
+                    onmousedown={Callback::from(|e: MouseEvent| e.prevent_default())}
+                    {onclick}
+                >
+                    { operator }
+                </button>
+            }
+        })
+        .collect::<Html>();
+
     html! {
-        <Input
-            id={props.id.clone()}
-            label={props.label.clone()}
-            inputmode={InputMode::Numeric}
-            placeholder={"0.00"}
-            oninput={format_input}
-            value={AttrValue::from((*input_value).clone())}
-        />
+        <>
+            <Input
+                id={props.id.clone()}
+                label={props.label.clone()}
+                inputmode={InputMode::Numeric}
+                placeholder={Money::zero(props.currency).format(props.locale)}
+                oninput={format_input}
+                onblur={evaluate_input}
+                onpaste={paste_amount}
+                error={props.error.clone()}
+                value={AttrValue::from((*input_value).clone())}
+            />
+            <div
+                id={format!("{}_operators", props.id)}
+                class={"operators"}
+                role={"group"}
+                aria-label={"Operators"}
+            >
+                { operator_buttons }
+            </div>
+        </>
     }
 }
 
-fn convert_digit_string_to_monetary(string: &str) -> String {
-    let string = truncate_to_valid_i64(string);
+fn input_by_id(id: &str) -> Option<HtmlInputElement> {
+    web_sys::window()?
+        .document()?
+        .get_element_by_id(id)?
+        .dyn_into::<HtmlInputElement>()
+        .ok()
+}
 
-    match string.len() {
-        0 => String::from("0.00"),
-        1 => format!("0.0{}", string),
-        2 => format!("0.{}", string),
-        n => {
-            let (a, b) = string.split_at(n - 2);
-            format!("{}.{}", add_thousands_separator(a), b)
-        },
-    }
+fn selection(input: &HtmlInputElement, value: &str) -> (u32, u32) {
+    let end = value.encode_utf16().count() as u32;
+    let start = input.selection_start().ok().flatten();
+    let finish = input.selection_end().ok().flatten();
+    (start.unwrap_or(end), finish.unwrap_or(end))
 }
 
-fn truncate_to_valid_i64(string: &str) -> String {
-    let mut string = match filter_digits(string).trim_start_matches('0') {
-        s if s.len() == 0 => String::from("0"),
-        s => s.to_owned(),
+// Selection offsets from the DOM are in UTF-16 code units.
+fn insert_text(value: &str, start: u32, end: u32, text: &str) -> (String, u32) {
+    let byte_index = |offset: u32| {
+        let mut position = 0;
+        value
+            .char_indices()
+            .find(|(_, c)| {
+                let found = position >= offset;
+                position += c.len_utf16() as u32;
+                found
+            })
+            .map_or(value.len(), |(index, _)| index)
     };
-    let max = i64::MAX.to_string();
-    string.truncate(max.len());
-    while let Err(_) = string.parse::<i64>() {
-        string.truncate(string.len() - 1);
-    }
-    string
+    let (start, end) = (start.min(end), start.max(end));
+    let (from, to) = (byte_index(start), byte_index(end));
+    let new_value = [&value[..from], text, &value[to..]].concat();
+    let caret =
+        value[..from].encode_utf16().count() + text.encode_utf16().count();
+    (new_value, caret as u32)
 }
 
-fn filter_digits(string: &str) -> String {
-    string
-        .chars()
-        .filter(|c| c.is_digit(10))
-        .collect::<String>()
+fn clipboard_text(e: &Event) -> Option<String> {
+    js_sys::Reflect::get(e, &JsValue::from_str("clipboardData"))
+        .ok()?
+        .dyn_into::<DataTransfer>()
+        .ok()?
+        .get_data("text")
+        .ok()
 }
 
-fn add_thousands_separator(string: &str) -> String {
-    match string.len() {
-        n if n <= 3 => string.to_string(),
-        n if n <= 6 => {
-            let (a, b) = string.split_at(n - 3);
-            format!("{},{}", a, b)
-        },
-        n => {
-            let (a, b) = string.split_at(n - 6);
-            let a = add_thousands_separator(a);
-            let b = add_thousands_separator(b);
-            format!("{},{}", a, b)
-        },
+fn caret_after_reformat(old: &str, caret: u32, new: &str) -> u32 {
+    let mut position = 0;
+    let digits_after_caret = old
+        .chars()
+        .filter(|c| {
+            position += c.len_utf16() as u32;
+            position > caret && c.is_ascii_digit()
+        })
+        .count();
+
+    let chars: Vec<char> = new.chars().collect();
+    let mut index = chars.len();
+    let mut digits = 0;
+    while index > 0 && digits < digits_after_caret {
+        index -= 1;
+        if chars[index].is_ascii_digit() {
+            digits += 1;
+        }
+    }
+    while index > 0 && !chars[index - 1].is_ascii_digit() {
+        index -= 1;
     }
+    chars[..index].iter().map(|c| c.len_utf16() as u32).sum()
+}
+
+fn parse_amount(
+    string: &str,
+    currency: Currency,
+    locale: Locale,
+) -> Option<i64> {
+    let money = match is_expression(string) {
+        true => Money::from_expression(string, currency, locale),
+        false => Money::from_input(string, currency),
+    };
+    money.map(|m| m.minor)
+}
+
+fn convert_digit_string_to_monetary(
+    string: &str,
+    currency: Currency,
+    locale: Locale,
+) -> String {
+    Money::from_input(string, currency)
+        .unwrap_or(Money::zero(currency))
+        .format(locale)
 }
 
 #[cfg(test)]
 mod test {
-    use std::time::Duration;
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+        time::Duration,
+    };
 
-    use wasm_bindgen::JsCast;
+    use wasm_bindgen::{
+        JsCast,
+        JsValue,
+    };
     use wasm_bindgen_test::{
         wasm_bindgen_test,
         wasm_bindgen_test_configure,
     };
     use web_sys::{
+        DataTransfer,
         Event,
+        HtmlElement,
         HtmlInputElement,
     };
-    use yew::AttrValue;
+    use yew::{
+        function_component,
+        html,
+        use_effect_with_deps,
+        use_state,
+        AttrValue,
+        Callback,
+        Html,
+    };
 
     use super::{
-        add_thousands_separator,
+        caret_after_reformat,
         convert_digit_string_to_monetary,
-        filter_digits,
-        truncate_to_valid_i64,
+        insert_text,
+        parse_amount,
         MonetaryInput,
         MonetaryInputProps,
     };
-    use crate::dom::DOM;
+    use crate::{
+        dom::DOM,
+        types::{
+            currency::Currency,
+            locale::Locale,
+        },
+    };
 
     wasm_bindgen_test_configure!(run_in_browser);
 
+    #[function_component(ClearingMonetaryInput)]
+    fn clearing_monetary_input() -> Html {
+        let value = use_state(|| Some(1234));
+        {
+            let value = value.clone();
+            use_effect_with_deps(
+                move |_| {
+                    yew::platform::spawn_local(async move { value.set(None) });
+                },
+                (),
+            );
+        }
+        html! {
+            <MonetaryInput
+                id={TEST_ID}
+                label={"Test"}
+                currency={Currency::Eur}
+                locale={Locale::EnUs}
+                value={*value}
+            />
+        }
+    }
+
     async fn render_monetary_input(props: MonetaryInputProps) {
         let output = DOM::get_element_by_id("output")
             .expect("Element `output` to exist");
@@ -140,6 +426,26 @@ mod test {
         yew::platform::time::sleep(Duration::from_millis(10)).await;
     }
 
+    fn monetary_input_props_with_id(id: &str) -> MonetaryInputProps {
+        MonetaryInputProps {
+            id: AttrValue::from(id.to_owned()),
+            label: AttrValue::from("Test"),
+            currency: Currency::Eur,
+            locale: Locale::EnUs,
+            value: None,
+            onvaluechange: None,
+            error: None,
+        }
+    }
+
+    async fn dispatch_blur_event(target: &HtmlInputElement) {
+        let event = Event::new("blur").expect("valid event");
+        target
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
     async fn dispatch_input_event(target: &HtmlInputElement) {
         let event = Event::new("input").expect("valid event");
         target
@@ -148,97 +454,185 @@ mod test {
         yew::platform::time::sleep(Duration::from_millis(10)).await;
     }
 
+    async fn dispatch_paste_event(target: &HtmlInputElement, text: &str) {
+        let clipboard_data = DataTransfer::new().expect("valid data transfer");
+        clipboard_data
+            .set_data("text", text)
+            .expect("Data to be set");
+        let event = Event::new("paste").expect("valid event");
+        js_sys::Reflect::set(
+            &event,
+            &JsValue::from_str("clipboardData"),
+            &clipboard_data,
+        )
+        .expect("Clipboard data to be set");
+        target
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
     static TEST_ID: &str = "test_monetary_input";
 
     #[wasm_bindgen_test]
-    fn filter_digits_works() {
+    fn convert_digit_string_to_monetary_works() {
         let tests = vec![
-            ("092019828832l", "092019828832"),
-            ("232jhdsa878dsa", "232878"),
-            ("+242jhdsa878ds", "242878"),
-            ("2f", "2"),
-            ("-", ""),
-            ("三", ""),
-            ("1¾", "1"),
-            ("8.34", "834"),
-            ("128.97 €", "12897"),
+            ("", "0.00"),
+            ("-", "0.00"),
+            ("jdsjakhsd", "0.00"),
+            ("¾", "0.00"),
+            ("0", "0.00"),
+            ("1", "0.01"),
+            ("12", "0.12"),
+            ("128", "1.28"),
+            ("128", "1.28"),
+            ("1289", "12.89"),
+            ("12890", "128.90"),
+            ("12890", "128.90"),
+            ("128900", "1,289.00"),
+            ("128900123", "1,289,001.23"),
+            ("00000012890", "128.90"),
+            ("000000128", "1.28"),
+            ("00000012", "0.12"),
+            ("0000000000000", "0.00"),
+            ("9223372036854775807", "92,233,720,368,547,758.07"),
+            ("9223372036854775808", "9,223,372,036,854,775.80"),
         ];
 
         for case in tests {
-            assert_eq!(&filter_digits(case.0), case.1);
+            assert_eq!(
+                &convert_digit_string_to_monetary(
+                    case.0,
+                    Currency::Eur,
+                    Locale::EnUs
+                ),
+                case.1
+            );
         }
     }
 
     #[wasm_bindgen_test]
-    fn truncate_to_valid_i64_works() {
+    fn convert_digit_string_to_monetary_follows_currency_minor_units() {
         let tests = vec![
-            ("9223372036854775807", "9223372036854775807"),
-            ("9223372036854775808", "922337203685477580"),
-            ("999999999999999999", "999999999999999999"),
-            ("9999999999999999999", "999999999999999999"),
-            (
-                "9999999999999999999999999999999999999999999999999999",
-                "999999999999999999",
-            ),
+            ("", Currency::Jpy, "0"),
+            ("1", Currency::Jpy, "1"),
+            ("128900", Currency::Jpy, "128,900"),
+            ("9223372036854775807", Currency::Jpy, "9,223,372,036,854,775,807"),
+            ("", Currency::Bhd, "0.000"),
+            ("1", Currency::Bhd, "0.001"),
+            ("1289", Currency::Bhd, "1.289"),
+            ("128900123", Currency::Bhd, "128,900.123"),
         ];
 
         for case in tests {
-            assert_eq!(&truncate_to_valid_i64(case.0), case.1);
+            assert_eq!(
+                &convert_digit_string_to_monetary(case.0, case.1, Locale::EnUs),
+                case.2
+            );
         }
     }
 
     #[wasm_bindgen_test]
-    fn add_thousands_separator_works() {
+    fn convert_digit_string_to_monetary_follows_locale_separators() {
         let tests = vec![
-            ("123", "123"),
-            ("1234", "1,234"),
-            ("12345", "12,345"),
-            ("dkasmdsakjsdkjas", "d,kas,mds,akj,sdk,jas"),
-            ("999999999999999999999999", "999,999,999,999,999,999,999,999"),
-            ("9999999999999999999999999", "9,999,999,999,999,999,999,999,999"),
+            ("1234900", Locale::FiFi, "12\u{a0}349,00"),
+            ("1234900", Locale::PtBr, "12.349,00"),
+            ("12345678900", Locale::EnIn, "12,34,56,789.00"),
+            ("", Locale::DeDe, "0,00"),
         ];
 
         for case in tests {
-            assert_eq!(&add_thousands_separator(case.0), case.1);
+            assert_eq!(
+                &convert_digit_string_to_monetary(
+                    case.0,
+                    Currency::Eur,
+                    case.1
+                ),
+                case.2
+            );
         }
     }
 
     #[wasm_bindgen_test]
-    fn convert_digit_string_to_monetary_works() {
+    fn caret_after_reformat_works() {
         let tests = vec![
-            ("", "0.00"),
-            ("-", "0.00"),
-            ("jdsjakhsd", "0.00"),
-            ("¾", "0.00"),
-            ("0", "0.00"),
-            ("1", "0.01"),
-            ("12", "0.12"),
-            ("128", "1.28"),
-            ("128", "1.28"),
-            ("1289", "12.89"),
-            ("12890", "128.90"),
-            ("12890", "128.90"),
-            ("128900", "1,289.00"),
-            ("128900123", "1,289,001.23"),
-            ("00000012890", "128.90"),
-            ("000000128", "1.28"),
-            ("00000012", "0.12"),
-            ("0000000000000", "0.00"),
-            ("9223372036854775807", "92,233,720,368,547,758.07"),
-            ("9223372036854775808", "9,223,372,036,854,775.80"),
+            // typing at the end
+            ("0.001", 5, "0.01", 4),
+            ("12,349.001", 10, "123,490.01", 10),
+            // typing in the middle
+            ("12,3549.00", 5, "123,549.00", 5),
+            ("12,349.050", 9, "123,490.50", 9),
+            // typing at the start
+            ("9123.00", 1, "9,123.00", 1),
+            ("50.00", 1, "50.00", 1),
+            // deleting a digit
+            ("12,39.00", 4, "1,239.00", 4),
+            // deleting a separator
+            ("12349.00", 2, "12,349.00", 2),
+            // leading zeros are dropped
+            ("00.012", 0, "0.12", 0),
+            // non-ascii separators
+            ("12\u{a0}3549,00", 5, "123\u{a0}549,00", 5),
+            // caret beyond the digits
+            ("", 0, "0.00", 4),
+        ];
+
+        for (old, caret, new, expected) in tests {
+            assert_eq!(
+                caret_after_reformat(old, caret, new),
+                expected,
+                "{}",
+                old
+            );
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn insert_text_works() {
+        let tests = vec![
+            ("", 0, 0, "+", ("+", 1)),
+            ("12.00", 5, 5, "+", ("12.00+", 6)),
+            ("12.00", 0, 0, "(", ("(12.00", 1)),
+            ("12.00+3", 5, 5, ")", ("12.00)+3", 6)),
+            ("12.00", 2, 5, "*", ("12*", 3)),
+            ("12.00", 5, 2, "*", ("12*", 3)),
+            ("12.00", 9, 9, "-", ("12.00-", 6)),
+            ("1 234,00 €", 10, 10, "/", ("1 234,00 €/", 11)),
+        ];
+
+        for (value, start, end, text, (expected, caret)) in tests {
+            assert_eq!(
+                insert_text(value, start, end, text),
+                (expected.to_owned(), caret),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn parse_amount_works() {
+        let tests = vec![
+            ("", None),
+            ("12,349.00", Some(1234900)),
+            ("fas1234", Some(1234)),
+            ("45.20+12.80", Some(5800)),
+            ("(1+2)*3", Some(900)),
+            ("45.20+", None),
+            ("92233720368547758.07+0.01", None),
         ];
 
         for case in tests {
-            assert_eq!(&convert_digit_string_to_monetary(case.0), case.1);
+            assert_eq!(
+                parse_amount(case.0, Currency::Eur, Locale::EnUs),
+                case.1
+            );
         }
     }
 
     #[wasm_bindgen_test]
     async fn component_contains_input_element() {
-        let props = MonetaryInputProps {
-            id: AttrValue::from(TEST_ID),
-            label: AttrValue::from("Test"),
-        };
+        let props = monetary_input_props_with_id(TEST_ID);
         render_monetary_input(props).await;
 
         let element = DOM::get_input_by_id(TEST_ID);
@@ -248,10 +642,7 @@ mod test {
 
     #[wasm_bindgen_test]
     async fn component_contains_label_element_for_input() {
-        let props = MonetaryInputProps {
-            id: AttrValue::from(TEST_ID),
-            label: AttrValue::from("Test"),
-        };
+        let props = monetary_input_props_with_id(TEST_ID);
         render_monetary_input(props).await;
 
         let element = DOM::get_label_by_for(TEST_ID);
@@ -262,10 +653,8 @@ mod test {
     #[wasm_bindgen_test]
     async fn label_element_has_inner_html_given_by_label_prop() {
         let label = "Test";
-        let props = MonetaryInputProps {
-            id: AttrValue::from(TEST_ID),
-            label: AttrValue::from(label),
-        };
+        let mut props = monetary_input_props_with_id(TEST_ID);
+        props.label = AttrValue::from(label);
         render_monetary_input(props).await;
 
         let element =
@@ -276,10 +665,7 @@ mod test {
 
     #[wasm_bindgen_test]
     async fn input_element_type_is_text() {
-        let props = MonetaryInputProps {
-            id: AttrValue::from(TEST_ID),
-            label: AttrValue::from("Test"),
-        };
+        let props = monetary_input_props_with_id(TEST_ID);
         render_monetary_input(props).await;
 
         let element =
@@ -291,10 +677,7 @@ mod test {
 
     #[wasm_bindgen_test]
     async fn input_element_inputmode_is_numeric() {
-        let props = MonetaryInputProps {
-            id: AttrValue::from(TEST_ID),
-            label: AttrValue::from("Test"),
-        };
+        let props = monetary_input_props_with_id(TEST_ID);
         render_monetary_input(props).await;
 
         let element =
@@ -306,10 +689,7 @@ mod test {
 
     #[wasm_bindgen_test]
     async fn input_element_placeholder_is_string_representing_monetary_zero() {
-        let props = MonetaryInputProps {
-            id: AttrValue::from(TEST_ID),
-            label: AttrValue::from("Test"),
-        };
+        let props = monetary_input_props_with_id(TEST_ID);
         render_monetary_input(props).await;
 
         let element =
@@ -319,12 +699,82 @@ mod test {
         assert_eq!(placeholder, Some("0.00".to_string()));
     }
 
+    #[wasm_bindgen_test]
+    async fn input_element_placeholder_follows_currency_minor_units() {
+        let tests = vec![
+            (Currency::Eur, "0.00"),
+            (Currency::Jpy, "0"),
+            (Currency::Bhd, "0.000"),
+        ];
+
+        for (currency, expected) in tests {
+            let mut props = monetary_input_props_with_id(TEST_ID);
+            props.currency = currency;
+            render_monetary_input(props).await;
+
+            let element =
+                DOM::get_input_by_id(TEST_ID).expect("Input Element to exist");
+            let placeholder = element.get_attribute("placeholder");
+
+            assert_eq!(placeholder, Some(expected.to_string()));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_value_is_formatted_with_locale_on_input_event() {
+        let tests = vec![
+            (Locale::FiFi, "fas0001s234juhda900", "12\u{a0}349,00"),
+            (Locale::PtBr, "fas0001s234juhda900", "12.349,00"),
+            (Locale::PtBr, "12.349,001", "123.490,01"),
+            (Locale::EnIn, "123456789", "12,34,567.89"),
+        ];
+
+        for (locale, input, expected) in tests {
+            let mut props = monetary_input_props_with_id(TEST_ID);
+            props.locale = locale;
+            render_monetary_input(props).await;
+
+            let element = DOM::get_input_by_id(TEST_ID)
+                .expect("Input Element to exist")
+                .dyn_into::<HtmlInputElement>()
+                .expect("Element to be Input");
+
+            element.set_value(input);
+            dispatch_input_event(&element).await;
+
+            assert_eq!(element.value(), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_value_is_formatted_with_currency_on_input_event() {
+        let tests = vec![
+            (Currency::Jpy, "fas1234", "1,234"),
+            (Currency::Jpy, "0", "0"),
+            (Currency::Bhd, "fas1234", "1.234"),
+            (Currency::Bhd, "12349000", "12,349.000"),
+        ];
+
+        for (currency, input, expected) in tests {
+            let mut props = monetary_input_props_with_id(TEST_ID);
+            props.currency = currency;
+            render_monetary_input(props).await;
+
+            let element = DOM::get_input_by_id(TEST_ID)
+                .expect("Input Element to exist")
+                .dyn_into::<HtmlInputElement>()
+                .expect("Element to be Input");
+
+            element.set_value(input);
+            dispatch_input_event(&element).await;
+
+            assert_eq!(element.value(), expected);
+        }
+    }
+
     #[wasm_bindgen_test]
     async fn input_element_value_is_formatted_on_input_event() {
-        let props = MonetaryInputProps {
-            id: AttrValue::from(TEST_ID),
-            label: AttrValue::from("Test"),
-        };
+        let props = monetary_input_props_with_id(TEST_ID);
         render_monetary_input(props).await;
 
         let element = DOM::get_input_by_id(TEST_ID)
@@ -356,4 +806,330 @@ mod test {
             assert_eq!(element.value(), case.1);
         }
     }
+
+    #[wasm_bindgen_test]
+    async fn input_element_value_is_formatted_from_value_prop() {
+        let tests = vec![
+            (None, ""),
+            (Some(0), "0.00"),
+            (Some(12), "0.12"),
+            (Some(1234900), "12,349.00"),
+            (Some(i64::MAX), "92,233,720,368,547,758.07"),
+        ];
+
+        for case in tests {
+            let mut props = monetary_input_props_with_id(TEST_ID);
+            props.value = case.0;
+            render_monetary_input(props).await;
+
+            let element = DOM::get_input_by_id(TEST_ID)
+                .expect("Input Element to exist")
+                .dyn_into::<HtmlInputElement>()
+                .expect("Element to be Input");
+
+            assert_eq!(element.value(), case.1);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_value_is_cleared_when_value_prop_is_cleared() {
+        let output = DOM::get_element_by_id("output")
+            .expect("Element `output` to exist");
+        yew::Renderer::<ClearingMonetaryInput>::with_root(output).render();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+
+        let element = DOM::get_input_by_id(TEST_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+
+        assert_eq!(element.value(), "");
+    }
+
+    #[wasm_bindgen_test]
+    async fn component_executes_given_onvaluechange_with_minor_units() {
+        let emitted: Rc<RefCell<Option<Option<i64>>>> =
+            Rc::new(RefCell::new(None));
+        let onvaluechange = {
+            let emitted = emitted.clone();
+            Callback::from(move |value: Option<i64>| {
+                *emitted.borrow_mut() = Some(value);
+            })
+        };
+        let mut props = monetary_input_props_with_id(TEST_ID);
+        props.onvaluechange = Some(onvaluechange);
+        render_monetary_input(props).await;
+
+        let element = DOM::get_input_by_id(TEST_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+
+        let tests = vec![
+            ("", None),
+            ("fas", None),
+            ("0", Some(0)),
+            ("fas1234", Some(1234)),
+            ("12,349.00", Some(1234900)),
+            ("9223372036854775808", Some(922337203685477580)),
+        ];
+
+        for case in tests {
+            element.set_value(case.0);
+            dispatch_input_event(&element).await;
+
+            assert_eq!(*emitted.borrow(), Some(case.1));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_keeps_expression_until_blur_event() {
+        let props = monetary_input_props_with_id(TEST_ID);
+        render_monetary_input(props).await;
+
+        let element = DOM::get_input_by_id(TEST_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+
+        let tests = vec![
+            ("45.20+12.80", "58.00"),
+            ("(1+2)*3", "9.00"),
+            ("10/3", "3.33"),
+            ("100-0.01", "99.99"),
+            ("1/8", "0.12"),
+        ];
+
+        for (input, expected) in tests {
+            element.set_value(input);
+            dispatch_input_event(&element).await;
+
+            assert_eq!(element.value(), input);
+
+            dispatch_blur_event(&element).await;
+
+            assert_eq!(element.value(), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn operator_buttons_insert_operator_into_input_element() {
+        let props = monetary_input_props_with_id(TEST_ID);
+        render_monetary_input(props).await;
+
+        let element = DOM::get_input_by_id(TEST_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        element.set_value("12.00");
+        dispatch_input_event(&element).await;
+
+        let tests = vec![
+            (0, "Insert plus", "12.00+"),
+            (1, "Insert minus", "12.00+-"),
+            (2, "Insert multiplication", "12.00+-*"),
+            (3, "Insert division", "12.00+-*/"),
+            (4, "Insert opening parenthesis", "12.00+-*/("),
+            (5, "Insert closing parenthesis", "12.00+-*/()"),
+        ];
+
+        for (index, label, expected) in tests {
+            let button = DOM::get_button_by_id(&format!(
+                "{}_operator_{}",
+                TEST_ID, index
+            ))
+            .expect("Button to exist")
+            .dyn_into::<HtmlElement>()
+            .expect("Element to be HtmlElement");
+            assert_eq!(button.get_attribute("type"), Some("button".to_owned()));
+            assert_eq!(
+                button.get_attribute("aria-label"),
+                Some(label.to_owned())
+            );
+
+            button.click();
+            yew::platform::time::sleep(Duration::from_millis(10)).await;
+
+            assert_eq!(element.value(), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_keeps_invalid_expression_on_blur_event() {
+        let props = monetary_input_props_with_id(TEST_ID);
+        render_monetary_input(props).await;
+
+        let element = DOM::get_input_by_id(TEST_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+
+        let tests = vec!["45.20+", "1/0", "92233720368547758.07+0.01"];
+
+        for input in tests {
+            element.set_value(input);
+            dispatch_input_event(&element).await;
+            dispatch_blur_event(&element).await;
+
+            assert_eq!(element.value(), input);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn component_executes_given_onvaluechange_with_evaluated_expression()
+    {
+        let emitted: Rc<RefCell<Option<Option<i64>>>> =
+            Rc::new(RefCell::new(None));
+        let onvaluechange = {
+            let emitted = emitted.clone();
+            Callback::from(move |value: Option<i64>| {
+                *emitted.borrow_mut() = Some(value);
+            })
+        };
+        let mut props = monetary_input_props_with_id(TEST_ID);
+        props.onvaluechange = Some(onvaluechange);
+        render_monetary_input(props).await;
+
+        let element = DOM::get_input_by_id(TEST_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+
+        let tests = vec![
+            ("45.20+", None),
+            ("45.20+12.80", Some(5800)),
+            ("92233720368547758.07+0.01", None),
+        ];
+
+        for case in tests {
+            element.set_value(case.0);
+            dispatch_input_event(&element).await;
+
+            assert_eq!(*emitted.borrow(), Some(case.1));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_caret_is_preserved_on_input_event() {
+        let props = monetary_input_props_with_id(TEST_ID);
+        render_monetary_input(props).await;
+
+        let element = DOM::get_input_by_id(TEST_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+
+        let tests = vec![
+            ("12,3549.00", 5, "123,549.00", 5),
+            ("123,5490.00", 7, "1,235,490.00", 8),
+            ("1,235,49.00", 8, "123,549.00", 7),
+            ("123549.00", 3, "123,549.00", 3),
+            ("0123,549.00", 1, "123,549.00", 0),
+        ];
+
+        for (input, caret, expected_value, expected_caret) in tests {
+            element.set_value(input);
+            element
+                .set_selection_range(caret, caret)
+                .expect("Selection to be set");
+            dispatch_input_event(&element).await;
+
+            assert_eq!(element.value(), expected_value);
+            assert_eq!(element.selection_start(), Ok(Some(expected_caret)));
+            assert_eq!(element.selection_end(), Ok(Some(expected_caret)));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_value_is_interpreted_on_paste_event() {
+        let emitted: Rc<RefCell<Option<Option<i64>>>> =
+            Rc::new(RefCell::new(None));
+        let onvaluechange = {
+            let emitted = emitted.clone();
+            Callback::from(move |value: Option<i64>| {
+                *emitted.borrow_mut() = Some(value);
+            })
+        };
+        let mut props = monetary_input_props_with_id(TEST_ID);
+        props.onvaluechange = Some(onvaluechange);
+        render_monetary_input(props).await;
+
+        let element = DOM::get_input_by_id(TEST_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+
+        let tests = vec![
+            ("12.5", "12.50", 1250),
+            ("1.234,56 €", "1,234.56", 123456),
+            ("€12.50", "12.50", 1250),
+            ("1,234.56 USD", "1,234.56", 123456),
+        ];
+
+        for (input, expected_value, expected_amount) in tests {
+            element.select();
+            dispatch_paste_event(&element, input).await;
+
+            assert_eq!(element.value(), expected_value);
+            assert_eq!(*emitted.borrow(), Some(Some(expected_amount)));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_value_is_pasted_at_selection() {
+        let emitted: Rc<RefCell<Option<Option<i64>>>> =
+            Rc::new(RefCell::new(None));
+        let onvaluechange = {
+            let emitted = emitted.clone();
+            Callback::from(move |value: Option<i64>| {
+                *emitted.borrow_mut() = Some(value);
+            })
+        };
+        let mut props = monetary_input_props_with_id(TEST_ID);
+        props.onvaluechange = Some(onvaluechange);
+        render_monetary_input(props).await;
+
+        let element = DOM::get_input_by_id(TEST_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+
+        let tests = vec![
+            ("45.20+", 6, 6, "12.8", "45.20+12.80", 5800),
+            ("45.20+1.00", 6, 10, "€12.80", "45.20+12.80", 5800),
+            ("", 0, 0, "-12.50", "-12.50", -1250),
+            ("10.00", 0, 5, "-1.234,56 €", "-1,234.56", -123456),
+            ("45.20+", 6, 6, "-12.80", "45.20+-12.80", 3240),
+        ];
+
+        for (value, start, end, pasted, expected_value, expected_amount) in
+            tests
+        {
+            element.set_value(value);
+            element
+                .set_selection_range(start, end)
+                .expect("Selection to be set");
+            dispatch_paste_event(&element, pasted).await;
+
+            assert_eq!(element.value(), expected_value);
+            assert_eq!(*emitted.borrow(), Some(Some(expected_amount)));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn input_element_is_described_by_given_error() {
+        let mut props = monetary_input_props_with_id(TEST_ID);
+        props.error = Some(AttrValue::from("Must be greater than zero"));
+        render_monetary_input(props).await;
+
+        let element =
+            DOM::get_input_by_id(TEST_ID).expect("Input Element to exist");
+        let error_id = format!("{}_error", TEST_ID);
+        let error =
+            DOM::get_element_by_id(&error_id).expect("Error Element to exist");
+
+        assert_eq!(element.get_attribute("aria-invalid"), Some("true".into()));
+        assert_eq!(element.get_attribute("aria-describedby"), Some(error_id));
+        assert_eq!(&error.inner_html(), "Must be greater than zero");
+    }
 }
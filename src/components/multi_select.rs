@@ -0,0 +1,489 @@
+use std::rc::Rc;
+
+use web_sys::{
+    HtmlInputElement,
+    KeyboardEvent,
+    MouseEvent,
+};
+use yew::{
+    function_component,
+    html,
+    use_effect_with_deps,
+    use_state_eq,
+    AttrValue,
+    Callback,
+    Html,
+    Properties,
+    TargetCast,
+};
+
+use crate::{
+    components::Combobox,
+    types::select::SelectOption,
+};
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct MultiSelectProps {
+    pub(crate) id: AttrValue,
+    pub(crate) label: AttrValue,
+    pub(crate) options: Rc<[SelectOption]>,
+    #[prop_or_default]
+    pub(crate) max: Option<usize>,
+    #[prop_or_default]
+    pub(crate) creatable: bool,
+    #[prop_or_default]
+    pub(crate) onchange: Option<Callback<Vec<AttrValue>>>,
+}
+
+#[function_component(MultiSelect)]
+pub(crate) fn multi_select(props: &MultiSelectProps) -> Html {
+    let selected = use_state_eq(|| selected_values(&props.options));
+    {
+        let selected = selected.clone();
+        use_effect_with_deps(
+            move |options| selected.set(merge_selected(&selected, options)),
+            props.options.clone(),
+        );
+    }
+
+    let update = {
+        let selected = selected.clone();
+        let onchange = props.onchange.clone();
+        Callback::from(move |values: Vec<AttrValue>| {
+            if let Some(onchange) = &onchange {
+                onchange.emit(values.clone());
+            }
+            selected.set(values);
+        })
+    };
+
+    let add = {
+        let selected = selected.clone();
+        let update = update.clone();
+        let max = props.max;
+        Callback::from(move |value: AttrValue| {
+            if let Some(values) = add_value(&selected, value, max) {
+                update.emit(values);
+            }
+        })
+    };
+
+    let remove = {
+        let selected = selected.clone();
+        let update = update.clone();
+        Callback::from(move |value: AttrValue| {
+            update.emit(remove_value(&selected, &value));
+        })
+    };
+
+    let onkeydown = {
+        let selected = selected.clone();
+        let remove = remove.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let is_empty = e
+                .target_dyn_into::<HtmlInputElement>()
+                .map(|input| input.value().is_empty())
+                .unwrap_or_default();
+            if e.key() == "Backspace" && is_empty {
+                if let Some(last) = selected.last() {
+                    remove.emit(last.clone());
+                }
+            }
+        })
+    };
+
+    let is_full = props.max.is_some_and(|max| selected.len() >= max);
+    let available: Rc<[SelectOption]> = match is_full {
+        true => Rc::new([]),
+        false => props
+            .options
+            .iter()
+            .filter(|option| !selected.contains(&option.value))
+            .map(|option| option.clone().selected(false))
+            .collect(),
+    };
+    let oncreate = match props.creatable && !is_full {
+        true => Some(add.clone()),
+        false => None,
+    };
+
+    html! {
+        <section id={format!("{}_section", props.id)}>
+            <ul
+                id={format!("{}_chips", props.id)}
+                aria-label={format!("Selected {}", props.label)}
+            >
+            {
+                selected.iter().enumerate().map(|(index, value)| {
+                    let text = option_text(&props.options, value);
+                    let onclick = {
+                        let remove = remove.clone();
+                        let value = value.clone();
+                        Callback::from(move |_: MouseEvent| {
+                            remove.emit(value.clone())
+                        })
+                    };
+                    let onkeydown = {
+                        let remove = remove.clone();
+                        let value = value.clone();
+                        Callback::from(move |e: KeyboardEvent| {
+                            if matches!(e.key().as_str(), "Backspace" | "Delete") {
+                                e.prevent_default();
+                                remove.emit(value.clone());
+                            }
+                        })
+                    };
+                    html! {
+                        <li key={value.as_str()}>
+                            { text.clone() }
+                            <button
+                                id={format!("{}_remove_{}", props.id, index)}
+                                type={"button"}
+                                aria-label={format!("Remove {}", text)}
+                                {onclick}
+                                {onkeydown}
+                            >
+                                { "×" }
+                            </button>
+                        </li>
+                    }
+                }).collect::<Html>()
+            }
+            </ul>
+            <Combobox
+                id={props.id.clone()}
+                label={props.label.clone()}
+                options={available}
+                onselect={add}
+                {oncreate}
+                {onkeydown}
+                clear_on_choose={true}
+            />
+            if is_full {
+                <p id={format!("{}_limit", props.id)}>
+                    { format!("At most {} can be selected", props.max.unwrap_or_default()) }
+                </p>
+            }
+        </section>
+    }
+}
+
+fn selected_values(options: &[SelectOption]) -> Vec<AttrValue> {
+    options
+        .iter()
+        .filter(|option| option.selected)
+        .map(|option| option.value.clone())
+        .collect()
+}
+
+fn merge_selected(
+    selected: &[AttrValue],
+    options: &[SelectOption],
+) -> Vec<AttrValue> {
+    let mut values = selected.to_vec();
+    for value in selected_values(options) {
+        if !values.contains(&value) {
+            values.push(value);
+        }
+    }
+    values
+}
+
+fn option_text(options: &[SelectOption], value: &AttrValue) -> String {
+    options
+        .iter()
+        .find(|option| &option.value == value)
+        .map(|option| option.text())
+        .unwrap_or_else(|| value.to_string())
+}
+
+fn add_value(
+    selected: &[AttrValue],
+    value: AttrValue,
+    max: Option<usize>,
+) -> Option<Vec<AttrValue>> {
+    let is_full = max.is_some_and(|max| selected.len() >= max);
+    if is_full || value.trim().is_empty() || selected.contains(&value) {
+        return None;
+    }
+
+    let mut values = selected.to_vec();
+    values.push(value);
+    Some(values)
+}
+
+fn remove_value(selected: &[AttrValue], value: &AttrValue) -> Vec<AttrValue> {
+    selected.iter().filter(|v| *v != value).cloned().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+        time::Duration,
+    };
+
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use web_sys::{
+        Event,
+        HtmlElement,
+        HtmlInputElement,
+        KeyboardEvent,
+        KeyboardEventInit,
+    };
+    use yew::{
+        AttrValue,
+        Callback,
+    };
+
+    use super::{
+        add_value,
+        merge_selected,
+        remove_value,
+        MultiSelect,
+        MultiSelectProps,
+    };
+    use crate::{
+        dom::DOM,
+        types::select::SelectOption,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    async fn render_multi_select(props: MultiSelectProps) {
+        let output = DOM::get_element_by_id("output")
+            .expect("Element `output` to exist");
+        yew::Renderer::<MultiSelect>::with_root_and_props(output, props)
+            .render();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    fn multi_select_props_with_id(id: &str) -> MultiSelectProps {
+        MultiSelectProps {
+            id: AttrValue::from(id.to_owned()),
+            label: AttrValue::from("Tags"),
+            options: ["Holiday", "Work", "Gift"]
+                .into_iter()
+                .map(SelectOption::from)
+                .collect(),
+            max: None,
+            creatable: false,
+            onchange: None,
+        }
+    }
+
+    fn get_chips(id: &str) -> Vec<String> {
+        let list = DOM::get_element_by_id(&format!("{}_chips", id))
+            .expect("Chip list to exist");
+        let mut chips = vec![];
+        for index in 0..list.children().length() {
+            let chip = list.children().item(index).expect("Element to exist");
+            let text = chip.first_child().and_then(|c| c.text_content());
+            chips.push(text.unwrap_or_default());
+        }
+        chips
+    }
+
+    fn get_input(id: &str) -> HtmlInputElement {
+        DOM::get_input_by_id(id)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input")
+    }
+
+    async fn type_query(target: &HtmlInputElement, query: &str) {
+        target.set_value(query);
+        let event = Event::new("input").expect("valid event");
+        target
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    async fn dispatch_keydown_event(target: &HtmlElement, key: &str) {
+        let mut init = KeyboardEventInit::new();
+        init.key(key);
+        let event =
+            KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init)
+                .expect("valid event");
+        target
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    async fn choose(target: &HtmlInputElement, query: &str) {
+        type_query(target, query).await;
+        dispatch_keydown_event(target, "ArrowDown").await;
+        dispatch_keydown_event(target, "Enter").await;
+    }
+
+    static TEST_ID: &str = "test_multi_select";
+
+    #[wasm_bindgen_test]
+    fn add_value_works() {
+        let selected = vec![AttrValue::from("Work")];
+        let tests = vec![
+            ("Gift", None, Some(vec!["Work", "Gift"])),
+            ("Work", None, None),
+            ("  ", None, None),
+            ("Gift", Some(2), Some(vec!["Work", "Gift"])),
+            ("Gift", Some(1), None),
+        ];
+
+        for (value, max, expected) in tests {
+            let values = add_value(&selected, AttrValue::from(value), max);
+            let expected = expected.map(|list: Vec<&str>| {
+                list.into_iter().map(AttrValue::from).collect::<Vec<_>>()
+            });
+            assert_eq!(values, expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn merge_selected_keeps_picked_values() {
+        let selected = vec![AttrValue::from("Work"), AttrValue::from("Gift")];
+        let options = vec![
+            SelectOption::from("Gift").selected(true),
+            SelectOption::from("Holiday").selected(true),
+            SelectOption::from("Work"),
+        ];
+
+        let values = merge_selected(&selected, &options);
+        let expected: Vec<AttrValue> = ["Work", "Gift", "Holiday"]
+            .into_iter()
+            .map(AttrValue::from)
+            .collect();
+
+        assert_eq!(values, expected);
+        assert_eq!(merge_selected(&[], &[]), Vec::<AttrValue>::new());
+    }
+
+    #[wasm_bindgen_test]
+    fn remove_value_works() {
+        let selected = vec![AttrValue::from("Work"), AttrValue::from("Gift")];
+        let tests = vec![
+            ("Work", vec!["Gift"]),
+            ("Gift", vec!["Work"]),
+            ("Holiday", vec!["Work", "Gift"]),
+        ];
+
+        for (value, expected) in tests {
+            let values = remove_value(&selected, &AttrValue::from(value));
+            let expected: Vec<AttrValue> =
+                expected.into_iter().map(AttrValue::from).collect();
+            assert_eq!(values, expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn component_has_chips_for_selected_options() {
+        let mut props = multi_select_props_with_id(TEST_ID);
+        props.options = ["Holiday", "Work", "Gift"]
+            .into_iter()
+            .map(|s| SelectOption::from(s).selected(s != "Work"))
+            .collect();
+        render_multi_select(props).await;
+
+        assert_eq!(get_chips(TEST_ID), vec!["Holiday", "Gift"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn choosing_option_adds_chip_and_executes_onchange() {
+        let emitted = Rc::new(RefCell::new(vec![]));
+        let onchange = {
+            let emitted = emitted.clone();
+            Callback::from(move |values: Vec<AttrValue>| {
+                *emitted.borrow_mut() = values;
+            })
+        };
+        let mut props = multi_select_props_with_id(TEST_ID);
+        props.onchange = Some(onchange);
+        render_multi_select(props).await;
+
+        let input = get_input(TEST_ID);
+        choose(&input, "work").await;
+        choose(&input, "gift").await;
+
+        assert_eq!(get_chips(TEST_ID), vec!["Work", "Gift"]);
+        assert_eq!(
+            *emitted.borrow(),
+            vec![AttrValue::from("Work"), AttrValue::from("Gift")]
+        );
+        assert_eq!(input.value(), "");
+    }
+
+    #[wasm_bindgen_test]
+    async fn creatable_component_adds_free_form_chip() {
+        let mut props = multi_select_props_with_id(TEST_ID);
+        props.creatable = true;
+        render_multi_select(props).await;
+
+        let input = get_input(TEST_ID);
+        choose(&input, "Birthday").await;
+
+        assert_eq!(get_chips(TEST_ID), vec!["Birthday"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn backspace_on_empty_input_removes_last_chip() {
+        let mut props = multi_select_props_with_id(TEST_ID);
+        props.options = ["Holiday", "Work", "Gift"]
+            .into_iter()
+            .map(|s| SelectOption::from(s).selected(true))
+            .collect();
+        render_multi_select(props).await;
+
+        let input = get_input(TEST_ID);
+        dispatch_keydown_event(&input, "Backspace").await;
+
+        assert_eq!(get_chips(TEST_ID), vec!["Holiday", "Work"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn delete_on_chip_button_removes_chip() {
+        let mut props = multi_select_props_with_id(TEST_ID);
+        props.options = ["Holiday", "Work", "Gift"]
+            .into_iter()
+            .map(|s| SelectOption::from(s).selected(true))
+            .collect();
+        render_multi_select(props).await;
+
+        let button = DOM::get_button_by_id(&format!("{}_remove_1", TEST_ID))
+            .expect("Button Element to exist")
+            .dyn_into::<HtmlElement>()
+            .expect("Element to be HtmlElement");
+
+        assert_eq!(
+            button.get_attribute("aria-label"),
+            Some("Remove Work".into())
+        );
+
+        dispatch_keydown_event(&button, "Delete").await;
+
+        assert_eq!(get_chips(TEST_ID), vec!["Holiday", "Gift"]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn no_more_options_are_offered_when_max_is_reached() {
+        let mut props = multi_select_props_with_id(TEST_ID);
+        props.max = Some(1);
+        render_multi_select(props).await;
+
+        let input = get_input(TEST_ID);
+        choose(&input, "work").await;
+        type_query(&input, "gift").await;
+
+        let listbox = DOM::get_element_by_id(&format!("{}_listbox", TEST_ID));
+        let limit = DOM::get_element_by_id(&format!("{}_limit", TEST_ID));
+
+        assert_eq!(get_chips(TEST_ID), vec!["Work"]);
+        assert!(listbox.is_none());
+        assert!(limit.is_some());
+    }
+}
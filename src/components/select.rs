@@ -19,10 +19,15 @@ pub(crate) struct SelectProps {
     pub(crate) options: Rc<[SelectOption]>,
     #[prop_or_default]
     pub(crate) onchange: Option<Callback<Event>>,
+    #[prop_or_default]
+    pub(crate) error: Option<AttrValue>,
 }
 
 #[function_component(Select)]
 pub(crate) fn select(props: &SelectProps) -> Html {
+    let error_id = format!("{}_error", props.id);
+    let is_invalid = props.error.is_some();
+
     html! {
         <section id={format!("{}_section", props.id)}>
             <label
@@ -34,24 +39,54 @@ pub(crate) fn select(props: &SelectProps) -> Html {
             <select
                 id={props.id.clone()}
                 onchange={props.onchange.clone()}
+                aria-invalid={is_invalid.then_some("true")}
+                aria-describedby={is_invalid.then_some(error_id.clone())}
             >
             {
-                props.options.iter().map(|option| {
-                    let key = option.value.as_str();
-                    let value = &option.value;
-                    let inner_html = &option.inner_html;
-                    let selected = option.selected;
-                    let disabled = option.disabled;
-                    html! {
-                        <option {key} {value} {selected} {disabled}>{inner_html}</option>
+                group_options(&props.options).into_iter().flat_map(|(group, options)| {
+                    let options = options.into_iter().map(|option| {
+                        let key = option.value.as_str();
+                        let value = &option.value;
+                        let inner_html = &option.inner_html;
+                        let selected = option.selected;
+                        let disabled = option.disabled;
+                        html! {
+                            <option {key} {value} {selected} {disabled}>{inner_html}</option>
+                        }
+                    });
+                    match group {
+                        Some(label) => vec![html! {
+                            <optgroup key={format!("optgroup_{}", label)} {label}>
+                                { for options }
+                            </optgroup>
+                        }],
+                        None => options.collect(),
                     }
                 }).collect::<Html>()
             }
             </select>
+            if let Some(error) = props.error.clone() {
+                <p id={error_id} class={"error"}>{ error }</p>
+            }
         </section>
     }
 }
 
+fn group_options(
+    options: &[SelectOption],
+) -> Vec<(Option<AttrValue>, Vec<&SelectOption>)> {
+    let mut groups: Vec<(Option<AttrValue>, Vec<&SelectOption>)> = Vec::new();
+    for option in options {
+        match groups.last_mut() {
+            Some((group, members)) if *group == option.group => {
+                members.push(option)
+            },
+            _ => groups.push((option.group.clone(), vec![option])),
+        }
+    }
+    groups
+}
+
 #[cfg(test)]
 mod test {
     use std::{
@@ -76,6 +111,7 @@ mod test {
     };
 
     use super::{
+        group_options,
         Select,
         SelectProps,
     };
@@ -99,6 +135,7 @@ mod test {
             label: AttrValue::from(""),
             options: Rc::new([]),
             onchange: None,
+            error: None,
         }
     }
 
@@ -261,6 +298,69 @@ mod test {
         assert_eq!(disabled_options, disabled);
     }
 
+    #[wasm_bindgen_test]
+    fn group_options_groups_consecutive_options() {
+        let food = Some(AttrValue::from("Food"));
+        let options = vec![
+            SelectOption::from("Food"),
+            SelectOption::from("Groceries").group(food.clone()),
+            SelectOption::from("Restaurants").group(food.clone()),
+            SelectOption::from("Rent"),
+            SelectOption::from("Transport"),
+        ];
+        let groups: Vec<(Option<AttrValue>, Vec<&str>)> =
+            group_options(&options)
+                .into_iter()
+                .map(|(group, members)| {
+                    let values =
+                        members.iter().map(|o| o.value.as_str()).collect();
+                    (group, values)
+                })
+                .collect();
+
+        let expected = vec![
+            (None, vec!["Food"]),
+            (food, vec!["Groceries", "Restaurants"]),
+            (None, vec!["Rent", "Transport"]),
+        ];
+
+        assert_eq!(groups, expected);
+    }
+
+    #[wasm_bindgen_test]
+    async fn select_element_has_given_option_groups() {
+        let food = Some(AttrValue::from("Food"));
+        let mut props = select_props_with_id(TEST_ID);
+        props.options = Rc::new([
+            SelectOption::from("Food"),
+            SelectOption::from("Groceries").group(food.clone()),
+            SelectOption::from("Restaurants").group(food),
+            SelectOption::from("Rent"),
+        ]);
+        render_select(props).await;
+
+        let element = DOM::get_html_select_by_id(TEST_ID)
+            .expect("Html Select Element to exist");
+        let children = element.children();
+        let mut tags = vec![];
+        for index in 0..children.length() {
+            let child = children.item(index).expect("Element to exist");
+            tags.push((child.tag_name(), child.get_attribute("label")));
+        }
+        let optgroup = children.item(1).expect("Element to exist");
+
+        assert_eq!(
+            tags,
+            vec![
+                (String::from("OPTION"), None),
+                (String::from("OPTGROUP"), Some(String::from("Food"))),
+                (String::from("OPTION"), None),
+            ]
+        );
+        assert_eq!(optgroup.children().length(), 2);
+        assert_eq!(element.length(), 4);
+    }
+
     #[wasm_bindgen_test]
     async fn component_executes_given_onchange() {
         let options = vec!["First", "Second", "Third", "Fourth"];
@@ -294,6 +394,23 @@ mod test {
         assert_eq!(onchange_output, change_to.1);
     }
 
+    #[wasm_bindgen_test]
+    async fn select_element_is_described_by_given_error() {
+        let mut props = select_props_with_id(TEST_ID);
+        props.error = Some(AttrValue::from("This field is required"));
+        render_select(props).await;
+
+        let element =
+            DOM::get_select_by_id(TEST_ID).expect("Select Element to exist");
+        let error_id = format!("{}_error", TEST_ID);
+        let error =
+            DOM::get_element_by_id(&error_id).expect("Error Element to exist");
+
+        assert_eq!(element.get_attribute("aria-invalid"), Some("true".into()));
+        assert_eq!(element.get_attribute("aria-describedby"), Some(error_id));
+        assert_eq!(&error.inner_html(), "This field is required");
+    }
+
     #[wasm_bindgen_test]
     async fn component_contains_label_element_for_select() {
         let props = select_props_with_id(TEST_ID);
@@ -1,6 +1,7 @@
 mod components;
 pub mod pages;
 mod requests;
+pub mod route;
 mod types;
 
 #[cfg(test)]
@@ -1,4 +1,7 @@
-use possu_web::pages;
+use possu_web::{
+    pages,
+    route::Route,
+};
 use yew::{
     function_component,
     html,
@@ -8,24 +11,18 @@ use yew_router::{
     components::Redirect,
     router::BrowserRouter,
     switch::Switch,
-    Routable,
 };
 
-#[derive(Clone, PartialEq, Routable)]
-enum Route {
-    #[at("/")]
-    Login,
-    #[at("/entries/new")]
-    NewEntry,
-    #[not_found]
-    #[at("/404")]
-    NotFound,
-}
-
 fn switch(route: Route) -> Html {
     match route {
         Route::Login => html! { <pages::Login /> },
+        Route::Dashboard => html! { <pages::Dashboard /> },
+        Route::Entries => html! { <pages::Entries /> },
         Route::NewEntry => html! { <pages::NewEntry /> },
+        Route::Rules => html! { <pages::Rules /> },
+        Route::Budgets => html! { <pages::Budgets /> },
+        Route::Goals => html! { <pages::Goals /> },
+        Route::Reports => html! { <pages::Reports /> },
         Route::NotFound => html! {
             <Redirect<Route> to={Route::Login} />
         },
@@ -0,0 +1,394 @@
+use std::rc::Rc;
+
+use chrono::{
+    Datelike,
+    Utc,
+};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{
+    Event,
+    HtmlInputElement,
+    MouseEvent,
+};
+use yew::{
+    function_component,
+    html,
+    use_effect_with_deps,
+    use_state_eq,
+    AttrValue,
+    Callback,
+    Html,
+    TargetCast,
+};
+
+use crate::{
+    components::{
+        reset_form,
+        Combobox,
+        Form,
+        FormValues,
+        MonetaryInput,
+    },
+    requests,
+    types::{
+        budget::{
+            budget_status,
+            Budget,
+            BudgetStatus,
+        },
+        category,
+        currency::Currency,
+        datetime::Month,
+        entry::Entry,
+        exchange_rate::ExchangeRate,
+        locale::Locale,
+        money::Money,
+        select::SelectOption,
+        settings::Settings,
+    },
+};
+
+const FORM_ID: &str = "budget_form";
+
+#[function_component(Budgets)]
+pub fn budgets() -> Html {
+    let budgets = use_state_eq(Vec::<Budget>::default);
+    let entries = use_state_eq(Vec::<Entry>::default);
+    let rates = use_state_eq(Vec::<ExchangeRate>::default);
+    let categories = use_state_eq(Vec::<SelectOption>::default);
+    let currency = use_state_eq(Currency::default);
+    let timezone = use_state_eq(|| Settings::default().timezone);
+    {
+        let budgets = budgets.clone();
+        let entries = entries.clone();
+        let rates = rates.clone();
+        let categories = categories.clone();
+        let currency = currency.clone();
+        let timezone = timezone.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    if let Ok(tree) = requests::get_categories().await {
+                        categories.set(category::select_options(&tree));
+                    }
+                    if let Ok(settings) = requests::get_settings().await {
+                        currency.set(settings.default_currency);
+                        timezone.set(settings.timezone);
+                    }
+                    if let Ok(list) = requests::get_entries().await {
+                        if let Ok(list) =
+                            requests::get_exchange_rates_for(&list).await
+                        {
+                            rates.set(list);
+                        }
+                        entries.set(list);
+                    }
+                    if let Ok(list) = requests::get_budgets().await {
+                        budgets.set(list);
+                    }
+                });
+            },
+            (),
+        );
+    }
+    let save_budgets = {
+        let budgets = budgets.clone();
+        Callback::from(move |list: Vec<Budget>| {
+            let budgets = budgets.clone();
+            spawn_local(async move {
+                if requests::put_budgets(&list).await.is_ok() {
+                    budgets.set(list);
+                }
+            });
+        })
+    };
+
+    let today = Utc::now().with_timezone(&*timezone).date_naive();
+    let (year, month) = (today.year(), Month::from_u32(today.month()));
+    let locale = Locale::from_browser();
+
+    let budget_category = use_state_eq(|| None::<AttrValue>);
+    let amount = use_state_eq(|| None::<i64>);
+    let rollover = use_state_eq(|| false);
+    let can_save = budget_category.is_some() && amount.is_some_and(|a| a > 0);
+
+    let category_options: Vec<SelectOption> = categories
+        .iter()
+        .map(|o| {
+            o.clone()
+                .selected(Some(&o.value) == budget_category.as_ref())
+        })
+        .collect();
+
+    // ONCHANGE
+    let onselect_category = {
+        let budget_category = budget_category.clone();
+        Callback::from(move |value| budget_category.set(Some(value)))
+    };
+    let onchange_amount = {
+        let amount = amount.clone();
+        Callback::from(move |value| amount.set(value))
+    };
+    let onchange_rollover = {
+        let rollover = rollover.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                rollover.set(input.checked());
+            }
+        })
+    };
+
+    // SUBMIT
+    let onsubmit = {
+        let budgets = budgets.clone();
+        let save_budgets = save_budgets.clone();
+        let budget = match (budget_category.as_ref(), *amount) {
+            (Some(category), Some(minor)) => Some(Budget {
+                category: category.to_string(),
+                amount: Money::new(minor, *currency),
+                rollover: *rollover,
+                since: today.with_day(1).unwrap_or(today),
+            }),
+            _ => None,
+        };
+        Callback::from(move |_: FormValues| {
+            if let (Some(budget), true) = (budget.clone(), can_save) {
+                let mut list = (*budgets).clone();
+                match list.iter_mut().find(|b| b.category == budget.category) {
+                    Some(existing) => {
+                        *existing = Budget {
+                            since: existing.since,
+                            ..budget
+                        }
+                    },
+                    None => list.push(budget),
+                }
+                save_budgets.emit(list);
+                reset_form(FORM_ID);
+            }
+        })
+    };
+    let onreset = {
+        let budget_category = budget_category.clone();
+        let amount = amount.clone();
+        let rollover = rollover.clone();
+        Callback::from(move |_| {
+            budget_category.set(None);
+            amount.set(None);
+            rollover.set(false);
+        })
+    };
+
+    html! {
+        <section id={"budgets"}>
+            <table id={"budgets_table"}>
+                <caption>{ format!("Budgets for {} {}", month, year) }</caption>
+                <tbody>
+                {
+                    budgets.iter().enumerate().map(|(index, budget)| {
+                        let status = budget_status(budget, &entries, &rates, &*timezone, year, month);
+                        let onclick_delete = {
+                            let budgets = budgets.clone();
+                            let save_budgets = save_budgets.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                let mut list = (*budgets).clone();
+                                list.remove(index);
+                                save_budgets.emit(list);
+                            })
+                        };
+                        html! {
+                            <tr
+                                id={format!("budget_{}", index)}
+                                class={status.is_some_and(|s| s.is_over()).then_some("over-budget")}
+                            >
+                                <th scope={"row"}>{ budget.category.clone() }</th>
+                                <td>
+                                if let Some(status) = status {
+                                    <progress
+                                        id={format!("budget_progress_{}", index)}
+                                        max={status.budgeted.minor.max(0).to_string()}
+                                        value={status.spent.minor.clamp(0, status.budgeted.minor.max(0)).to_string()}
+                                        aria-label={format!("{} budget used", budget.category)}
+                                    />
+                                }
+                                </td>
+                                <td id={format!("budget_summary_{}", index)}>
+                                {
+                                    match status {
+                                        Some(status) => summary(&status, locale),
+                                        None => String::from("Unavailable"),
+                                    }
+                                }
+                                </td>
+                                <td>
+                                    <button
+                                        id={format!("budget_delete_{}", index)}
+                                        type={"button"}
+                                        aria-label={format!("Delete budget for {}", budget.category)}
+                                        onclick={onclick_delete}
+                                    >
+                                        { "×" }
+                                    </button>
+                                </td>
+                            </tr>
+                        }
+                    }).collect::<Html>()
+                }
+                </tbody>
+            </table>
+            <Form id={FORM_ID} {onsubmit} {onreset}>
+                <Combobox
+                    id={"budget_category_select"}
+                    label={"Category"}
+                    options={Rc::from(category_options)}
+                    onselect={onselect_category}
+                />
+                <MonetaryInput
+                    id={"budget_amount_input"}
+                    label={"Monthly budget"}
+                    currency={*currency}
+                    {locale}
+                    value={*amount}
+                    onvaluechange={onchange_amount}
+                />
+                <section id={"budget_rollover_input_section"}>
+                    <input
+                        id={"budget_rollover_input"}
+                        name={"rollover"}
+                        type={"checkbox"}
+                        checked={*rollover}
+                        onchange={onchange_rollover}
+                    />
+                    <label for={"budget_rollover_input"}>
+                        { "Roll over unspent amounts" }
+                    </label>
+                </section>
+                <button
+                    id={"save_budget_button"}
+                    type={"submit"}
+                    disabled={!can_save}
+                >
+                    { "Save budget" }
+                </button>
+            </Form>
+        </section>
+    }
+}
+
+fn summary(status: &BudgetStatus, locale: Locale) -> String {
+    let currency = status.budgeted.currency;
+    let spent = format!(
+        "Spent {} of {} {}",
+        status.spent.format(locale),
+        status.budgeted.format(locale),
+        currency
+    );
+    let remaining = match status.remaining() {
+        Some(remaining) if status.is_over() => remaining
+            .checked_neg()
+            .map(|over| format!("{} over", over.format(locale))),
+        Some(remaining) => Some(format!("{} left", remaining.format(locale))),
+        None => None,
+    };
+    match (remaining, status.carried.minor) {
+        (None, _) => spent,
+        (Some(remaining), 0) => format!("{}, {}", spent, remaining),
+        (Some(remaining), _) => format!(
+            "{}, {} (includes {} rolled over)",
+            spent,
+            remaining,
+            status.carried.format(locale)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        summary,
+        Budgets,
+    };
+    use crate::{
+        dom::DOM,
+        types::{
+            budget::BudgetStatus,
+            locale::Locale,
+            money::eur,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    async fn render_budgets() {
+        let output = DOM::get_element_by_id("output")
+            .expect("Element `output` to exist");
+        yew::Renderer::<Budgets>::with_root(output).render();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    #[wasm_bindgen_test]
+    fn summary_shows_spent_remaining_and_rollover() {
+        let tests = vec![
+            (
+                (eur(10000), eur(0), eur(3000)),
+                "Spent 30.00 of 100.00 EUR, 70.00 left",
+            ),
+            (
+                (eur(10000), eur(0), eur(12050)),
+                "Spent 120.50 of 100.00 EUR, 20.50 over",
+            ),
+            (
+                (eur(12000), eur(2000), eur(3000)),
+                "Spent 30.00 of 120.00 EUR, 90.00 left (includes 20.00 \
+                 rolled over)",
+            ),
+        ];
+
+        for ((budgeted, carried, spent), expected) in tests {
+            let status = BudgetStatus {
+                budgeted,
+                carried,
+                spent,
+            };
+            assert_eq!(summary(&status, Locale::default()), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn page_contains_budget_fields() {
+        render_budgets().await;
+
+        let tests = vec![
+            ("budget_category_select", "Category"),
+            ("budget_amount_input", "Monthly budget"),
+            ("budget_rollover_input", "Roll over unspent amounts"),
+        ];
+
+        for (id, label) in tests {
+            let element = DOM::get_input_by_id(id).expect("Input to exist");
+            let label_element =
+                DOM::get_label_by_for(id).expect("Label to exist");
+
+            assert!(DOM::is_element_visible(&element));
+            assert_eq!(&label_element.inner_html(), label);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn save_button_is_disabled_until_budget_is_complete() {
+        render_budgets().await;
+
+        let button = DOM::get_button_by_id("save_budget_button")
+            .expect("Button Element to exist");
+
+        assert!(button.has_attribute("disabled"));
+        assert!(DOM::get_element_by_id("budgets_table").is_some());
+    }
+}
@@ -0,0 +1,302 @@
+use std::rc::Rc;
+
+use chrono::{
+    Datelike,
+    Utc,
+};
+use wasm_bindgen_futures::spawn_local;
+use yew::{
+    function_component,
+    html,
+    use_effect_with_deps,
+    use_state_eq,
+    AttrValue,
+    Html,
+};
+
+use crate::{
+    components::{
+        ChartDatum,
+        ChartSeries,
+        LineChart,
+        PieChart,
+        StackedBarChart,
+    },
+    requests,
+    types::{
+        category::totals_by_category,
+        datetime::{
+            previous_month,
+            DateTimeRange,
+            Month,
+        },
+        entry::{
+            Entry,
+            EntryKind,
+        },
+        exchange_rate::ExchangeRate,
+        locale::Locale,
+        money::Money,
+        settings::Settings,
+        summary::{
+            entries_in,
+            latest_entries,
+            percent_change,
+            running_balance,
+            summarize,
+            top_categories,
+        },
+    },
+};
+
+const TOP_CATEGORIES: usize = 5;
+const LATEST_ENTRIES: usize = 5;
+
+#[function_component(Dashboard)]
+pub fn dashboard() -> Html {
+    let settings = use_state_eq(Settings::default);
+    let entries = use_state_eq(Vec::<Entry>::default);
+    let rates = use_state_eq(Vec::<ExchangeRate>::default);
+    {
+        let settings = settings.clone();
+        let entries = entries.clone();
+        let rates = rates.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    if let Ok(response) = requests::get_settings().await {
+                        settings.set(response);
+                    }
+                    if let Ok(list) = requests::get_entries().await {
+                        if let Ok(list) =
+                            requests::get_exchange_rates_for(&list).await
+                        {
+                            rates.set(list);
+                        }
+                        entries.set(list);
+                    }
+                });
+            },
+            (),
+        );
+    }
+
+    let timezone = settings.timezone;
+    let currency = settings.home_currency;
+    let locale = Locale::from_browser();
+    let format =
+        |money: Money| format!("{} {}", money.format(locale), money.currency);
+
+    let today = Utc::now().with_timezone(&timezone).date_naive();
+    let (year, month) = (today.year(), Month::from_u32(today.month()));
+    let (last_year, last_month) = previous_month(year, month);
+    let this_month = DateTimeRange::month(&timezone, year, month)
+        .map(|range| entries_in(&entries, &range))
+        .unwrap_or_default();
+    let previous = DateTimeRange::month(&timezone, last_year, last_month)
+        .map(|range| entries_in(&entries, &range))
+        .unwrap_or_default();
+
+    let summary = summarize(&this_month, currency, &rates);
+    let previous_summary = summarize(&previous, currency, &rates);
+    let top = top_categories(&this_month, currency, &rates, TOP_CATEGORIES)
+        .unwrap_or_default();
+    let latest = latest_entries(&entries, LATEST_ENTRIES);
+
+    // CHARTS
+    let top_data: Rc<[ChartDatum]> = top
+        .iter()
+        .map(|(category, total)| ChartDatum {
+            label: AttrValue::from(category.clone()),
+            value: *total,
+        })
+        .collect();
+    let previous_totals =
+        totals_by_category(&previous, currency, &rates).unwrap_or_default();
+    let month_labels: Rc<[AttrValue]> = Rc::from([
+        AttrValue::from(last_month.to_string()),
+        AttrValue::from(month.to_string()),
+    ]);
+    let month_series: Rc<[ChartSeries]> = top
+        .iter()
+        .map(|(category, total)| ChartSeries {
+            label: AttrValue::from(category.clone()),
+            values: vec![
+                previous_totals
+                    .get(category)
+                    .copied()
+                    .unwrap_or(Money::zero(currency)),
+                *total,
+            ],
+        })
+        .collect();
+    let balance_data: Rc<[ChartDatum]> =
+        running_balance(&this_month, currency, &rates, &timezone)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(date, balance)| ChartDatum {
+                label: AttrValue::from(date.format("%Y-%m-%d").to_string()),
+                value: balance,
+            })
+            .collect();
+
+    html! {
+        <section id={"dashboard"}>
+            <h2 id={"dashboard_month"}>{ format!("{} {}", month, year) }</h2>
+            if let Some(summary) = summary {
+                <dl id={"month_summary"}>
+                    <dt>{ "Spending" }</dt>
+                    <dd id={"summary_spending"}>{ format(summary.spending) }</dd>
+                    <dt>{ "Income" }</dt>
+                    <dd id={"summary_income"}>{ format(summary.income) }</dd>
+                    <dt>{ "Balance" }</dt>
+                    <dd id={"summary_balance"}>
+                        { summary.balance().map(format).unwrap_or_default() }
+                    </dd>
+                </dl>
+                if let Some(previous) = previous_summary {
+                    <p id={"month_comparison"}>
+                        { comparison(summary.spending, previous.spending, last_month) }
+                    </p>
+                }
+            } else {
+                <p id={"month_summary"}>{ "Summary unavailable" }</p>
+            }
+            <ol id={"top_categories"} aria-label={"Top categories this month"}>
+            {
+                top.into_iter().map(|(category, total)| html! {
+                    <li>{ format!("{}: {}", category, format(total)) }</li>
+                }).collect::<Html>()
+            }
+            </ol>
+            <PieChart
+                id={"top_categories_chart"}
+                title={"Top categories this month"}
+                data={top_data}
+                donut={true}
+                {locale}
+            />
+            <StackedBarChart
+                id={"month_comparison_chart"}
+                title={format!("Top categories in {} and {}", last_month, month)}
+                labels={month_labels}
+                series={month_series}
+                {locale}
+            />
+            <LineChart
+                id={"balance_chart"}
+                title={"Balance this month"}
+                data={balance_data}
+                {locale}
+            />
+            <ul id={"latest_entries"} aria-label={"Latest entries"}>
+            {
+                latest.into_iter().map(|entry| {
+                    let what = match &entry.kind {
+                        EntryKind::Transfer { to_account } => format!("Transfer to {}", to_account),
+                        EntryKind::Contribution { goal } => format!("Saved for {}", goal),
+                        _ if entry.description.is_empty() => entry.category.clone(),
+                        _ => entry.description.clone(),
+                    };
+                    html! {
+                        <li>
+                            { format!(
+                                "{} {} {}",
+                                entry.datetime.with_timezone(&timezone).format("%Y-%m-%d"),
+                                what,
+                                format(entry.amount)
+                            ) }
+                        </li>
+                    }
+                }).collect::<Html>()
+            }
+            </ul>
+        </section>
+    }
+}
+
+fn comparison(current: Money, previous: Money, month: Month) -> String {
+    match percent_change(current, previous) {
+        Some(0) => format!("Spending is the same as in {}", month),
+        Some(percent) if percent > 0 => {
+            format!("Spending is {}% higher than in {}", percent, month)
+        },
+        Some(percent) => format!(
+            "Spending is {}% lower than in {}",
+            percent.unsigned_abs(),
+            month
+        ),
+        None => format!("No spending in {} to compare with", month),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        comparison,
+        Dashboard,
+    };
+    use crate::{
+        dom::DOM,
+        types::{
+            datetime::Month,
+            money::eur,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    async fn render_dashboard() {
+        let output = DOM::get_element_by_id("output")
+            .expect("Element `output` to exist");
+        yew::Renderer::<Dashboard>::with_root(output).render();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    #[wasm_bindgen_test]
+    fn comparison_describes_change_from_previous_month() {
+        let tests = vec![
+            (eur(12000), eur(10000), "Spending is 20% higher than in May"),
+            (eur(7500), eur(10000), "Spending is 25% lower than in May"),
+            (eur(10000), eur(10000), "Spending is the same as in May"),
+            (eur(10000), eur(0), "No spending in May to compare with"),
+        ];
+
+        for (current, previous, expected) in tests {
+            assert_eq!(comparison(current, previous, Month::May), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn page_contains_month_summary() {
+        render_dashboard().await;
+
+        let tests = vec![
+            ("summary_spending", "0.00 EUR"),
+            ("summary_income", "0.00 EUR"),
+            ("summary_balance", "0.00 EUR"),
+        ];
+
+        for (id, expected) in tests {
+            let element =
+                DOM::get_element_by_id(id).expect("Summary Element to exist");
+            assert_eq!(element.text_content().unwrap_or_default(), expected);
+        }
+        assert!(DOM::get_element_by_id("top_categories").is_some());
+        for id in [
+            "top_categories_chart",
+            "month_comparison_chart",
+            "balance_chart",
+        ] {
+            assert!(DOM::get_element_by_id(&format!("{}_svg", id)).is_some());
+        }
+        assert!(DOM::get_element_by_id("latest_entries").is_some());
+    }
+}
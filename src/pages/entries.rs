@@ -0,0 +1,263 @@
+use std::rc::Rc;
+
+use wasm_bindgen_futures::spawn_local;
+use yew::{
+    function_component,
+    html,
+    use_effect_with_deps,
+    use_state_eq,
+    AttrValue,
+    Callback,
+    Html,
+};
+
+use crate::{
+    components::MultiSelect,
+    requests,
+    types::{
+        account::{
+            self,
+            Account,
+        },
+        category,
+        entry::{
+            Entry,
+            EntryKind,
+        },
+        exchange_rate::ExchangeRate,
+        locale::Locale,
+        select::SelectOption,
+    },
+};
+
+#[function_component(Entries)]
+pub fn entries() -> Html {
+    let entries = use_state_eq(Vec::<Entry>::default);
+    let categories = use_state_eq(Vec::<SelectOption>::default);
+    let accounts = use_state_eq(Vec::<Account>::default);
+    let rates = use_state_eq(Vec::<ExchangeRate>::default);
+    {
+        let entries = entries.clone();
+        let categories = categories.clone();
+        let accounts = accounts.clone();
+        let rates = rates.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    if let Ok(tree) = requests::get_categories().await {
+                        categories.set(category::select_options(&tree));
+                    }
+                    if let Ok(list) = requests::get_accounts().await {
+                        accounts.set(list);
+                    }
+                    if let Ok(list) = requests::get_entries().await {
+                        if let Ok(list) =
+                            requests::get_exchange_rates_for(&list).await
+                        {
+                            rates.set(list);
+                        }
+                        entries.set(list);
+                    }
+                });
+            },
+            (),
+        );
+    }
+
+    let category_filter = use_state_eq(Vec::<AttrValue>::default);
+    let onchange_category_filter = {
+        let category_filter = category_filter.clone();
+        Callback::from(move |values| category_filter.set(values))
+    };
+
+    let locale = Locale::from_browser();
+    let visible_entries = filter_by_categories(&entries, &category_filter);
+
+    html! {
+        <section id={"entries"}>
+            <table id={"account_balances"}>
+                <caption>{ "Balances" }</caption>
+                <tbody>
+                {
+                    accounts.iter().map(|account| html! {
+                        <tr>
+                            <th scope={"row"}>{ account.name.clone() }</th>
+                            <td>{
+                                match account::balance(account, &entries, &rates) {
+                                    Some(balance) => format!("{} {}", balance.format(locale), balance.currency),
+                                    None => String::from("Unavailable"),
+                                }
+                            }</td>
+                        </tr>
+                    }).collect::<Html>()
+                }
+                </tbody>
+            </table>
+            <MultiSelect
+                id={"category_filter"}
+                label={"Categories"}
+                options={Rc::from((*categories).clone())}
+                onchange={onchange_category_filter}
+            />
+            <table id={"entries_table"}>
+                <thead>
+                    <tr>
+                        <th>{ "Date" }</th>
+                        <th>{ "Account" }</th>
+                        <th>{ "Category" }</th>
+                        <th>{ "Description" }</th>
+                        <th>{ "Tags" }</th>
+                        <th>{ "Value" }</th>
+                    </tr>
+                </thead>
+                <tbody>
+                {
+                    visible_entries.iter().map(|entry| html! {
+                        <tr>
+                            <td>{ entry.datetime.format("%Y-%m-%d").to_string() }</td>
+                            <td>{ entry.account.clone() }</td>
+                            <td>{
+                                match &entry.kind {
+                                    EntryKind::Transfer { to_account } => format!("Transfer to {}", to_account),
+                                    EntryKind::Contribution { goal } => format!("Saved for {}", goal),
+                                    EntryKind::Expense | EntryKind::Income => entry.parts().iter().map(|(path, _)| *path).collect::<Vec<_>>().join(", "),
+                                }
+                            }</td>
+                            <td>{ entry.description.clone() }</td>
+                            <td>{ entry.tags.join(", ") }</td>
+                            <td>{ format!("{} {}", entry.amount.format(locale), entry.amount.currency) }</td>
+                        </tr>
+                    }).collect::<Html>()
+                }
+                </tbody>
+            </table>
+        </section>
+    }
+}
+
+fn filter_by_categories<'a>(
+    entries: &'a [Entry],
+    categories: &[AttrValue],
+) -> Vec<&'a Entry> {
+    entries
+        .iter()
+        .filter(|entry| {
+            categories.is_empty()
+                || entry.parts().iter().any(|(path, _)| {
+                    categories.iter().any(|c| category::is_in_category(path, c))
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use chrono::{
+        FixedOffset,
+        TimeZone,
+    };
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use yew::AttrValue;
+
+    use super::{
+        filter_by_categories,
+        Entries,
+    };
+    use crate::{
+        dom::DOM,
+        types::{
+            currency::Currency,
+            entry::Entry,
+            money::Money,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    async fn render_entries() {
+        let output = DOM::get_element_by_id("output")
+            .expect("Element `output` to exist");
+        yew::Renderer::<Entries>::with_root(output).render();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    fn make_entry(category: &str) -> Entry {
+        Entry::fixture(
+            FixedOffset::east_opt(0)
+                .unwrap()
+                .with_ymd_and_hms(2023, 6, 1, 12, 0, 0)
+                .unwrap(),
+            Money::new(100, Currency::Eur),
+        )
+        .with_category(category)
+    }
+
+    static CATEGORY_FILTER_ID: &str = "category_filter";
+    static ENTRIES_TABLE_ID: &str = "entries_table";
+    static ACCOUNT_BALANCES_ID: &str = "account_balances";
+
+    #[wasm_bindgen_test]
+    fn filter_by_categories_includes_subcategories() {
+        let entries = vec![
+            make_entry("Food › Groceries"),
+            make_entry("Food"),
+            make_entry("Rent"),
+            make_entry("Transport › Fuel"),
+        ];
+        let tests = vec![
+            (vec![], vec![0, 1, 2, 3]),
+            (vec!["Food"], vec![0, 1]),
+            (vec!["Food › Groceries"], vec![0]),
+            (vec!["Rent", "Transport"], vec![2, 3]),
+            (vec!["Pets"], vec![]),
+        ];
+
+        for (categories, expected) in tests {
+            let categories: Vec<AttrValue> =
+                categories.into_iter().map(AttrValue::from).collect();
+            let filtered = filter_by_categories(&entries, &categories);
+            let expected: Vec<&Entry> =
+                expected.into_iter().map(|i| &entries[i]).collect();
+            assert_eq!(filtered, expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn page_contains_category_filter() {
+        render_entries().await;
+
+        let element = DOM::get_input_by_id(CATEGORY_FILTER_ID)
+            .expect("Input Element to exist");
+        let label = DOM::get_label_by_for(CATEGORY_FILTER_ID)
+            .expect("Label Element to exist");
+
+        assert!(DOM::is_element_visible(&element));
+        assert_eq!(&label.inner_html(), "Categories");
+    }
+
+    #[wasm_bindgen_test]
+    async fn page_contains_entries_table() {
+        render_entries().await;
+
+        let element = DOM::get_element_by_id(ENTRIES_TABLE_ID);
+
+        assert!(element.is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn page_contains_account_balances() {
+        render_entries().await;
+
+        let element = DOM::get_element_by_id(ACCOUNT_BALANCES_ID)
+            .expect("Balances table to exist");
+        let text = element.text_content().unwrap_or_default();
+
+        assert!(text.contains("Checking"));
+        assert!(text.contains("Cash"));
+    }
+}
@@ -0,0 +1,495 @@
+use std::rc::Rc;
+
+use chrono::{
+    DateTime,
+    FixedOffset,
+    NaiveDate,
+    Utc,
+};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{
+    Event,
+    HtmlInputElement,
+    HtmlSelectElement,
+    InputEvent,
+    MouseEvent,
+};
+use yew::{
+    function_component,
+    html,
+    use_effect_with_deps,
+    use_state_eq,
+    AttrValue,
+    Callback,
+    Html,
+    TargetCast,
+    UseStateHandle,
+};
+
+use crate::{
+    components::{
+        reset_form,
+        DateTimeSelect,
+        Form,
+        FormValues,
+        Input,
+        MonetaryInput,
+        Select,
+    },
+    requests,
+    types::{
+        account::Account,
+        currency::Currency,
+        datetime::DateTimeRange,
+        entry::{
+            Entry,
+            EntryKind,
+        },
+        exchange_rate::ExchangeRate,
+        goal::Goal,
+        locale::Locale,
+        money::Money,
+        select::SelectOption,
+        settings::Settings,
+    },
+};
+
+const GOAL_FORM_ID: &str = "goal_form";
+const CONTRIBUTION_FORM_ID: &str = "contribution_form";
+
+#[function_component(Goals)]
+pub fn goals() -> Html {
+    let goals = use_state_eq(Vec::<Goal>::default);
+    let entries = use_state_eq(Vec::<Entry>::default);
+    let rates = use_state_eq(Vec::<ExchangeRate>::default);
+    let accounts = use_state_eq(Vec::<Account>::default);
+    let currency = use_state_eq(Currency::default);
+    let timezone = use_state_eq(|| Settings::default().timezone);
+    {
+        let goals = goals.clone();
+        let entries = entries.clone();
+        let rates = rates.clone();
+        let accounts = accounts.clone();
+        let currency = currency.clone();
+        let timezone = timezone.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    if let Ok(settings) = requests::get_settings().await {
+                        currency.set(settings.default_currency);
+                        timezone.set(settings.timezone);
+                    }
+                    if let Ok(list) = requests::get_accounts().await {
+                        accounts.set(list);
+                    }
+                    if let Ok(list) = requests::get_entries().await {
+                        if let Ok(list) =
+                            requests::get_exchange_rates_for(&list).await
+                        {
+                            rates.set(list);
+                        }
+                        entries.set(list);
+                    }
+                    if let Ok(list) = requests::get_goals().await {
+                        goals.set(list);
+                    }
+                });
+            },
+            (),
+        );
+    }
+    let save_goals = {
+        let goals = goals.clone();
+        Callback::from(move |list: Vec<Goal>| {
+            let goals = goals.clone();
+            spawn_local(async move {
+                if requests::put_goals(&list).await.is_ok() {
+                    goals.set(list);
+                }
+            });
+        })
+    };
+
+    let now = Utc::now().with_timezone(&*timezone).fixed_offset();
+    let today = now.date_naive();
+    let selectable_range =
+        DateTimeRange::from(now, now + chrono::Duration::days(10 * 365));
+    let locale = Locale::from_browser();
+
+    // NEW GOAL
+    let name = use_state_eq(String::default);
+    let target = use_state_eq(|| None::<i64>);
+    let target_date =
+        use_state_eq(|| Some((now + chrono::Duration::days(365)).date_naive()));
+    let is_duplicate = goals.iter().any(|g| g.name == name.trim())
+        || accounts.iter().any(|a| a.name == name.trim());
+    let can_add = !name.trim().is_empty()
+        && !is_duplicate
+        && target.is_some_and(|t| t > 0);
+
+    let oninput_name = {
+        let name = name.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                name.set(input.value());
+            }
+        })
+    };
+    let onchange_target = {
+        let target = target.clone();
+        Callback::from(move |value| target.set(value))
+    };
+    let ondatetimechange = {
+        let target_date = target_date.clone();
+        Callback::from(move |datetime: Option<DateTime<FixedOffset>>| {
+            target_date.set(datetime.map(|d| d.date_naive()))
+        })
+    };
+    let onsubmit_goal = {
+        let goals = goals.clone();
+        let save_goals = save_goals.clone();
+        let goal = match (*target, *target_date) {
+            (Some(minor), Some(target_date)) => Some(Goal {
+                name: name.trim().to_owned(),
+                target: Money::new(minor, *currency),
+                target_date,
+            }),
+            _ => None,
+        };
+        Callback::from(move |_: FormValues| {
+            if let (Some(goal), true) = (goal.clone(), can_add) {
+                let mut list = (*goals).clone();
+                list.push(goal);
+                save_goals.emit(list);
+                reset_form(GOAL_FORM_ID);
+            }
+        })
+    };
+    let onreset_goal = {
+        let name = name.clone();
+        let target = target.clone();
+        Callback::from(move |_| {
+            name.set(String::default());
+            target.set(None);
+        })
+    };
+
+    // CONTRIBUTION
+    let goal_name = use_state_eq(|| None::<AttrValue>);
+    let from_account = use_state_eq(|| None::<AttrValue>);
+    let contribution = use_state_eq(|| None::<i64>);
+    let contribution_goal = goals
+        .iter()
+        .find(|g| Some(g.name.as_str()) == goal_name.as_deref());
+    let contribution_currency =
+        contribution_goal.map_or(*currency, |goal| goal.target.currency);
+    let can_contribute = contribution_goal.is_some()
+        && from_account.is_some()
+        && contribution.is_some_and(|c| c > 0);
+
+    let choose = |text: &str, selected: bool| SelectOption {
+        inner_html: AttrValue::from(text.to_owned()),
+        selected,
+        ..Default::default()
+    };
+    let goal_options: Vec<SelectOption> =
+        std::iter::once(choose("Choose a goal", goal_name.is_none()))
+            .chain(goals.iter().map(|goal| {
+                let option = SelectOption::from(goal);
+                let selected = Some(&option.value) == goal_name.as_ref();
+                option.selected(selected)
+            }))
+            .collect();
+    let account_options: Vec<SelectOption> =
+        std::iter::once(choose("Choose an account", from_account.is_none()))
+            .chain(accounts.iter().map(|account| {
+                let option = SelectOption::from(account);
+                let selected = Some(&option.value) == from_account.as_ref();
+                option.selected(selected)
+            }))
+            .collect();
+
+    let onchange_select = |state: UseStateHandle<Option<AttrValue>>| {
+        Callback::from(move |e: Event| {
+            let value = e
+                .target_dyn_into::<HtmlSelectElement>()
+                .map(|select| select.value())
+                .filter(|value| !value.is_empty());
+            state.set(value.map(AttrValue::from));
+        })
+    };
+    let onchange_contribution = {
+        let contribution = contribution.clone();
+        Callback::from(move |value| contribution.set(value))
+    };
+    let onsubmit_contribution = {
+        let entries = entries.clone();
+        let entry =
+            match (contribution_goal, from_account.as_ref(), *contribution) {
+                (Some(goal), Some(account), Some(minor)) => Some(Entry {
+                    datetime: now,
+                    account: account.to_string(),
+                    kind: EntryKind::Contribution {
+                        goal: goal.name.clone(),
+                    },
+                    category: String::new(),
+                    description: format!("Contribution to {}", goal.name),
+                    tags: Vec::new(),
+                    amount: Money::new(minor, goal.target.currency),
+                    exchange_rate: None,
+                    lines: Vec::new(),
+                }),
+                _ => None,
+            };
+        Callback::from(move |_: FormValues| {
+            let (Some(entry), true) = (entry.clone(), can_contribute) else {
+                return;
+            };
+            let entries = entries.clone();
+            spawn_local(async move {
+                if requests::post_entry(&entry).await.is_ok() {
+                    let mut list = (*entries).clone();
+                    list.push(entry);
+                    entries.set(list);
+                    reset_form(CONTRIBUTION_FORM_ID);
+                }
+            });
+        })
+    };
+    let onreset_contribution = {
+        let goal_name = goal_name.clone();
+        let from_account = from_account.clone();
+        let contribution = contribution.clone();
+        Callback::from(move |_| {
+            goal_name.set(None);
+            from_account.set(None);
+            contribution.set(None);
+        })
+    };
+
+    html! {
+        <section id={"goals"}>
+            <ul id={"goals_list"}>
+            {
+                goals.iter().enumerate().map(|(index, goal)| {
+                    let saved = goal.saved(&entries, &rates);
+                    let onclick_delete = {
+                        let goals = goals.clone();
+                        let save_goals = save_goals.clone();
+                        Callback::from(move |_: MouseEvent| {
+                            let mut list = (*goals).clone();
+                            list.remove(index);
+                            save_goals.emit(list);
+                        })
+                    };
+                    html! {
+                        <li id={format!("goal_{}", index)}>
+                            <h2>{ goal.name.clone() }</h2>
+                            if let Some(saved) = saved {
+                                <progress
+                                    id={format!("goal_progress_{}", index)}
+                                    max={goal.target.minor.max(0).to_string()}
+                                    value={saved.minor.clamp(0, goal.target.minor.max(0)).to_string()}
+                                    aria-label={format!("{} saved", goal.name)}
+                                />
+                                <p id={format!("goal_summary_{}", index)}>
+                                    { format!(
+                                        "Saved {} of {} {}",
+                                        saved.format(locale),
+                                        goal.target.format(locale),
+                                        goal.target.currency
+                                    ) }
+                                </p>
+                                <p id={format!("goal_plan_{}", index)}>
+                                    { plan(goal, saved, today, locale) }
+                                </p>
+                            } else {
+                                <p id={format!("goal_summary_{}", index)}>{ "Unavailable" }</p>
+                            }
+                            <button
+                                id={format!("goal_delete_{}", index)}
+                                type={"button"}
+                                aria-label={format!("Delete goal {}", goal.name)}
+                                onclick={onclick_delete}
+                            >
+                                { "×" }
+                            </button>
+                        </li>
+                    }
+                }).collect::<Html>()
+            }
+            </ul>
+            <Form
+                id={CONTRIBUTION_FORM_ID}
+                onsubmit={onsubmit_contribution}
+                onreset={onreset_contribution}
+            >
+                <Select
+                    id={"contribution_goal_select"}
+                    label={"Goal"}
+                    options={Rc::from(goal_options)}
+                    onchange={onchange_select(goal_name.clone())}
+                />
+                <Select
+                    id={"contribution_account_select"}
+                    label={"From account"}
+                    options={Rc::from(account_options)}
+                    onchange={onchange_select(from_account.clone())}
+                />
+                <MonetaryInput
+                    id={"contribution_amount_input"}
+                    label={"Contribution"}
+                    currency={contribution_currency}
+                    {locale}
+                    value={*contribution}
+                    onvaluechange={onchange_contribution}
+                />
+                <button
+                    id={"contribute_button"}
+                    type={"submit"}
+                    disabled={!can_contribute}
+                >
+                    { "Contribute" }
+                </button>
+            </Form>
+            <Form id={GOAL_FORM_ID} onsubmit={onsubmit_goal} onreset={onreset_goal}>
+                <Input
+                    id={"goal_name_input"}
+                    label={"Goal"}
+                    name={"name"}
+                    value={AttrValue::from((*name).clone())}
+                    oninput={oninput_name}
+                    error={is_duplicate.then(|| AttrValue::from("A goal or account with this name already exists"))}
+                />
+                <MonetaryInput
+                    id={"goal_target_input"}
+                    label={"Target amount"}
+                    currency={*currency}
+                    {locale}
+                    value={*target}
+                    onvaluechange={onchange_target}
+                />
+                <DateTimeSelect
+                    id={"goal_date_select"}
+                    label={"Target date"}
+                    range={Rc::new(selectable_range)}
+                    preselect={Rc::new(now + chrono::Duration::days(365))}
+                    {ondatetimechange}
+                />
+                <button
+                    id={"add_goal_button"}
+                    type={"submit"}
+                    disabled={!can_add}
+                >
+                    { "Add goal" }
+                </button>
+            </Form>
+        </section>
+    }
+}
+
+fn plan(goal: &Goal, saved: Money, today: NaiveDate, locale: Locale) -> String {
+    let currency = goal.target.currency;
+    match goal.monthly_contribution(saved, today) {
+        Some(monthly) if monthly.minor == 0 => String::from("Goal reached"),
+        Some(monthly) => format!(
+            "Save {} {} a month to reach it by {}",
+            monthly.format(locale),
+            currency,
+            goal.target_date.format("%Y-%m-%d")
+        ),
+        None => match goal.target.checked_sub(saved) {
+            Some(remaining) => format!(
+                "Target date passed with {} {} to go",
+                remaining.format(locale),
+                currency
+            ),
+            None => String::from("Target date passed"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use chrono::NaiveDate;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        plan,
+        Goals,
+    };
+    use crate::{
+        dom::DOM,
+        types::{
+            goal::Goal,
+            locale::Locale,
+            money::eur,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    async fn render_goals() {
+        let output = DOM::get_element_by_id("output")
+            .expect("Element `output` to exist");
+        yew::Renderer::<Goals>::with_root(output).render();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    #[wasm_bindgen_test]
+    fn plan_describes_monthly_contribution() {
+        let goal = Goal {
+            name: String::from("Bicycle"),
+            target: eur(120000),
+            target_date: NaiveDate::from_ymd_opt(2024, 5, 31).unwrap(),
+        };
+        let tests = vec![
+            (
+                eur(0),
+                (2023, 6, 15),
+                "Save 100.00 EUR a month to reach it by 2024-05-31",
+            ),
+            (eur(120000), (2023, 6, 15), "Goal reached"),
+            (
+                eur(20000),
+                (2024, 6, 1),
+                "Target date passed with 1,000.00 EUR to go",
+            ),
+        ];
+
+        for (saved, (y, m, d), expected) in tests {
+            let today = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            assert_eq!(plan(&goal, saved, today, Locale::default()), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn page_contains_goal_and_contribution_forms() {
+        render_goals().await;
+
+        let tests = vec![
+            ("goal_name_input", "Goal"),
+            ("goal_target_input", "Target amount"),
+            ("contribution_amount_input", "Contribution"),
+        ];
+
+        for (id, label) in tests {
+            let element = DOM::get_input_by_id(id).expect("Input to exist");
+            let label_element =
+                DOM::get_label_by_for(id).expect("Label to exist");
+
+            assert!(DOM::is_element_visible(&element));
+            assert_eq!(&label_element.inner_html(), label);
+        }
+        for id in ["add_goal_button", "contribute_button"] {
+            let button =
+                DOM::get_button_by_id(id).expect("Button Element to exist");
+            assert!(button.has_attribute("disabled"));
+        }
+    }
+}
@@ -1,33 +1,84 @@
+use web_sys::{
+    KeyboardEvent,
+    MouseEvent,
+};
 use yew::{
     function_component,
     html,
+    use_state_eq,
+    Callback,
     Html,
 };
 
 use crate::components::{
+    Form,
     Input,
     InputType,
 };
 
 #[function_component(Login)]
 pub fn login() -> Html {
+    let password_visible = use_state_eq(|| false);
+    let onclick_toggle = {
+        let password_visible = password_visible.clone();
+        Callback::from(move |_: MouseEvent| {
+            password_visible.set(!*password_visible)
+        })
+    };
+    let password_type = match *password_visible {
+        true => InputType::Text,
+        false => InputType::Password,
+    };
+
+    let caps_lock = use_state_eq(|| false);
+    let onkey_password = {
+        let caps_lock = caps_lock.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            caps_lock.set(e.get_modifier_state("CapsLock"))
+        })
+    };
+
     html! {
         <section id={"login_section"}>
-            <Input
-                id={"username_input"}
-                label={"Username"}
-            />
-            <Input
-                id={"password_input"}
-                label={"Password"}
-                input_type={InputType::Password}
-            />
-            <button
-                id={"login_button"}
-                type={"submit"}
-            >
-                { "Login" }
-            </button>
+            <Form id={"login_form"}>
+                <Input
+                    id={"username_input"}
+                    label={"Username"}
+                    name={"username"}
+                    autocomplete={"username"}
+                    required={true}
+                />
+                <Input
+                    id={"password_input"}
+                    label={"Password"}
+                    input_type={password_type}
+                    name={"password"}
+                    autocomplete={"current-password"}
+                    required={true}
+                    onkeydown={onkey_password.clone()}
+                    onkeyup={onkey_password}
+                />
+                <button
+                    id={"password_toggle"}
+                    type={"button"}
+                    aria-controls={"password_input"}
+                    aria-pressed={password_visible.to_string()}
+                    onclick={onclick_toggle}
+                >
+                    { "Show password" }
+                </button>
+                if *caps_lock {
+                    <p id={"caps_lock_warning"} role={"status"}>
+                        { "Caps Lock is on" }
+                    </p>
+                }
+                <button
+                    id={"login_button"}
+                    type={"submit"}
+                >
+                    { "Login" }
+                </button>
+            </Form>
         </section>
     }
 }
@@ -36,10 +87,17 @@ pub fn login() -> Html {
 mod test {
     use std::time::Duration;
 
+    use wasm_bindgen::JsCast;
     use wasm_bindgen_test::{
         wasm_bindgen_test,
         wasm_bindgen_test_configure,
     };
+    use web_sys::{
+        HtmlElement,
+        HtmlInputElement,
+        KeyboardEvent,
+        KeyboardEventInit,
+    };
 
     use super::Login;
     use crate::dom::DOM;
@@ -53,9 +111,46 @@ mod test {
         yew::platform::time::sleep(Duration::from_millis(10)).await;
     }
 
+    async fn dispatch_keyboard_event(event_type: &str, caps_lock: bool) {
+        let mut init = KeyboardEventInit::new();
+        init.key("a");
+        init.modifier_caps_lock(caps_lock);
+        let event =
+            KeyboardEvent::new_with_keyboard_event_init_dict(event_type, &init)
+                .expect("valid event");
+        DOM::get_input_by_id(PASSWORD_INPUT_ID)
+            .expect("Input Element to exist")
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    async fn click_password_toggle() {
+        DOM::get_button_by_id(PASSWORD_TOGGLE_ID)
+            .expect("Button Element to exist")
+            .dyn_into::<HtmlElement>()
+            .expect("Element to be HtmlElement")
+            .click();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
     static USERNAME_INPUT_ID: &str = "username_input";
     static PASSWORD_INPUT_ID: &str = "password_input";
     static LOGIN_BUTTON_ID: &str = "login_button";
+    static PASSWORD_TOGGLE_ID: &str = "password_toggle";
+    static CAPS_LOCK_WARNING_ID: &str = "caps_lock_warning";
+    static LOGIN_FORM_ID: &str = "login_form";
+
+    // FORM TESTS
+    #[wasm_bindgen_test]
+    async fn page_contains_login_form_element() {
+        render_login().await;
+
+        let element = DOM::get_element_by_id(LOGIN_FORM_ID)
+            .expect("Form Element to exist");
+
+        assert_eq!(element.tag_name(), "FORM");
+    }
 
     // USERNAME INPUT TESTS
     #[wasm_bindgen_test]
@@ -117,6 +212,19 @@ mod test {
         assert_eq!(input_type, Some("text".to_string()));
     }
 
+    #[wasm_bindgen_test]
+    async fn username_input_element_autocompletes_username() {
+        render_login().await;
+
+        let element = DOM::get_input_by_id(USERNAME_INPUT_ID)
+            .expect("Input Element to exist");
+
+        assert_eq!(
+            element.get_attribute("autocomplete"),
+            Some("username".to_string())
+        );
+    }
+
     // PASSWORD INPUT TESTS
     #[wasm_bindgen_test]
     async fn page_contains_password_input_element() {
@@ -177,6 +285,118 @@ mod test {
         assert_eq!(input_type, Some("password".to_string()));
     }
 
+    #[wasm_bindgen_test]
+    async fn password_input_element_autocompletes_current_password() {
+        render_login().await;
+
+        let element = DOM::get_input_by_id(PASSWORD_INPUT_ID)
+            .expect("Input Element to exist");
+
+        assert_eq!(
+            element.get_attribute("autocomplete"),
+            Some("current-password".to_string())
+        );
+    }
+
+    // PASSWORD TOGGLE TESTS
+    #[wasm_bindgen_test]
+    async fn password_toggle_element_is_not_pressed_initially() {
+        render_login().await;
+
+        let button = DOM::get_button_by_id(PASSWORD_TOGGLE_ID)
+            .expect("Button Element to exist");
+
+        assert!(DOM::is_element_visible(&button));
+        assert_eq!(button.get_attribute("type"), Some("button".to_string()));
+        assert_eq!(
+            button.get_attribute("aria-controls"),
+            Some(PASSWORD_INPUT_ID.to_string())
+        );
+        assert_eq!(
+            button.get_attribute("aria-pressed"),
+            Some("false".to_string())
+        );
+        assert_eq!(&button.inner_html(), "Show password");
+    }
+
+    #[wasm_bindgen_test]
+    async fn password_toggle_switches_password_visibility() {
+        render_login().await;
+
+        let tests = vec![("true", "text"), ("false", "password")];
+
+        for (pressed, input_type) in tests {
+            click_password_toggle().await;
+
+            let button = DOM::get_button_by_id(PASSWORD_TOGGLE_ID)
+                .expect("Button Element to exist");
+            let input = DOM::get_input_by_id(PASSWORD_INPUT_ID)
+                .expect("Input Element to exist");
+
+            assert_eq!(
+                button.get_attribute("aria-pressed"),
+                Some(pressed.to_string())
+            );
+            assert_eq!(&button.inner_html(), "Show password");
+            assert_eq!(
+                input.get_attribute("type"),
+                Some(input_type.to_string())
+            );
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn password_toggle_keeps_password_value() {
+        render_login().await;
+
+        let input = DOM::get_input_by_id(PASSWORD_INPUT_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        input.set_value("secret");
+        click_password_toggle().await;
+
+        assert_eq!(input.value(), "secret");
+    }
+
+    // CAPS LOCK WARNING TESTS
+    #[wasm_bindgen_test]
+    async fn caps_lock_warning_is_absent_initially() {
+        render_login().await;
+
+        let element = DOM::get_element_by_id(CAPS_LOCK_WARNING_ID);
+
+        assert!(element.is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn caps_lock_warning_follows_keyboard_events() {
+        render_login().await;
+
+        let tests = vec![
+            ("keydown", true, true),
+            ("keyup", true, true),
+            ("keydown", false, false),
+            ("keyup", true, true),
+            ("keyup", false, false),
+        ];
+
+        for (event_type, caps_lock, expected) in tests {
+            dispatch_keyboard_event(event_type, caps_lock).await;
+
+            let element = DOM::get_element_by_id(CAPS_LOCK_WARNING_ID);
+
+            assert_eq!(element.is_some(), expected);
+            if let Some(element) = element {
+                assert_eq!(
+                    element.get_attribute("role"),
+                    Some("status".into())
+                );
+                assert_eq!(&element.inner_html(), "Caps Lock is on");
+            }
+        }
+    }
+
     // LOGIN BUTTON TESTS
     #[wasm_bindgen_test]
     async fn page_contains_login_button_element() {
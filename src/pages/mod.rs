@@ -1,5 +1,17 @@
+mod budgets;
+mod dashboard;
+mod entries;
+mod goals;
 mod login;
 mod new_entry;
+mod reports;
+mod rules;
 
+pub use budgets::Budgets;
+pub use dashboard::Dashboard;
+pub use entries::Entries;
+pub use goals::Goals;
 pub use login::Login;
 pub use new_entry::NewEntry;
+pub use reports::Reports;
+pub use rules::Rules;
@@ -0,0 +1,225 @@
+use std::rc::Rc;
+
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{
+    Event,
+    HtmlSelectElement,
+};
+use yew::{
+    function_component,
+    html,
+    use_effect_with_deps,
+    use_state_eq,
+    AttrValue,
+    Callback,
+    Html,
+    Properties,
+    TargetCast,
+};
+
+use crate::{
+    components::Select,
+    requests,
+    types::{
+        account::Account,
+        select::SelectOption,
+    },
+};
+
+pub(crate) const EXPENSE: &str = "expense";
+pub(crate) const INCOME: &str = "income";
+pub(crate) const TRANSFER: &str = "transfer";
+const KINDS: [(&str, &str); 3] = [
+    (EXPENSE, "Expense"),
+    (INCOME, "Income"),
+    (TRANSFER, "Transfer between accounts"),
+];
+
+fn parse_kind(value: &str) -> &'static str {
+    KINDS
+        .iter()
+        .find(|(kind, _)| *kind == value)
+        .map_or(EXPENSE, |(kind, _)| kind)
+}
+
+fn account_options(
+    accounts: &[Account],
+    selected: Option<&AttrValue>,
+) -> Vec<SelectOption> {
+    accounts
+        .iter()
+        .map(|a| {
+            let option = SelectOption::from(a);
+            let is_selected = Some(&option.value) == selected;
+            option.selected(is_selected)
+        })
+        .collect()
+}
+
+fn to_account_options(
+    accounts: &[Account],
+    account: Option<&AttrValue>,
+    to_account: Option<&AttrValue>,
+) -> Vec<SelectOption> {
+    let choose_account = SelectOption {
+        inner_html: AttrValue::from("Choose an account"),
+        selected: to_account.is_none(),
+        ..Default::default()
+    };
+    let others: Vec<Account> = accounts
+        .iter()
+        .filter(|a| Some(a.name.as_str()) != account.map(AttrValue::as_str))
+        .cloned()
+        .collect();
+    std::iter::once(choose_account)
+        .chain(account_options(&others, to_account))
+        .collect()
+}
+
+fn select_value(e: Event) -> Option<AttrValue> {
+    e.target_dyn_into::<HtmlSelectElement>()
+        .map(|select| select.value())
+        .filter(|value| !value.is_empty())
+        .map(AttrValue::from)
+}
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct AccountFieldsProps {
+    pub(crate) kind: &'static str,
+    pub(crate) account: Option<AttrValue>,
+    pub(crate) to_account: Option<AttrValue>,
+    #[prop_or_default]
+    pub(crate) account_error: Option<AttrValue>,
+    #[prop_or_default]
+    pub(crate) to_account_error: Option<AttrValue>,
+    pub(crate) ondefault_account: Callback<AttrValue>,
+    pub(crate) onchange_kind: Callback<&'static str>,
+    pub(crate) onchange_account: Callback<Option<AttrValue>>,
+    pub(crate) onchange_to_account: Callback<Option<AttrValue>>,
+}
+
+#[function_component(AccountFields)]
+pub(crate) fn account_fields(props: &AccountFieldsProps) -> Html {
+    let accounts = use_state_eq(Vec::<Account>::default);
+    {
+        let accounts = accounts.clone();
+        let ondefault_account = props.ondefault_account.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    if let Ok(list) = requests::get_accounts().await {
+                        if let Some(first) = list.first() {
+                            ondefault_account
+                                .emit(AttrValue::from(first.name.clone()));
+                        }
+                        accounts.set(list);
+                    }
+                });
+            },
+            (),
+        );
+    }
+    let transfer = props.kind == TRANSFER;
+    let kind_options: Vec<SelectOption> = KINDS
+        .iter()
+        .map(|&(value, text)| SelectOption {
+            value: AttrValue::from(value),
+            inner_html: AttrValue::from(text),
+            selected: value == props.kind,
+            ..Default::default()
+        })
+        .collect();
+    let onchange_kind = props
+        .onchange_kind
+        .reform(|e: Event| parse_kind(&select_value(e).unwrap_or_default()));
+
+    html! {
+        <>
+            <Select
+                id={"kind_select"}
+                label={"Type"}
+                options={Rc::from(kind_options)}
+                onchange={onchange_kind}
+            />
+            <Select
+                id={"account_select"}
+                label={if transfer { "From account" } else { "Account" }}
+                options={Rc::from(account_options(&accounts, props.account.as_ref()))}
+                onchange={props.onchange_account.reform(select_value)}
+                error={props.account_error.clone()}
+            />
+            if transfer {
+                <Select
+                    id={"to_account_select"}
+                    label={"To account"}
+                    options={Rc::from(to_account_options(
+                        &accounts,
+                        props.account.as_ref(),
+                        props.to_account.as_ref(),
+                    ))}
+                    onchange={props.onchange_to_account.reform(select_value)}
+                    error={props.to_account_error.clone()}
+                />
+            }
+        </>
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use yew::AttrValue;
+
+    use super::{
+        parse_kind,
+        to_account_options,
+        EXPENSE,
+        INCOME,
+        TRANSFER,
+    };
+    use crate::types::account::Account;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn parse_kind_defaults_to_expense() {
+        let tests = vec![
+            ("expense", EXPENSE),
+            ("income", INCOME),
+            ("transfer", TRANSFER),
+            ("", EXPENSE),
+            ("unknown", EXPENSE),
+        ];
+
+        for (value, expected) in tests {
+            assert_eq!(parse_kind(value), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn to_account_options_exclude_source_account() {
+        let accounts = vec![
+            Account::from("Checking"),
+            Account::from("Cash"),
+            Account::from("Savings"),
+        ];
+        let checking = AttrValue::from("Checking");
+        let savings = AttrValue::from("Savings");
+
+        let options =
+            to_account_options(&accounts, Some(&checking), Some(&savings));
+        let values: Vec<&str> =
+            options.iter().map(|o| o.value.as_str()).collect();
+        let selected: Vec<&str> = options
+            .iter()
+            .filter(|o| o.selected)
+            .map(|o| o.value.as_str())
+            .collect();
+
+        assert_eq!(values, vec!["", "Cash", "Savings"]);
+        assert_eq!(selected, vec!["Savings"]);
+    }
+}
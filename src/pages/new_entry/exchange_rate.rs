@@ -0,0 +1,245 @@
+use chrono::NaiveDate;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{
+    HtmlInputElement,
+    InputEvent,
+};
+use yew::{
+    function_component,
+    html,
+    use_effect_with_deps,
+    use_state_eq,
+    AttrValue,
+    Callback,
+    Html,
+    Properties,
+    TargetCast,
+};
+
+use crate::{
+    components::{
+        Input,
+        InputMode,
+    },
+    requests,
+    types::{
+        currency::Currency,
+        exchange_rate::{
+            find_rate,
+            ExchangeRate,
+            RateSource,
+        },
+        locale::Locale,
+        money::Money,
+    },
+};
+
+fn entered_rate(
+    input: &str,
+    fetched: Option<ExchangeRate>,
+    date: NaiveDate,
+    from: Currency,
+    to: Currency,
+) -> Option<ExchangeRate> {
+    ExchangeRate::parse_rate(input).map(|rate| match fetched {
+        Some(fetched) if fetched.rate == rate => fetched,
+        _ => ExchangeRate {
+            date,
+            from,
+            to,
+            rate,
+            source: RateSource::Manual,
+        },
+    })
+}
+
+// Fetched rates are the latest on or before the entry date, so a rate from an
+// earlier day (e.g. a Friday rate for a Sunday entry) is still usable.
+pub(crate) fn usable_rate(
+    rate: Option<ExchangeRate>,
+    from: Currency,
+    to: Currency,
+    date: Option<NaiveDate>,
+) -> Option<ExchangeRate> {
+    let date = date?;
+    rate.filter(|rate| rate.from == from && rate.to == to && rate.date <= date)
+}
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct ExchangeRateInputProps {
+    pub(crate) currency: Currency,
+    pub(crate) home_currency: Currency,
+    pub(crate) date: Option<NaiveDate>,
+    pub(crate) amount: Option<i64>,
+    pub(crate) locale: Locale,
+    #[prop_or_default]
+    pub(crate) error: Option<AttrValue>,
+    pub(crate) onchange: Callback<Option<ExchangeRate>>,
+    pub(crate) ontouch: Callback<()>,
+}
+
+#[function_component(ExchangeRateInput)]
+pub(crate) fn exchange_rate_input(props: &ExchangeRateInputProps) -> Html {
+    let fetched_rate = use_state_eq(|| None::<ExchangeRate>);
+    let input = use_state_eq(String::default);
+    {
+        let fetched_rate = fetched_rate.clone();
+        let input = input.clone();
+        let onchange = props.onchange.clone();
+        use_effect_with_deps(
+            move |&(from, to, date)| {
+                fetched_rate.set(None);
+                input.set(String::default());
+                onchange.emit(None);
+                if let (Some(date), true) = (date, from != to) {
+                    spawn_local(async move {
+                        let response = requests::get_exchange_rates(date).await;
+                        let rate = response.ok().and_then(|rates| {
+                            find_rate(&rates, date, from, to).copied()
+                        });
+                        if let Some(rate) = rate {
+                            input.set(rate.format_rate());
+                            fetched_rate.set(Some(rate));
+                            onchange.emit(Some(rate));
+                        }
+                    });
+                }
+            },
+            (props.currency, props.home_currency, props.date),
+        );
+    }
+    let rate_for = {
+        let fetched_rate = *fetched_rate;
+        let (date, from, to) =
+            (props.date, props.currency, props.home_currency);
+        move |input: &str| entered_rate(input, fetched_rate, date?, from, to)
+    };
+    let converted_amount = match (props.amount, rate_for(&input)) {
+        (Some(minor), Some(rate)) => {
+            rate.convert(Money::new(minor, props.currency))
+        },
+        _ => None,
+    };
+
+    let oninput = {
+        let input = input.clone();
+        let onchange = props.onchange.clone();
+        let ontouch = props.ontouch.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(element) = e.target_dyn_into::<HtmlInputElement>() {
+                let value = element.value();
+                onchange.emit(rate_for(&value));
+                input.set(value);
+                ontouch.emit(());
+            }
+        })
+    };
+
+    html! {
+        <>
+            <Input
+                id={"exchange_rate_input"}
+                label={format!("{} to {} rate", props.currency, props.home_currency)}
+                inputmode={InputMode::Decimal}
+                placeholder={"1.00"}
+                required={true}
+                value={AttrValue::from((*input).clone())}
+                {oninput}
+                error={props.error.clone()}
+            />
+            <p id={"converted_value"}>
+            {
+                match converted_amount {
+                    Some(money) => format!("{} {}", money.format(props.locale), money.currency),
+                    None => String::default(),
+                }
+            }
+            </p>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        entered_rate,
+        usable_rate,
+    };
+    use crate::types::{
+        currency::Currency,
+        exchange_rate::{
+            ExchangeRate,
+            RateSource,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn entered_rate_reuses_fetched_rate_when_unchanged() {
+        let date = NaiveDate::from_ymd_opt(2023, 5, 1).expect("valid date");
+        let rate = |rate, source| ExchangeRate {
+            date,
+            from: Currency::Usd,
+            to: Currency::Eur,
+            rate,
+            source,
+        };
+        let fetched = Some(rate(900_000, RateSource::Backend));
+        let tests = vec![
+            ("0.9", fetched, Some(rate(900_000, RateSource::Backend))),
+            ("0.95", fetched, Some(rate(950_000, RateSource::Manual))),
+            ("0.95", None, Some(rate(950_000, RateSource::Manual))),
+            ("", fetched, None),
+            ("abc", fetched, None),
+        ];
+
+        for (input, fetched, expected) in tests {
+            assert_eq!(
+                entered_rate(
+                    input,
+                    fetched,
+                    date,
+                    Currency::Usd,
+                    Currency::Eur
+                ),
+                expected,
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn usable_rate_accepts_rate_dated_before_entry() {
+        let day = |day| NaiveDate::from_ymd_opt(2023, 6, day);
+        // 2023-06-02 is a Friday, 2023-06-04 a Sunday
+        let friday_rate = ExchangeRate {
+            date: day(2).expect("valid date"),
+            from: Currency::Usd,
+            to: Currency::Eur,
+            rate: 900_000,
+            source: RateSource::Backend,
+        };
+        let tests = vec![
+            (Currency::Usd, Currency::Eur, day(4), Some(friday_rate)),
+            (Currency::Usd, Currency::Eur, day(2), Some(friday_rate)),
+            (Currency::Usd, Currency::Eur, day(1), None),
+            (Currency::Sek, Currency::Eur, day(4), None),
+            (Currency::Usd, Currency::Eur, None, None),
+        ];
+
+        for (from, to, date, expected) in tests {
+            assert_eq!(
+                usable_rate(Some(friday_rate), from, to, date),
+                expected
+            );
+        }
+    }
+}
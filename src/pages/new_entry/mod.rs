@@ -0,0 +1,1672 @@
+mod accounts;
+mod exchange_rate;
+mod split_lines;
+
+use std::{
+    collections::HashSet,
+    rc::Rc,
+};
+
+use chrono::Utc;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{
+    Event,
+    HtmlInputElement,
+    HtmlSelectElement,
+    InputEvent,
+    MouseEvent,
+};
+use yew::{
+    function_component,
+    html,
+    use_effect_with_deps,
+    use_state_eq,
+    AttrValue,
+    Callback,
+    Html,
+    TargetCast,
+    UseStateHandle,
+};
+
+use self::{
+    accounts::{
+        AccountFields,
+        EXPENSE,
+        INCOME,
+        TRANSFER,
+    },
+    exchange_rate::{
+        usable_rate,
+        ExchangeRateInput,
+    },
+    split_lines::{
+        entry_lines,
+        initial_lines,
+        validate_lines,
+        SplitLine,
+        SplitLines,
+    },
+};
+use crate::{
+    components::{
+        reset_form,
+        Combobox,
+        DateTimeSelect,
+        Form,
+        FormValues,
+        Input,
+        MonetaryInput,
+        MultiSelect,
+        Select,
+    },
+    requests,
+    types::{
+        budget::{
+            overspending,
+            Budget,
+        },
+        categorization::{
+            categorize,
+            CategoryRule,
+            Subject,
+        },
+        category,
+        currency::Currency,
+        datetime::DateTimeRange,
+        entry::{
+            Entry,
+            EntryKind,
+            DESCRIPTION_MAX_LENGTH,
+        },
+        exchange_rate::ExchangeRate,
+        locale::Locale,
+        money::Money,
+        select::SelectOption,
+        settings::Settings,
+        suggestion::{
+            description_suggestions,
+            find_suggestion,
+            Suggestion,
+        },
+        validation::{
+            validate_amount,
+            validate_datetime,
+            validate_text,
+            Rule,
+            ValidationError,
+        },
+    },
+};
+
+const DATETIME: &str = "datetime";
+const CATEGORY: &str = "category";
+const DESCRIPTION: &str = "description";
+const AMOUNT: &str = "amount";
+const EXCHANGE_RATE: &str = "exchange_rate";
+const SPLIT: &str = "split";
+const ACCOUNT: &str = "account";
+const TO_ACCOUNT: &str = "to_account";
+const FORM_ID: &str = "new_entry_form";
+
+#[function_component(NewEntry)]
+pub fn new_entry() -> Html {
+    let touched = use_state_eq(HashSet::<&'static str>::new);
+    let touch = {
+        let touched = touched.clone();
+        Callback::from(move |field: &'static str| {
+            let mut fields = (*touched).clone();
+            fields.insert(field);
+            touched.set(fields);
+        })
+    };
+
+    let categories = use_state_eq(|| Vec::<SelectOption>::default());
+    let category = use_state_eq(|| None::<AttrValue>);
+
+    {
+        let categories = categories.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    let response = requests::get_categories().await;
+                    if let Ok(tree) = response {
+                        categories.set(category::select_options(&tree));
+                    }
+                });
+            },
+            (),
+        );
+    }
+    let tags = use_state_eq(Vec::<SelectOption>::default);
+    {
+        let tags = tags.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    if let Ok(list) = requests::get_tags().await {
+                        tags.set(list.iter().map(SelectOption::from).collect());
+                    }
+                });
+            },
+            (),
+        );
+    }
+    let selected_tags = use_state_eq(Vec::<AttrValue>::default);
+    let onchange_tags = {
+        let selected_tags = selected_tags.clone();
+        Callback::from(move |values| selected_tags.set(values))
+    };
+
+    let category_options: Vec<SelectOption> = categories
+        .iter()
+        .map(|o| o.clone().selected(Some(&o.value) == category.as_ref()))
+        .collect();
+
+    let settings = use_state_eq(Settings::default);
+    let currency = use_state_eq(Currency::default);
+    {
+        let settings = settings.clone();
+        let currency = currency.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    if let Ok(response) = requests::get_settings().await {
+                        currency.set(response.default_currency);
+                        settings.set(response);
+                    }
+                });
+            },
+            (),
+        );
+    }
+    let currencies: Vec<SelectOption> = Currency::ALL
+        .iter()
+        .map(|&c| SelectOption::from(c).selected(c == *currency))
+        .collect();
+
+    let entries = use_state_eq(Vec::<Entry>::default);
+    let budget_rates = use_state_eq(Vec::<ExchangeRate>::default);
+    let suggestions = use_state_eq(Vec::<Suggestion>::default);
+    {
+        let entries = entries.clone();
+        let budget_rates = budget_rates.clone();
+        let suggestions = suggestions.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    if let Ok(list) = requests::get_entries().await {
+                        if let Ok(rates) =
+                            requests::get_exchange_rates_for(&list).await
+                        {
+                            budget_rates.set(rates);
+                        }
+                        suggestions.set(description_suggestions(&list));
+                        entries.set(list);
+                    }
+                });
+            },
+            (),
+        );
+    }
+    let suggestion_options: Vec<SelectOption> =
+        suggestions.iter().map(SelectOption::from).collect();
+
+    let amount = use_state_eq(|| None::<i64>);
+    let description = use_state_eq(String::default);
+    let oninput_description = {
+        let description = description.clone();
+        let category = category.clone();
+        let amount = amount.clone();
+        let currency = currency.clone();
+        let suggestions = suggestions.clone();
+        let touch = touch.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                let value = input.value();
+                if let Some(suggestion) = find_suggestion(&suggestions, &value)
+                {
+                    if category.is_none() {
+                        category.set(Some(AttrValue::from(
+                            suggestion.category.clone(),
+                        )));
+                    }
+                    if amount.is_none() {
+                        currency.set(suggestion.amount.currency);
+                        amount.set(Some(suggestion.amount.minor));
+                    }
+                }
+                description.set(value);
+                touch.emit(DESCRIPTION);
+            }
+        })
+    };
+    let onvaluechange = {
+        let amount = amount.clone();
+        let touch = touch.clone();
+        Callback::from(move |value: Option<i64>| {
+            amount.set(value);
+            touch.emit(AMOUNT);
+        })
+    };
+
+    let timezone = settings.timezone;
+    let now = Utc::now().with_timezone(&timezone).fixed_offset();
+    let selectable_range = DateTimeRange::from(
+        now - chrono::Duration::days(2 * 365),
+        now + chrono::Duration::days(2 * 365),
+    );
+    let locale = Locale::from_browser();
+
+    let selected_datetime = use_state_eq(|| Some(now));
+    let ondatetimechange = {
+        let selected_datetime = selected_datetime.clone();
+        let touch = touch.clone();
+        Callback::from(move |datetime| {
+            selected_datetime.set(datetime);
+            touch.emit(DATETIME);
+        })
+    };
+    let selected_date = selected_datetime.map(|d| d.date_naive());
+
+    // CATEGORIZATION RULES
+    let rules = use_state_eq(Vec::<CategoryRule>::default);
+    {
+        let rules = rules.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    if let Ok(list) = requests::get_rules().await {
+                        rules.set(list);
+                    }
+                });
+            },
+            (),
+        );
+    }
+    let subject = Subject {
+        description: &description,
+        amount: amount.map(|minor| Money::new(minor, *currency)),
+        datetime: *selected_datetime,
+    };
+    let matched_rule = categorize(&rules, &subject).cloned();
+    let is_category_manual = touched.contains(CATEGORY);
+    {
+        let category = category.clone();
+        use_effect_with_deps(
+            move |rule_category| {
+                if let (Some(rule_category), false) =
+                    (rule_category, is_category_manual)
+                {
+                    category.set(Some(AttrValue::from(rule_category.clone())));
+                }
+            },
+            matched_rule.as_ref().map(|rule| rule.category.clone()),
+        );
+    }
+    let category_explanation = match &matched_rule {
+        Some(rule)
+            if !is_category_manual
+                && category.as_deref() == Some(rule.category.as_str()) =>
+        {
+            Some(format!("Suggested {}", rule.explain(locale)))
+        },
+        _ => None,
+    };
+
+    // EXCHANGE RATE
+    let home_currency = settings.home_currency;
+    let entered_rate = use_state_eq(|| None::<ExchangeRate>);
+    let exchange_rate =
+        usable_rate(*entered_rate, *currency, home_currency, selected_date);
+    let onchange_exchange_rate = {
+        let entered_rate = entered_rate.clone();
+        Callback::from(move |rate| entered_rate.set(rate))
+    };
+
+    // ACCOUNTS
+    let account = use_state_eq(|| None::<AttrValue>);
+    let to_account = use_state_eq(|| None::<AttrValue>);
+    let kind = use_state_eq(|| EXPENSE);
+    let transfer = *kind == TRANSFER;
+
+    // SPLIT
+    let split = use_state_eq(|| false);
+    let lines = use_state_eq(Vec::<SplitLine>::new);
+    let onclick_split = {
+        let split = split.clone();
+        let lines = lines.clone();
+        let category = category.clone();
+        let amount = amount.clone();
+        Callback::from(move |_: MouseEvent| {
+            match *split {
+                true => lines.set(Vec::new()),
+                false => lines.set(initial_lines((*category).clone(), *amount)),
+            }
+            split.set(!*split);
+        })
+    };
+    let onchange_lines = {
+        let lines = lines.clone();
+        Callback::from(move |list| lines.set(list))
+    };
+
+    // VALIDATION
+    let category_result = match *split || transfer {
+        true => Ok(()),
+        false => validate_text(
+            category.as_deref().unwrap_or_default(),
+            &[Rule::Required],
+        ),
+    };
+    let account_result = validate_text(
+        account.as_deref().unwrap_or_default(),
+        &[Rule::Required],
+    );
+    let to_account_result = match (transfer, to_account.as_ref()) {
+        (false, _) => Ok(()),
+        (true, None) => Err(ValidationError::Required),
+        (true, Some(to)) if Some(to) == account.as_ref() => {
+            Err(ValidationError::SameAccount)
+        },
+        (true, Some(_)) => Ok(()),
+    };
+    let split_result = match *split {
+        true => validate_lines(*amount, &lines),
+        false => Ok(()),
+    };
+    let description_result =
+        validate_text(&description, &[Rule::MaxLength(DESCRIPTION_MAX_LENGTH)]);
+    let amount_result =
+        validate_amount(*amount, &[Rule::Required, Rule::PositiveAmount]);
+    let datetime_result =
+        validate_datetime(selected_datetime.as_ref(), &selectable_range);
+    let exchange_rate_result = match (*currency != home_currency, exchange_rate)
+    {
+        (true, None) => Err(ValidationError::Required),
+        _ => Ok(()),
+    };
+    let is_valid = [
+        category_result,
+        account_result,
+        to_account_result,
+        description_result,
+        amount_result,
+        datetime_result,
+        exchange_rate_result,
+        split_result,
+    ]
+    .iter()
+    .all(Result::is_ok);
+    let error_for = |field: &'static str,
+                     result: Result<(), ValidationError>| {
+        match (touched.contains(field), result) {
+            (true, Err(error)) => Some(AttrValue::from(error.to_string())),
+            _ => None,
+        }
+    };
+
+    // ONCHANGE
+    let ondefault_account = {
+        let account = account.clone();
+        Callback::from(move |name| account.set(Some(name)))
+    };
+    let onchange_kind = {
+        let kind = kind.clone();
+        let split = split.clone();
+        let lines = lines.clone();
+        Callback::from(move |selected: &'static str| {
+            if selected == TRANSFER {
+                split.set(false);
+                lines.set(Vec::new());
+            }
+            kind.set(selected);
+        })
+    };
+    let onchange_account = |state: UseStateHandle<Option<AttrValue>>,
+                            field: &'static str| {
+        let touch = touch.clone();
+        Callback::from(move |value: Option<AttrValue>| {
+            state.set(value);
+            touch.emit(field);
+        })
+    };
+    let onchange_currency = {
+        let currency = currency.clone();
+        Callback::from(move |e: Event| {
+            let code = e
+                .target_dyn_into::<HtmlSelectElement>()
+                .map(|select| select.value());
+            if let Some(value) = code.and_then(|c| Currency::from_code(&c)) {
+                currency.set(value);
+            }
+        })
+    };
+    let onselect_category = {
+        let category = category.clone();
+        let touch = touch.clone();
+        Callback::from(move |value: AttrValue| {
+            category.set(Some(value));
+            touch.emit(CATEGORY);
+        })
+    };
+    let oncreate_category = {
+        let categories = categories.clone();
+        let category = category.clone();
+        let touch = touch.clone();
+        Callback::from(move |name: AttrValue| {
+            let categories = categories.clone();
+            let category = category.clone();
+            touch.emit(CATEGORY);
+            spawn_local(async move {
+                if requests::post_category(&name).await.is_ok() {
+                    let mut options = (*categories).clone();
+                    options.push(SelectOption::from(name.as_str()));
+                    categories.set(options);
+                    category.set(Some(name));
+                }
+            });
+        })
+    };
+    // SUBMIT
+    let submitting = use_state_eq(|| false);
+    let entry_lines = entry_lines(&lines, *currency);
+    let entry_category = match (entry_lines.first(), transfer) {
+        (_, true) => Some(AttrValue::default()),
+        (Some(line), false) => Some(AttrValue::from(line.category.clone())),
+        (None, false) => (*category).clone(),
+    };
+    let entry_kind = match (*kind, to_account.as_ref()) {
+        (TRANSFER, Some(to)) => EntryKind::Transfer {
+            to_account: to.to_string(),
+        },
+        (INCOME, _) => EntryKind::Income,
+        _ => EntryKind::Expense,
+    };
+    let entry =
+        match (*selected_datetime, account.as_ref(), entry_category, *amount) {
+            (Some(datetime), Some(account), Some(category), Some(minor))
+                if is_valid =>
+            {
+                Some(Entry {
+                    datetime,
+                    account: account.to_string(),
+                    kind: entry_kind,
+                    category: category.to_string(),
+                    description: description.trim().to_owned(),
+                    tags: selected_tags.iter().map(|t| t.to_string()).collect(),
+                    amount: Money::new(minor, *currency),
+                    exchange_rate,
+                    lines: entry_lines,
+                })
+            },
+            _ => None,
+        };
+
+    // BUDGETS
+    let budgets = use_state_eq(Vec::<Budget>::default);
+    {
+        let budgets = budgets.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    if let Ok(list) = requests::get_budgets().await {
+                        budgets.set(list);
+                    }
+                });
+            },
+            (),
+        );
+    }
+    let budget_warnings: Vec<String> = entry
+        .iter()
+        .flat_map(|entry| {
+            overspending(&budgets, &entries, &budget_rates, &timezone, entry)
+        })
+        .map(|(budget, over)| {
+            format!(
+                "Saving this puts {} {} {} over budget",
+                budget.category,
+                over.format(locale),
+                over.currency
+            )
+        })
+        .collect();
+
+    let onsubmit = {
+        let touched = touched.clone();
+        let submitting = submitting.clone();
+        Callback::from(move |_: FormValues| {
+            let Some(entry) = entry.clone() else {
+                touched.set(
+                    [
+                        DATETIME,
+                        CATEGORY,
+                        DESCRIPTION,
+                        AMOUNT,
+                        EXCHANGE_RATE,
+                        SPLIT,
+                        ACCOUNT,
+                        TO_ACCOUNT,
+                    ]
+                    .into_iter()
+                    .collect(),
+                );
+                return;
+            };
+            let submitting = submitting.clone();
+            submitting.set(true);
+            spawn_local(async move {
+                if requests::post_entry(&entry).await.is_ok() {
+                    reset_form(FORM_ID);
+                }
+                submitting.set(false);
+            });
+        })
+    };
+    let onreset = {
+        let touched = touched.clone();
+        let category = category.clone();
+        let selected_tags = selected_tags.clone();
+        let description = description.clone();
+        let amount = amount.clone();
+        let currency = currency.clone();
+        let default_currency = settings.default_currency;
+        let selected_datetime = selected_datetime.clone();
+        let split = split.clone();
+        let lines = lines.clone();
+        let kind = kind.clone();
+        let to_account = to_account.clone();
+        let entered_rate = entered_rate.clone();
+        Callback::from(move |_| {
+            touched.set(HashSet::new());
+            kind.set(EXPENSE);
+            to_account.set(None);
+            split.set(false);
+            lines.set(Vec::new());
+            category.set(None);
+            selected_tags.set(Vec::new());
+            description.set(String::default());
+            amount.set(None);
+            currency.set(default_currency);
+            entered_rate.set(None);
+            selected_datetime.set(Some(now));
+        })
+    };
+
+    html! {
+        <section id={"new_entry"}>
+            <Form
+                id={FORM_ID}
+                submitting={*submitting}
+                {onsubmit}
+                {onreset}
+            >
+                <DateTimeSelect
+                    id={"datetime_select"}
+                    label={"Date"}
+                    range={Rc::from(selectable_range)}
+                    preselect={Rc::from(now)}
+                    {ondatetimechange}
+                    error={error_for(DATETIME, datetime_result)}
+                />
+                <AccountFields
+                    kind={*kind}
+                    account={(*account).clone()}
+                    to_account={(*to_account).clone()}
+                    account_error={error_for(ACCOUNT, account_result)}
+                    to_account_error={error_for(TO_ACCOUNT, to_account_result)}
+                    {ondefault_account}
+                    {onchange_kind}
+                    onchange_account={onchange_account(account.clone(), ACCOUNT)}
+                    onchange_to_account={onchange_account(to_account.clone(), TO_ACCOUNT)}
+                />
+                if !*split && !transfer {
+                    <Combobox
+                        id={"category_select"}
+                        label={"Category"}
+                        options={Rc::from(category_options.clone())}
+                        onselect={onselect_category}
+                        oncreate={oncreate_category}
+                        error={error_for(CATEGORY, category_result)}
+                    />
+                    if let Some(explanation) = category_explanation {
+                        <p id={"category_explanation"} role={"status"}>
+                            { explanation }
+                        </p>
+                    }
+                }
+                <Input
+                    id={"description_input"}
+                    label={"Description"}
+                    maxlength={DESCRIPTION_MAX_LENGTH}
+                    autocomplete={"off"}
+                    suggestions={Rc::from(suggestion_options)}
+                    value={AttrValue::from((*description).clone())}
+                    oninput={oninput_description}
+                    error={error_for(DESCRIPTION, description_result)}
+                />
+                <MultiSelect
+                    id={"tags_select"}
+                    label={"Tags"}
+                    options={Rc::from((*tags).clone())}
+                    creatable={true}
+                    onchange={onchange_tags}
+                />
+                <MonetaryInput
+                    id={"value_input"}
+                    label={"Value"}
+                    currency={*currency}
+                    {locale}
+                    value={*amount}
+                    {onvaluechange}
+                    error={error_for(AMOUNT, amount_result)}
+                />
+                <Select
+                    id={"currency_select"}
+                    label={"Currency"}
+                    options={Rc::from(currencies)}
+                    onchange={onchange_currency}
+                />
+                if *currency != home_currency {
+                    <ExchangeRateInput
+                        currency={*currency}
+                        {home_currency}
+                        date={selected_date}
+                        amount={*amount}
+                        {locale}
+                        error={error_for(EXCHANGE_RATE, exchange_rate_result)}
+                        onchange={onchange_exchange_rate}
+                        ontouch={touch.reform(|_| EXCHANGE_RATE)}
+                    />
+                }
+                if !transfer {
+                    <button
+                        id={"split_toggle"}
+                        type={"button"}
+                        aria-pressed={split.to_string()}
+                        aria-controls={"split_lines"}
+                        onclick={onclick_split}
+                    >
+                        { "Split across categories" }
+                    </button>
+                }
+                if *split {
+                    <SplitLines
+                        lines={Rc::from((*lines).clone())}
+                        category_options={Rc::from(category_options)}
+                        amount={*amount}
+                        currency={*currency}
+                        {locale}
+                        show_errors={touched.contains(SPLIT)}
+                        onchange={onchange_lines}
+                        ontouch={touch.reform(|_| SPLIT)}
+                    />
+                }
+                if !budget_warnings.is_empty() {
+                    <ul id={"budget_warning"} role={"status"}>
+                    {
+                        budget_warnings.into_iter().map(|warning| html! {
+                            <li>{ warning }</li>
+                        }).collect::<Html>()
+                    }
+                    </ul>
+                }
+                <button
+                    id={"submit_button"}
+                    type={"submit"}
+                    disabled={!is_valid || *submitting}
+                >
+                    { "Save" }
+                </button>
+                <button
+                    id={"reset_button"}
+                    type={"reset"}
+                    disabled={*submitting}
+                >
+                    { "Reset" }
+                </button>
+            </Form>
+        </section>
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use chrono::{
+        DateTime,
+        Datelike,
+        FixedOffset,
+        Utc,
+    };
+    use chrono_tz::Tz;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use web_sys::{
+        Event,
+        HtmlElement,
+        HtmlInputElement,
+        HtmlSelectElement,
+    };
+
+    use super::NewEntry;
+    use crate::{
+        dom::DOM,
+        types::entry::DESCRIPTION_MAX_LENGTH,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    async fn render_new_entry() {
+        let output = DOM::get_element_by_id("output")
+            .expect("Element `output` to exist");
+        yew::Renderer::<NewEntry>::with_root(output).render();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    async fn dispatch_input_event(target: &HtmlInputElement) {
+        let event = Event::new("input").expect("valid event");
+        target
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    async fn dispatch_change_event(target: &HtmlSelectElement) {
+        let event = Event::new("change").expect("valid event");
+        target
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    async fn click_button(id: &str) {
+        DOM::get_button_by_id(id)
+            .expect("Button Element to exist")
+            .dyn_into::<HtmlElement>()
+            .expect("Element to be HtmlElement")
+            .click();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    async fn enter_value(id: &str, value: &str) {
+        let element = DOM::get_input_by_id(id)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        element.set_value(value);
+        dispatch_input_event(&element).await;
+    }
+
+    async fn select_value(id: &str, value: &str) {
+        let element =
+            DOM::get_html_select_by_id(id).expect("Select Element to exist");
+        element.set_value(value);
+        dispatch_change_event(&element).await;
+    }
+
+    async fn select_currency(code: &str) {
+        select_value(CURRENCY_SELECT_ID, code).await;
+    }
+
+    static DATETIME_SELECT_ID: &str = "datetime_select";
+    static CATEGORY_SELECT_ID: &str = "category_select";
+    static KIND_SELECT_ID: &str = "kind_select";
+    static ACCOUNT_SELECT_ID: &str = "account_select";
+    static TO_ACCOUNT_SELECT_ID: &str = "to_account_select";
+    static TAGS_SELECT_ID: &str = "tags_select";
+    static DESCRIPTION_INPUT_ID: &str = "description_input";
+    static VALUE_INPUT_ID: &str = "value_input";
+    static CURRENCY_SELECT_ID: &str = "currency_select";
+    static EXCHANGE_RATE_INPUT_ID: &str = "exchange_rate_input";
+    static CONVERTED_VALUE_ID: &str = "converted_value";
+    static SUBMIT_BUTTON_ID: &str = "submit_button";
+    static RESET_BUTTON_ID: &str = "reset_button";
+    static FORM_ID: &str = "new_entry_form";
+    static SPLIT_TOGGLE_ID: &str = "split_toggle";
+    static SPLIT_REMAINDER_ID: &str = "split_remainder";
+
+    // DATETIME INPUT TESTS
+    #[wasm_bindgen_test]
+    async fn page_contains_datetime_select_elements() {
+        render_new_entry().await;
+
+        for postfix in &["year", "month", "day"] {
+            let id = format!("{}_{}", DATETIME_SELECT_ID, postfix);
+            let element = DOM::get_select_by_id(&id);
+
+            assert!(element.is_some());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn datetime_select_elements_are_visible() {
+        render_new_entry().await;
+
+        for postfix in &["year", "month", "day"] {
+            let id = format!("{}_{}", DATETIME_SELECT_ID, postfix);
+            let element =
+                DOM::get_select_by_id(&id).expect("Select Element to exist");
+
+            assert!(DOM::is_element_visible(&element));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn page_contains_datetime_select_label_elements() {
+        render_new_entry().await;
+
+        for postfix in &["year", "month", "day"] {
+            let id = format!("{}_{}", DATETIME_SELECT_ID, postfix);
+            let element = DOM::get_label_by_for(&id);
+
+            assert!(element.is_some());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn datetime_select_label_elements_are_visible() {
+        render_new_entry().await;
+
+        for postfix in &["year", "month", "day"] {
+            let id = format!("{}_{}", DATETIME_SELECT_ID, postfix);
+            let element =
+                DOM::get_label_by_for(&id).expect("Label Element to exist");
+
+            assert!(DOM::is_element_visible(&element));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn datetime_select_label_elements_have_expected_inner_htmls() {
+        render_new_entry().await;
+
+        for (postfix, expected_inner_html) in
+            &[("year", "Year"), ("month", "Month"), ("day", "Day")]
+        {
+            let id = format!("{}_{}", DATETIME_SELECT_ID, postfix);
+            let element =
+                DOM::get_label_by_for(&id).expect("Label Element to exist");
+
+            assert_eq!(&element.inner_html(), expected_inner_html);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn datetime_select_component_has_attribute_selected_datetime() {
+        render_new_entry().await;
+
+        let element = DOM::get_section_by_id(DATETIME_SELECT_ID)
+            .expect("Section Element to exist");
+
+        let attribute = element.get_attribute("selected_datetime");
+
+        assert!(attribute.is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn datetime_select_component_allows_2_years_before_and_after_now() {
+        render_new_entry().await;
+
+        let id = format!("{}_year", DATETIME_SELECT_ID);
+        let element = DOM::get_select_by_id(&id)
+            .expect("Select Element to exist")
+            .dyn_into::<HtmlSelectElement>()
+            .expect("Element to be Select");
+
+        let mut available_years = vec![];
+        for index in 0..element.length() {
+            let option = element.get(index as u32).expect("Element to exist");
+            available_years.push(option.inner_html());
+        }
+
+        let expected_start = Utc::now()
+            .with_timezone(&Tz::Europe__Helsinki)
+            .fixed_offset()
+            - chrono::Duration::days(2 * 365);
+        let expected_end = Utc::now()
+            .with_timezone(&Tz::Europe__Helsinki)
+            .fixed_offset()
+            + chrono::Duration::days(2 * 365);
+        let expected_available_years: Vec<String> = (expected_start.year()
+            ..=expected_end.year())
+            .map(|v| v.to_string())
+            .collect();
+
+        assert_eq!(available_years, expected_available_years);
+    }
+
+    #[wasm_bindgen_test]
+    async fn datetime_select_component_has_current_datetime_preselected_in_selected_datetime_attribute(
+    ) {
+        render_new_entry().await;
+
+        let element = DOM::get_section_by_id(DATETIME_SELECT_ID)
+            .expect("Section Element to exist");
+
+        let attribute = element
+            .get_attribute("selected_datetime")
+            .expect("Attribute to exist");
+
+        let now = Utc::now()
+            .with_timezone(&Tz::Europe__Helsinki)
+            .fixed_offset();
+
+        assert_eq!(attribute, now.to_rfc2822());
+    }
+
+    #[wasm_bindgen_test]
+    async fn datetime_select_component_has_current_datetime_preselected_in_select_elements(
+    ) {
+        render_new_entry().await;
+
+        #[derive(Debug, PartialEq, Default)]
+        struct Selected {
+            year: Option<String>,
+            month: Option<String>,
+            day: Option<String>,
+        }
+        impl Selected {
+            fn change(&mut self, attribute: &str, value: Option<String>) {
+                match attribute {
+                    "year" => self.year = value,
+                    "month" => self.month = value,
+                    "day" => self.day = value,
+                    _ => panic!("no such attribute"),
+                }
+            }
+
+            fn with_datetime(datetime: &DateTime<FixedOffset>) -> Self {
+                Self {
+                    year: Some(datetime.year().to_string()),
+                    month: Some(datetime.month().to_string()),
+                    day: Some(datetime.day().to_string()),
+                }
+            }
+        }
+
+        let mut selected = Selected::default();
+
+        for postfix in &["year", "month", "day"] {
+            let id = format!("{}_{}", DATETIME_SELECT_ID, postfix);
+            let element = DOM::get_select_by_id(&id)
+                .expect("Select Element to exist")
+                .dyn_into::<HtmlSelectElement>()
+                .expect("Element to be Select");
+
+            let selected_index = element.selected_index();
+            let option = element
+                .get(selected_index as u32)
+                .expect("Element to exist");
+            selected.change(postfix, option.get_attribute("value"));
+        }
+
+        let now = Utc::now().with_timezone(&Tz::Europe__Helsinki);
+        let expected_selected = Selected::with_datetime(&now.fixed_offset());
+
+        assert_eq!(selected, expected_selected);
+    }
+
+    // REPEAT INPUT TESTS
+    fn repeat_input_tests() {
+        todo!();
+    }
+
+    // CATEGORY SELECT TESTS
+    #[wasm_bindgen_test]
+    async fn page_contains_category_combobox_element() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(CATEGORY_SELECT_ID)
+            .expect("Input Element to exist");
+
+        assert_eq!(element.get_attribute("role"), Some("combobox".into()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn category_combobox_element_is_visible() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(CATEGORY_SELECT_ID)
+            .expect("Input Element to exist");
+
+        assert!(DOM::is_element_visible(&element));
+    }
+
+    #[wasm_bindgen_test]
+    async fn page_contains_category_select_label_element() {
+        render_new_entry().await;
+
+        let element = DOM::get_label_by_for(CATEGORY_SELECT_ID);
+
+        assert!(element.is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn category_select_label_element_is_visible() {
+        render_new_entry().await;
+
+        let element = DOM::get_label_by_for(CATEGORY_SELECT_ID)
+            .expect("Label Element to exist");
+
+        assert!(DOM::is_element_visible(&element));
+    }
+
+    #[wasm_bindgen_test]
+    async fn category_select_label_element_has_expected_inner_html() {
+        render_new_entry().await;
+
+        let element = DOM::get_label_by_for(CATEGORY_SELECT_ID)
+            .expect("Label Element to exist");
+
+        assert_eq!(&element.inner_html(), "Category");
+    }
+
+    #[wasm_bindgen_test]
+    async fn category_combobox_element_has_expected_options() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(CATEGORY_SELECT_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        element.focus().expect("Element to be focused");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+
+        let listbox_id = format!("{}_listbox", CATEGORY_SELECT_ID);
+        let listbox =
+            DOM::get_element_by_id(&listbox_id).expect("Listbox to exist");
+        let mut select_options = vec![];
+        for index in 0..listbox.children().length() {
+            let option = listbox.children().item(index).expect("Element");
+            select_options.push(option.inner_html());
+        }
+
+        let categories = crate::requests::get_categories()
+            .await
+            .expect("Categories to be returned");
+        let expected_options: Vec<String> =
+            crate::types::category::select_options(&categories)
+                .iter()
+                .map(|option| option.text())
+                .collect();
+
+        assert_eq!(select_options, expected_options);
+    }
+
+    #[wasm_bindgen_test]
+    async fn category_combobox_offers_to_create_new_category() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(CATEGORY_SELECT_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        element.focus().expect("Element to be focused");
+        element.set_value("Pets");
+        dispatch_input_event(&element).await;
+
+        let option_id = format!("{}_option_0", CATEGORY_SELECT_ID);
+        let option =
+            DOM::get_element_by_id(&option_id).expect("Option to exist");
+
+        assert_eq!(option.inner_html(), "Create ‘Pets’");
+    }
+
+    // TAGS SELECT TESTS
+    #[wasm_bindgen_test]
+    async fn page_contains_tags_select_element() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(TAGS_SELECT_ID)
+            .expect("Input Element to exist");
+        let chips =
+            DOM::get_element_by_id(&format!("{}_chips", TAGS_SELECT_ID));
+
+        assert!(DOM::is_element_visible(&element));
+        assert!(chips.is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn tags_select_label_element_has_expected_inner_html() {
+        render_new_entry().await;
+
+        let element = DOM::get_label_by_for(TAGS_SELECT_ID)
+            .expect("Label Element to exist");
+
+        assert_eq!(&element.inner_html(), "Tags");
+    }
+
+    // DESCRIPTION INPUT TESTS
+    #[wasm_bindgen_test]
+    async fn page_contains_description_input_element() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(DESCRIPTION_INPUT_ID);
+
+        assert!(element.is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn description_input_element_is_visible() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(DESCRIPTION_INPUT_ID)
+            .expect("Input Element to exist");
+
+        assert!(DOM::is_element_visible(&element));
+    }
+
+    #[wasm_bindgen_test]
+    async fn page_contains_label_for_description_input_element() {
+        render_new_entry().await;
+
+        let element = DOM::get_label_by_for(DESCRIPTION_INPUT_ID);
+
+        assert!(element.is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn description_input_label_element_is_visible() {
+        render_new_entry().await;
+
+        let element = DOM::get_label_by_for(DESCRIPTION_INPUT_ID)
+            .expect("Label Element to exist");
+
+        assert!(DOM::is_element_visible(&element));
+    }
+
+    #[wasm_bindgen_test]
+    async fn description_input_label_element_has_expected_inner_html() {
+        render_new_entry().await;
+
+        let element = DOM::get_label_by_for(DESCRIPTION_INPUT_ID)
+            .expect("Label Element to exist");
+
+        assert_eq!(&element.inner_html(), "Description");
+    }
+
+    #[wasm_bindgen_test]
+    async fn description_input_element_type_is_text() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(DESCRIPTION_INPUT_ID)
+            .expect("Input Element to exist");
+        let input_type = element.get_attribute("type");
+
+        assert_eq!(input_type, Some("text".to_string()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn description_input_element_is_length_limited() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(DESCRIPTION_INPUT_ID)
+            .expect("Input Element to exist");
+
+        assert_eq!(
+            element.get_attribute("maxlength"),
+            Some(DESCRIPTION_MAX_LENGTH.to_string())
+        );
+    }
+
+    // VALUE INPUT TESTS
+    #[wasm_bindgen_test]
+    async fn page_contains_value_input_element() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(VALUE_INPUT_ID);
+
+        assert!(element.is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn value_input_element_is_visible() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(VALUE_INPUT_ID)
+            .expect("Input Element to exist");
+
+        assert!(DOM::is_element_visible(&element));
+    }
+
+    #[wasm_bindgen_test]
+    async fn page_contains_label_for_value_input_element() {
+        render_new_entry().await;
+
+        let element = DOM::get_label_by_for(VALUE_INPUT_ID);
+
+        assert!(element.is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn value_input_label_element_is_visible() {
+        render_new_entry().await;
+
+        let element = DOM::get_label_by_for(VALUE_INPUT_ID)
+            .expect("Label Element to exist");
+
+        assert!(DOM::is_element_visible(&element));
+    }
+
+    #[wasm_bindgen_test]
+    async fn value_input_label_element_has_expected_inner_html() {
+        render_new_entry().await;
+
+        let element = DOM::get_label_by_for(VALUE_INPUT_ID)
+            .expect("Label Element to exist");
+
+        assert_eq!(&element.inner_html(), "Value");
+    }
+
+    #[wasm_bindgen_test]
+    async fn value_input_element_type_is_text() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(VALUE_INPUT_ID)
+            .expect("Input Element to exist");
+        let input_type = element.get_attribute("type");
+
+        assert_eq!(input_type, Some("text".to_string()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn value_input_element_inputmode_is_numeric() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(VALUE_INPUT_ID)
+            .expect("Input Element to exist");
+        let input_mode = element.get_attribute("inputmode");
+
+        assert_eq!(input_mode, Some("numeric".to_string()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn value_input_element_placeholder_is_string_representing_monetary_zero(
+    ) {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(VALUE_INPUT_ID)
+            .expect("Input Element to exist");
+        let placeholder = element.get_attribute("placeholder");
+
+        assert_eq!(placeholder, Some("0.00".to_string()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn value_input_element_value_is_formatted_on_input() {
+        render_new_entry().await;
+
+        let element = DOM::get_input_by_id(VALUE_INPUT_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+
+        let tests = vec![
+            ("", "0.00"),
+            ("0", "0.00"),
+            ("0000000000", "0.00"),
+            ("2", "0.02"),
+            ("23", "0.23"),
+            ("fas", "0.00"),
+            ("fas1", "0.01"),
+            ("fas1234", "12.34"),
+            ("fas0001234", "12.34"),
+            ("fas0001s234juhda", "12.34"),
+            ("fas0001s234juhda9", "123.49"),
+            ("fas0001s234juhda900", "12,349.00"),
+            ("9223372036854775807", "92,233,720,368,547,758.07"),
+            ("9223372036854775808", "9,223,372,036,854,775.80"),
+        ];
+
+        for case in tests {
+            element.set_value(case.0);
+            dispatch_input_event(&element).await;
+
+            assert_eq!(element.value(), case.1);
+        }
+    }
+
+    // CURRENCY SELECT TESTS
+    #[wasm_bindgen_test]
+    async fn page_contains_currency_select_element() {
+        render_new_entry().await;
+
+        let element = DOM::get_select_by_id(CURRENCY_SELECT_ID);
+
+        assert!(element.is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn currency_select_element_is_visible() {
+        render_new_entry().await;
+
+        let element = DOM::get_select_by_id(CURRENCY_SELECT_ID)
+            .expect("Select Element to exist");
+
+        assert!(DOM::is_element_visible(&element));
+    }
+
+    #[wasm_bindgen_test]
+    async fn currency_select_label_element_has_expected_inner_html() {
+        render_new_entry().await;
+
+        let element = DOM::get_label_by_for(CURRENCY_SELECT_ID)
+            .expect("Label Element to exist");
+
+        assert_eq!(&element.inner_html(), "Currency");
+    }
+
+    #[wasm_bindgen_test]
+    async fn currency_select_element_has_default_currency_selected() {
+        render_new_entry().await;
+
+        let element = DOM::get_html_select_by_id(CURRENCY_SELECT_ID)
+            .expect("Select Element to exist");
+
+        let expected = crate::requests::get_settings()
+            .await
+            .expect("Settings to be returned")
+            .default_currency;
+
+        assert_eq!(element.value(), expected.to_string());
+    }
+
+    #[wasm_bindgen_test]
+    async fn value_input_placeholder_follows_selected_currency() {
+        render_new_entry().await;
+        select_currency("JPY").await;
+
+        let element = DOM::get_input_by_id(VALUE_INPUT_ID)
+            .expect("Input Element to exist");
+        let placeholder = element.get_attribute("placeholder");
+
+        assert_eq!(placeholder, Some("0".to_string()));
+    }
+
+    // EXCHANGE RATE INPUT TESTS
+    #[wasm_bindgen_test]
+    async fn exchange_rate_input_is_absent_for_home_currency() {
+        render_new_entry().await;
+        select_currency("EUR").await;
+
+        let element = DOM::get_input_by_id(EXCHANGE_RATE_INPUT_ID);
+
+        assert!(element.is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn exchange_rate_input_is_present_for_foreign_currency() {
+        render_new_entry().await;
+        select_currency("USD").await;
+
+        let element = DOM::get_input_by_id(EXCHANGE_RATE_INPUT_ID)
+            .expect("Input Element to exist");
+        let label = DOM::get_label_by_for(EXCHANGE_RATE_INPUT_ID)
+            .expect("Label Element to exist");
+
+        assert!(DOM::is_element_visible(&element));
+        assert_eq!(&label.inner_html(), "USD to EUR rate");
+        assert_eq!(
+            element.get_attribute("inputmode"),
+            Some("decimal".to_string())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn converted_value_shows_amount_in_home_currency() {
+        render_new_entry().await;
+        select_currency("USD").await;
+
+        let value = DOM::get_input_by_id(VALUE_INPUT_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        value.set_value("1000");
+        dispatch_input_event(&value).await;
+
+        let rate = DOM::get_input_by_id(EXCHANGE_RATE_INPUT_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        rate.set_value("0.5");
+        dispatch_input_event(&rate).await;
+
+        let element = DOM::get_element_by_id(CONVERTED_VALUE_ID)
+            .expect("Element to exist");
+
+        assert_eq!(&element.inner_html(), "5.00 EUR");
+    }
+
+    // VALIDATION TESTS
+    #[wasm_bindgen_test]
+    async fn submit_button_is_disabled_while_form_is_invalid() {
+        render_new_entry().await;
+
+        let element = DOM::get_button_by_id(SUBMIT_BUTTON_ID)
+            .expect("Button Element to exist");
+
+        assert!(DOM::is_element_visible(&element));
+        assert!(element.has_attribute("disabled"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn errors_are_not_shown_for_untouched_fields() {
+        render_new_entry().await;
+
+        for id in [CATEGORY_SELECT_ID, VALUE_INPUT_ID] {
+            let error = DOM::get_element_by_id(&format!("{}_error", id));
+
+            assert!(error.is_none());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn value_input_shows_error_for_non_positive_amount() {
+        render_new_entry().await;
+
+        let value = DOM::get_input_by_id(VALUE_INPUT_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        value.set_value("0");
+        dispatch_input_event(&value).await;
+
+        let error_id = format!("{}_error", VALUE_INPUT_ID);
+        let error =
+            DOM::get_element_by_id(&error_id).expect("Error Element to exist");
+
+        assert_eq!(&error.inner_html(), "Must be greater than zero");
+        assert_eq!(value.get_attribute("aria-describedby"), Some(error_id));
+    }
+
+    #[wasm_bindgen_test]
+    async fn description_input_shows_error_when_too_long() {
+        render_new_entry().await;
+
+        let description = DOM::get_input_by_id(DESCRIPTION_INPUT_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        description.set_value(&"a".repeat(256));
+        dispatch_input_event(&description).await;
+
+        let error =
+            DOM::get_element_by_id(&format!("{}_error", DESCRIPTION_INPUT_ID))
+                .expect("Error Element to exist");
+
+        assert_eq!(&error.inner_html(), "Must be at most 255 characters");
+    }
+
+    // FORM TESTS
+    #[wasm_bindgen_test]
+    async fn page_contains_form_element() {
+        render_new_entry().await;
+
+        let element =
+            DOM::get_element_by_id(FORM_ID).expect("Form Element to exist");
+
+        assert_eq!(element.tag_name(), "FORM");
+        assert_eq!(
+            element.get_attribute("data-status"),
+            Some("pristine".into())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn reset_button_element_type_is_reset() {
+        render_new_entry().await;
+
+        let button = DOM::get_button_by_id(RESET_BUTTON_ID)
+            .expect("Button Element to exist");
+
+        assert_eq!(button.get_attribute("type"), Some("reset".to_string()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn form_is_dirty_after_input_and_reset_clears_fields() {
+        render_new_entry().await;
+
+        let description = DOM::get_input_by_id(DESCRIPTION_INPUT_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        description.set_value(&"a".repeat(256));
+        dispatch_input_event(&description).await;
+
+        let form =
+            DOM::get_element_by_id(FORM_ID).expect("Form Element to exist");
+
+        assert_eq!(form.get_attribute("data-status"), Some("dirty".into()));
+
+        let event = Event::new("reset").expect("valid event");
+        form.dispatch_event(&event).expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+
+        let description = DOM::get_input_by_id(DESCRIPTION_INPUT_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        let error_id = format!("{}_error", DESCRIPTION_INPUT_ID);
+
+        assert_eq!(description.value(), "");
+        assert!(DOM::get_element_by_id(&error_id).is_none());
+        assert_eq!(form.get_attribute("data-status"), Some("pristine".into()));
+    }
+
+    // SPLIT TESTS
+    #[wasm_bindgen_test]
+    async fn split_toggle_element_is_not_pressed_initially() {
+        render_new_entry().await;
+
+        let button = DOM::get_button_by_id(SPLIT_TOGGLE_ID)
+            .expect("Button Element to exist");
+
+        assert_eq!(button.get_attribute("type"), Some("button".to_string()));
+        assert_eq!(
+            button.get_attribute("aria-pressed"),
+            Some("false".to_string())
+        );
+        assert!(DOM::get_element_by_id("split_lines").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn split_mode_replaces_category_with_two_lines() {
+        render_new_entry().await;
+        click_button(SPLIT_TOGGLE_ID).await;
+
+        let button = DOM::get_button_by_id(SPLIT_TOGGLE_ID)
+            .expect("Button Element to exist");
+
+        assert_eq!(
+            button.get_attribute("aria-pressed"),
+            Some("true".to_string())
+        );
+        assert!(DOM::get_input_by_id(CATEGORY_SELECT_ID).is_none());
+        for index in 0..2 {
+            assert!(DOM::get_select_by_id(&format!(
+                "split_category_{}",
+                index
+            ))
+            .is_some());
+            assert!(DOM::get_input_by_id(&format!("split_amount_{}", index))
+                .is_some());
+        }
+
+        click_button("add_line_button").await;
+
+        assert!(DOM::get_select_by_id("split_category_2").is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn split_remainder_shows_unassigned_amount() {
+        render_new_entry().await;
+        enter_value(VALUE_INPUT_ID, "1000").await;
+        click_button(SPLIT_TOGGLE_ID).await;
+        enter_value("split_amount_0", "600").await;
+        enter_value("split_amount_1", "300").await;
+
+        let remainder = DOM::get_element_by_id(SPLIT_REMAINDER_ID)
+            .expect("Remainder Element to exist");
+        let error = DOM::get_element_by_id("split_lines_error")
+            .expect("Error Element to exist");
+        let submit = DOM::get_button_by_id(SUBMIT_BUTTON_ID)
+            .expect("Button Element to exist");
+
+        assert_eq!(&remainder.inner_html(), "Remaining 1.00 EUR");
+        assert_eq!(&error.inner_html(), "Lines must add up to the total");
+        assert!(submit.has_attribute("disabled"));
+
+        enter_value("split_amount_1", "400").await;
+
+        let remainder = DOM::get_element_by_id(SPLIT_REMAINDER_ID)
+            .expect("Remainder Element to exist");
+
+        assert_eq!(&remainder.inner_html(), "Remaining 0.00 EUR");
+        assert!(DOM::get_element_by_id("split_lines_error").is_none());
+    }
+
+    // ACCOUNT TESTS
+    #[wasm_bindgen_test]
+    async fn page_contains_account_select_element() {
+        render_new_entry().await;
+
+        let select = DOM::get_html_select_by_id(ACCOUNT_SELECT_ID)
+            .expect("Select Element to exist");
+        let label = DOM::get_label_by_for(ACCOUNT_SELECT_ID)
+            .expect("Label Element to exist");
+
+        assert_eq!(&label.inner_html(), "Account");
+        assert_eq!(select.value(), "Checking");
+        assert!(DOM::get_html_select_by_id(TO_ACCOUNT_SELECT_ID).is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn transfer_replaces_category_with_to_account() {
+        render_new_entry().await;
+        select_value(KIND_SELECT_ID, "transfer").await;
+
+        let label = DOM::get_label_by_for(ACCOUNT_SELECT_ID)
+            .expect("Label Element to exist");
+        let to_account = DOM::get_html_select_by_id(TO_ACCOUNT_SELECT_ID)
+            .expect("Select Element to exist");
+
+        assert_eq!(&label.inner_html(), "From account");
+        assert_eq!(to_account.value(), "");
+        assert!(DOM::get_input_by_id(CATEGORY_SELECT_ID).is_none());
+        assert!(DOM::get_button_by_id(SPLIT_TOGGLE_ID).is_none());
+
+        select_value(TO_ACCOUNT_SELECT_ID, "Cash").await;
+
+        let to_account = DOM::get_html_select_by_id(TO_ACCOUNT_SELECT_ID)
+            .expect("Select Element to exist");
+        let error_id = format!("{}_error", TO_ACCOUNT_SELECT_ID);
+
+        assert_eq!(to_account.value(), "Cash");
+        assert!(DOM::get_element_by_id(&error_id).is_none());
+    }
+}
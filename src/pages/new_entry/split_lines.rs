@@ -0,0 +1,436 @@
+use std::rc::Rc;
+
+use web_sys::{
+    Event,
+    HtmlInputElement,
+    HtmlSelectElement,
+    InputEvent,
+    MouseEvent,
+};
+use yew::{
+    function_component,
+    html,
+    AttrValue,
+    Callback,
+    Html,
+    Properties,
+    TargetCast,
+};
+
+use crate::{
+    components::{
+        Input,
+        MonetaryInput,
+        Select,
+    },
+    types::{
+        currency::Currency,
+        entry::{
+            split_remainder,
+            EntryLine,
+            DESCRIPTION_MAX_LENGTH,
+        },
+        locale::Locale,
+        money::Money,
+        select::SelectOption,
+        validation::{
+            validate_amount,
+            validate_split,
+            validate_text,
+            Rule,
+            ValidationError,
+        },
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct SplitLine {
+    pub(crate) key: usize,
+    pub(crate) category: Option<AttrValue>,
+    pub(crate) description: String,
+    pub(crate) amount: Option<i64>,
+}
+
+type LineResult = (Result<(), ValidationError>, Result<(), ValidationError>);
+
+pub(crate) fn initial_lines(
+    category: Option<AttrValue>,
+    amount: Option<i64>,
+) -> Vec<SplitLine> {
+    vec![
+        SplitLine {
+            key: 0,
+            category,
+            amount,
+            ..Default::default()
+        },
+        SplitLine {
+            key: 1,
+            ..Default::default()
+        },
+    ]
+}
+
+pub(crate) fn entry_lines(
+    lines: &[SplitLine],
+    currency: Currency,
+) -> Vec<EntryLine> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            Some(EntryLine {
+                category: line.category.as_ref()?.to_string(),
+                description: line.description.trim().to_owned(),
+                amount: Money::new(line.amount?, currency),
+            })
+        })
+        .collect()
+}
+
+fn line_results(lines: &[SplitLine]) -> Vec<LineResult> {
+    lines
+        .iter()
+        .map(|line| {
+            (
+                validate_text(
+                    line.category.as_deref().unwrap_or_default(),
+                    &[Rule::Required],
+                ),
+                validate_amount(
+                    line.amount,
+                    &[Rule::Required, Rule::PositiveAmount],
+                ),
+            )
+        })
+        .collect()
+}
+
+fn line_amounts(lines: &[SplitLine]) -> Vec<Option<i64>> {
+    lines.iter().map(|line| line.amount).collect()
+}
+
+pub(crate) fn validate_lines(
+    total: Option<i64>,
+    lines: &[SplitLine],
+) -> Result<(), ValidationError> {
+    line_results(lines)
+        .into_iter()
+        .flat_map(|(category, amount)| [category, amount])
+        .try_for_each(|result| result)?;
+    validate_split(total, &line_amounts(lines))
+}
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct SplitLinesProps {
+    pub(crate) lines: Rc<[SplitLine]>,
+    pub(crate) category_options: Rc<[SelectOption]>,
+    pub(crate) amount: Option<i64>,
+    pub(crate) currency: Currency,
+    pub(crate) locale: Locale,
+    #[prop_or_default]
+    pub(crate) show_errors: bool,
+    pub(crate) onchange: Callback<Vec<SplitLine>>,
+    pub(crate) ontouch: Callback<()>,
+}
+
+#[function_component(SplitLines)]
+pub(crate) fn split_lines(props: &SplitLinesProps) -> Html {
+    let currency = props.currency;
+    let locale = props.locale;
+    let onclick_add_line = {
+        let lines = props.lines.clone();
+        let onchange = props.onchange.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut list = lines.to_vec();
+            let key = list.iter().map(|line| line.key + 1).max();
+            list.push(SplitLine {
+                key: key.unwrap_or_default(),
+                ..Default::default()
+            });
+            onchange.emit(list);
+        })
+    };
+    let set_line = {
+        let lines = props.lines.clone();
+        let onchange = props.onchange.clone();
+        let ontouch = props.ontouch.clone();
+        Callback::from(move |(index, line): (usize, SplitLine)| {
+            let mut list = lines.to_vec();
+            if let Some(slot) = list.get_mut(index) {
+                *slot = line;
+            }
+            onchange.emit(list);
+            ontouch.emit(());
+        })
+    };
+    let remove_line = {
+        let lines = props.lines.clone();
+        let onchange = props.onchange.clone();
+        Callback::from(move |index: usize| {
+            let mut list = lines.to_vec();
+            list.remove(index);
+            onchange.emit(list);
+        })
+    };
+
+    let line_money: Option<Vec<Money>> = props
+        .lines
+        .iter()
+        .map(|line| line.amount.map(|minor| Money::new(minor, currency)))
+        .collect();
+    let remainder = match (props.amount, line_money) {
+        (Some(total), Some(line_money)) => {
+            split_remainder(Money::new(total, currency), &line_money)
+        },
+        _ => None,
+    };
+    let split_result =
+        validate_split(props.amount, &line_amounts(&props.lines));
+    let error_for =
+        |result: Result<(), ValidationError>| match (props.show_errors, result)
+        {
+            (true, Err(error)) => Some(AttrValue::from(error.to_string())),
+            _ => None,
+        };
+
+    let line_rows = props
+        .lines
+        .iter()
+        .zip(line_results(&props.lines))
+        .enumerate()
+        .map(|(index, (line, (category_result, amount_result)))| {
+            let options: Vec<SelectOption> = std::iter::once(SelectOption {
+                inner_html: AttrValue::from("Choose a category"),
+                ..Default::default()
+            })
+            .chain(props.category_options.iter().cloned())
+            .map(|o| {
+                let selected = match &line.category {
+                    Some(category) => o.value == *category,
+                    None => o.value.is_empty(),
+                };
+                o.selected(selected)
+            })
+            .collect();
+            let onchange_category = {
+                let set_line = set_line.clone();
+                let line = line.clone();
+                Callback::from(move |e: Event| {
+                    let value = e
+                        .target_dyn_into::<HtmlSelectElement>()
+                        .map(|select| select.value())
+                        .filter(|value| !value.is_empty());
+                    let category = value.map(AttrValue::from);
+                    set_line.emit((
+                        index,
+                        SplitLine {
+                            category,
+                            ..line.clone()
+                        },
+                    ));
+                })
+            };
+            let oninput_description = {
+                let set_line = set_line.clone();
+                let line = line.clone();
+                Callback::from(move |e: InputEvent| {
+                    if let Some(input) = e.target_dyn_into::<HtmlInputElement>()
+                    {
+                        let description = input.value();
+                        set_line.emit((
+                            index,
+                            SplitLine {
+                                description,
+                                ..line.clone()
+                            },
+                        ));
+                    }
+                })
+            };
+            let onvaluechange = {
+                let set_line = set_line.clone();
+                let line = line.clone();
+                Callback::from(move |amount| {
+                    set_line.emit((
+                        index,
+                        SplitLine {
+                            amount,
+                            ..line.clone()
+                        },
+                    ))
+                })
+            };
+            let onclick_remove = {
+                let remove_line = remove_line.clone();
+                Callback::from(move |_: MouseEvent| remove_line.emit(index))
+            };
+            html! {
+                <fieldset key={line.key} id={format!("split_line_{}", index)}>
+                    <legend>{ format!("Line {}", index + 1) }</legend>
+                    <Select
+                        id={format!("split_category_{}", index)}
+                        label={"Category"}
+                        options={Rc::from(options)}
+                        onchange={onchange_category}
+                        error={error_for(category_result)}
+                    />
+                    <Input
+                        id={format!("split_description_{}", index)}
+                        label={"Description"}
+                        maxlength={DESCRIPTION_MAX_LENGTH}
+                        value={AttrValue::from(line.description.clone())}
+                        oninput={oninput_description}
+                    />
+                    <MonetaryInput
+                        id={format!("split_amount_{}", index)}
+                        label={"Value"}
+                        {currency}
+                        {locale}
+                        value={line.amount}
+                        {onvaluechange}
+                        error={error_for(amount_result)}
+                    />
+                    <button
+                        id={format!("remove_line_{}", index)}
+                        type={"button"}
+                        disabled={props.lines.len() <= 2}
+                        aria-label={format!("Remove line {}", index + 1)}
+                        onclick={onclick_remove}
+                    >
+                        { "×" }
+                    </button>
+                </fieldset>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <section id={"split_lines"}>
+            { line_rows }
+            <button
+                id={"add_line_button"}
+                type={"button"}
+                onclick={onclick_add_line}
+            >
+                { "Add line" }
+            </button>
+            <p id={"split_remainder"} role={"status"}>
+            {
+                match remainder {
+                    Some(money) => format!("Remaining {} {}", money.format(locale), money.currency),
+                    None => String::default(),
+                }
+            }
+            </p>
+            if let Some(error) = error_for(split_result) {
+                <p id={"split_lines_error"} class={"error"}>{ error }</p>
+            }
+        </section>
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use yew::AttrValue;
+
+    use super::{
+        entry_lines,
+        initial_lines,
+        validate_lines,
+        SplitLine,
+    };
+    use crate::types::{
+        currency::Currency,
+        entry::EntryLine,
+        money::Money,
+        validation::ValidationError,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn line(category: Option<&'static str>, amount: Option<i64>) -> SplitLine {
+        SplitLine {
+            category: category.map(AttrValue::from),
+            amount,
+            ..Default::default()
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn initial_lines_carry_category_and_amount_to_first_line() {
+        let lines = initial_lines(Some(AttrValue::from("Food")), Some(1000));
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].category, Some(AttrValue::from("Food")));
+        assert_eq!(lines[0].amount, Some(1000));
+        assert_eq!(
+            lines[1],
+            SplitLine {
+                key: 1,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn entry_lines_skip_incomplete_lines() {
+        let lines = vec![
+            SplitLine {
+                description: String::from(" Lunch "),
+                ..line(Some("Food"), Some(600))
+            },
+            line(None, Some(400)),
+            line(Some("Rent"), None),
+        ];
+        let expected = vec![EntryLine {
+            category: String::from("Food"),
+            description: String::from("Lunch"),
+            amount: Money::new(600, Currency::Eur),
+        }];
+
+        assert_eq!(entry_lines(&lines, Currency::Eur), expected);
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_lines_works() {
+        let tests = vec![
+            (
+                Some(1000),
+                vec![
+                    line(Some("Food"), Some(600)),
+                    line(Some("Rent"), Some(400)),
+                ],
+                Ok(()),
+            ),
+            (
+                Some(1000),
+                vec![line(None, Some(600)), line(Some("Rent"), Some(400))],
+                Err(ValidationError::Required),
+            ),
+            (
+                Some(1000),
+                vec![
+                    line(Some("Food"), Some(0)),
+                    line(Some("Rent"), Some(1000)),
+                ],
+                Err(ValidationError::NotPositive),
+            ),
+            (
+                Some(1000),
+                vec![
+                    line(Some("Food"), Some(600)),
+                    line(Some("Rent"), Some(300)),
+                ],
+                Err(ValidationError::SplitMismatch),
+            ),
+        ];
+
+        for (total, lines, expected) in tests {
+            assert_eq!(validate_lines(total, &lines), expected);
+        }
+    }
+}
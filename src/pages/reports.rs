@@ -0,0 +1,669 @@
+use std::rc::Rc;
+
+use chrono::{
+    DateTime,
+    Datelike,
+    FixedOffset,
+    Utc,
+};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{
+    Event,
+    HtmlInputElement,
+    HtmlSelectElement,
+    MouseEvent,
+};
+use yew::{
+    function_component,
+    html,
+    use_effect_with_deps,
+    use_state_eq,
+    AttrValue,
+    Callback,
+    Html,
+    TargetCast,
+};
+
+use crate::{
+    components::{
+        ChartDatum,
+        ChartSeries,
+        DateTimeSelect,
+        PieChart,
+        Select,
+        StackedBarChart,
+    },
+    requests,
+    types::{
+        datetime::{
+            previous_month,
+            DateTimeRange,
+            Month,
+            Period,
+            Year,
+        },
+        entry::Entry,
+        exchange_rate::ExchangeRate,
+        locale::Locale,
+        money::Money,
+        report::{
+            category_entries,
+            category_report,
+            compare_categories,
+            largest_changes,
+            monthly_average,
+            monthly_reports,
+            CategoryChange,
+            CategoryReport,
+        },
+        select::SelectOption,
+        settings::Settings,
+        summary::entries_in,
+    },
+};
+
+const PREVIOUS: &str = "previous";
+const YEAR_BEFORE: &str = "year_before";
+const BASELINES: [(&str, &str); 2] = [
+    (PREVIOUS, "Previous period"),
+    (YEAR_BEFORE, "Same period last year"),
+];
+const COMPARISON_YEARS: Year = 5;
+const LARGEST_CHANGES: usize = 3;
+
+#[function_component(Reports)]
+pub fn reports() -> Html {
+    let settings = use_state_eq(Settings::default);
+    let entries = use_state_eq(Vec::<Entry>::default);
+    let rates = use_state_eq(Vec::<ExchangeRate>::default);
+    {
+        let settings = settings.clone();
+        let entries = entries.clone();
+        let rates = rates.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    if let Ok(response) = requests::get_settings().await {
+                        settings.set(response);
+                    }
+                    if let Ok(list) = requests::get_entries().await {
+                        if let Ok(list) =
+                            requests::get_exchange_rates_for(&list).await
+                        {
+                            rates.set(list);
+                        }
+                        entries.set(list);
+                    }
+                });
+            },
+            (),
+        );
+    }
+
+    let timezone = settings.timezone;
+    let currency = settings.home_currency;
+    let locale = Locale::from_browser();
+    let format =
+        |money: Money| format!("{} {}", money.format(locale), money.currency);
+
+    // PERIOD
+    let now = Utc::now().with_timezone(&timezone).fixed_offset();
+    let start_of_year = now - chrono::Duration::days(now.ordinal0().into());
+    let selectable_range =
+        DateTimeRange::from(now - chrono::Duration::days(10 * 365), now);
+    let start = use_state_eq(|| Some(start_of_year.date_naive()));
+    let end = use_state_eq(|| Some(now.date_naive()));
+    let by_month = use_state_eq(|| false);
+    let category = use_state_eq(|| None::<String>);
+    let is_reversed = matches!((*start, *end), (Some(s), Some(e)) if s > e);
+
+    let ondatetimechange_start = {
+        let start = start.clone();
+        Callback::from(move |datetime: Option<DateTime<FixedOffset>>| {
+            start.set(datetime.map(|d| d.date_naive()))
+        })
+    };
+    let ondatetimechange_end = {
+        let end = end.clone();
+        Callback::from(move |datetime: Option<DateTime<FixedOffset>>| {
+            end.set(datetime.map(|d| d.date_naive()))
+        })
+    };
+    let onchange_by_month = {
+        let by_month = by_month.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            by_month.set(input.checked());
+        })
+    };
+    let onclick_close = {
+        let category = category.clone();
+        Callback::from(move |_: MouseEvent| category.set(None))
+    };
+
+    // REPORT
+    let range = match (*start, *end) {
+        (Some(start), Some(end)) if start <= end => {
+            DateTimeRange::days(&timezone, start, end)
+        },
+        _ => None,
+    };
+    let period_entries = range
+        .as_ref()
+        .map(|range| entries_in(&entries, range))
+        .unwrap_or_default();
+    let months = range
+        .as_ref()
+        .map(|range| range.list_months().len())
+        .unwrap_or_default();
+    let report = range.as_ref().and_then(|_| {
+        category_report(&period_entries, currency, &rates, months)
+    });
+    let monthly = range
+        .as_ref()
+        .filter(|_| *by_month)
+        .and_then(|range| monthly_reports(&entries, range, currency, &rates))
+        .unwrap_or_default();
+    let drill_down = (*category).as_ref().and_then(|category| {
+        category_entries(&period_entries, category, currency, &rates)
+    });
+
+    // COMPARISON
+    let (year, month) = (now.year(), Month::from_u32(now.month()));
+    let period = use_state_eq(|| Period::Month(year, month));
+    let baseline = use_state_eq(|| PREVIOUS);
+    let baseline_period = match *baseline {
+        YEAR_BEFORE => period.year_before(),
+        _ => period.previous(),
+    };
+    let periods = comparison_periods(year, month);
+    let period_options: Vec<SelectOption> = periods
+        .iter()
+        .map(|&p| SelectOption::from(p).selected(p == *period))
+        .collect();
+    let baseline_options: Vec<SelectOption> = BASELINES
+        .iter()
+        .map(|&(value, text)| SelectOption {
+            value: AttrValue::from(value),
+            inner_html: AttrValue::from(text),
+            selected: value == *baseline,
+            ..Default::default()
+        })
+        .collect();
+    let onchange_period = {
+        let period = period.clone();
+        Callback::from(move |e: Event| {
+            let value = e
+                .target_dyn_into::<HtmlSelectElement>()
+                .map(|select| select.value())
+                .unwrap_or_default();
+            if let Some(&selected) = periods
+                .iter()
+                .find(|&&p| SelectOption::from(p).value == value)
+            {
+                period.set(selected);
+            }
+        })
+    };
+    let onchange_baseline = {
+        let baseline = baseline.clone();
+        Callback::from(move |e: Event| {
+            let value = e
+                .target_dyn_into::<HtmlSelectElement>()
+                .map(|select| select.value())
+                .unwrap_or_default();
+            let selected = BASELINES
+                .iter()
+                .find(|(baseline, _)| *baseline == value)
+                .map_or(PREVIOUS, |(baseline, _)| baseline);
+            baseline.set(selected);
+        })
+    };
+    let changes =
+        match (period.range(&timezone), baseline_period.range(&timezone)) {
+            (Some(current), Some(previous)) => compare_categories(
+                &entries_in(&entries, &current),
+                &entries_in(&entries, &previous),
+                currency,
+                &rates,
+            ),
+            _ => None,
+        };
+    let (increases, decreases) = changes
+        .as_deref()
+        .map(|changes| largest_changes(changes, LARGEST_CHANGES))
+        .unwrap_or_default();
+    let is_highlighted = |change: &CategoryChange| {
+        increases.contains(&change) || decreases.contains(&change)
+    };
+    let change_list = |id: &'static str,
+                       label: &'static str,
+                       list: &[&CategoryChange]| {
+        html! {
+            <ol {id} aria-label={label}>
+            {
+                list.iter().map(|change| html! {
+                    <li>{ format!("{}: {}", change.category, change_text(change, locale)) }</li>
+                }).collect::<Html>()
+            }
+            </ol>
+        }
+    };
+
+    // CHARTS
+    let top_level: Vec<&CategoryReport> = report
+        .iter()
+        .flatten()
+        .filter(|row| row.is_top_level())
+        .collect();
+    let share_data: Rc<[ChartDatum]> = top_level
+        .iter()
+        .map(|row| ChartDatum {
+            label: AttrValue::from(row.category.clone()),
+            value: row.total,
+        })
+        .collect();
+    let month_labels: Rc<[AttrValue]> = monthly
+        .iter()
+        .map(|((year, month), _)| {
+            AttrValue::from(format!("{} {}", month, year))
+        })
+        .collect();
+    let month_series: Rc<[ChartSeries]> = top_level
+        .iter()
+        .map(|row| ChartSeries {
+            label: AttrValue::from(row.category.clone()),
+            values: monthly
+                .iter()
+                .map(|(_, rows)| {
+                    rows.iter()
+                        .find(|r| r.category == row.category)
+                        .map(|r| r.total)
+                        .unwrap_or(Money::zero(currency))
+                })
+                .collect(),
+        })
+        .collect();
+
+    let report_table = |id: String, rows: &[CategoryReport]| {
+        html! {
+            <table {id}>
+                <thead>
+                    <tr>
+                        <th>{ "Category" }</th>
+                        <th>{ "Total" }</th>
+                        <th>{ "Per month" }</th>
+                        <th>{ "Share" }</th>
+                    </tr>
+                </thead>
+                <tbody>
+                {
+                    rows.iter().map(|row| {
+                        let is_selected = category.as_deref() == Some(row.category.as_str());
+                        let onclick = {
+                            let category = category.clone();
+                            let value = row.category.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                category.set((!is_selected).then(|| value.clone()))
+                            })
+                        };
+                        html! {
+                            <tr>
+                                <th scope={"row"}>
+                                    <button
+                                        type={"button"}
+                                        aria-pressed={is_selected.to_string()}
+                                        aria-controls={"category_entries"}
+                                        {onclick}
+                                    >
+                                        { row.category.clone() }
+                                    </button>
+                                </th>
+                                <td>{ format(row.total) }</td>
+                                <td>{ format(row.monthly_average) }</td>
+                                <td>{ format!("{}%", row.percent) }</td>
+                            </tr>
+                        }
+                    }).collect::<Html>()
+                }
+                </tbody>
+            </table>
+        }
+    };
+
+    html! {
+        <section id={"reports"}>
+            <h2>{ "Reports" }</h2>
+            <fieldset id={"report_start"}>
+                <legend>{ "From" }</legend>
+                <DateTimeSelect
+                    id={"report_start_select"}
+                    label={"From"}
+                    range={Rc::new(selectable_range.clone())}
+                    preselect={Rc::new(start_of_year)}
+                    ondatetimechange={ondatetimechange_start}
+                />
+            </fieldset>
+            <fieldset id={"report_end"}>
+                <legend>{ "To" }</legend>
+                <DateTimeSelect
+                    id={"report_end_select"}
+                    label={"To"}
+                    range={Rc::new(selectable_range)}
+                    preselect={Rc::new(now)}
+                    ondatetimechange={ondatetimechange_end}
+                    error={is_reversed.then(|| AttrValue::from("Must not be before the start date"))}
+                />
+            </fieldset>
+            <section id={"report_by_month_input_section"}>
+                <input
+                    id={"report_by_month_input"}
+                    name={"by_month"}
+                    type={"checkbox"}
+                    checked={*by_month}
+                    onchange={onchange_by_month}
+                />
+                <label for={"report_by_month_input"}>{ "Group by month" }</label>
+            </section>
+            if let Some(report) = &report {
+                <p id={"report_summary"}>
+                {
+                    Money::checked_sum(top_level.iter().map(|row| row.total), currency)
+                        .map(|total| summary(total, months, locale))
+                        .unwrap_or_default()
+                }
+                </p>
+                { report_table(String::from("category_report"), report) }
+                <PieChart
+                    id={"category_report_chart"}
+                    title={"Spending by category"}
+                    data={share_data}
+                    {locale}
+                />
+                if *by_month {
+                    <StackedBarChart
+                        id={"monthly_report_chart"}
+                        title={"Spending by category and month"}
+                        labels={month_labels}
+                        series={month_series}
+                        {locale}
+                    />
+                    {
+                        monthly.iter().enumerate().map(|(index, ((year, month), rows))| html! {
+                            <section id={format!("month_report_section_{}", index)}>
+                                <h3>{ format!("{} {}", month, year) }</h3>
+                                { report_table(format!("month_report_{}", index), rows) }
+                            </section>
+                        }).collect::<Html>()
+                    }
+                }
+                if let (Some(category), Some(list)) = (&*category, drill_down) {
+                    <section id={"category_entries"} aria-labelledby={"category_entries_heading"}>
+                        <h3 id={"category_entries_heading"}>
+                            { format!("Entries in {}", category) }
+                        </h3>
+                        <button
+                            id={"close_category_entries"}
+                            type={"button"}
+                            onclick={onclick_close}
+                        >
+                            { "Back to all categories" }
+                        </button>
+                        <table id={"category_entries_table"}>
+                            <thead>
+                                <tr>
+                                    <th>{ "Date" }</th>
+                                    <th>{ "Account" }</th>
+                                    <th>{ "Description" }</th>
+                                    <th>{ "Value" }</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                            {
+                                list.into_iter().map(|(entry, amount)| html! {
+                                    <tr>
+                                        <td>{ entry.datetime.with_timezone(&timezone).format("%Y-%m-%d").to_string() }</td>
+                                        <td>{ entry.account.clone() }</td>
+                                        <td>{ entry.description.clone() }</td>
+                                        <td>{ format(amount) }</td>
+                                    </tr>
+                                }).collect::<Html>()
+                            }
+                            </tbody>
+                        </table>
+                    </section>
+                }
+            } else {
+                <p id={"report_summary"}>{ "Report unavailable" }</p>
+            }
+            <section id={"comparison"} aria-labelledby={"comparison_heading"}>
+                <h3 id={"comparison_heading"}>
+                    { format!("{} compared with {}", *period, baseline_period) }
+                </h3>
+                <Select
+                    id={"comparison_period_select"}
+                    label={"Period"}
+                    options={Rc::from(period_options)}
+                    onchange={onchange_period}
+                />
+                <Select
+                    id={"comparison_baseline_select"}
+                    label={"Compare with"}
+                    options={Rc::from(baseline_options)}
+                    onchange={onchange_baseline}
+                />
+                if let Some(changes) = &changes {
+                    { change_list("largest_increases", "Largest increases", &increases) }
+                    { change_list("largest_decreases", "Largest decreases", &decreases) }
+                    <table id={"category_comparison"}>
+                        <thead>
+                            <tr>
+                                <th>{ "Category" }</th>
+                                <th>{ period.to_string() }</th>
+                                <th>{ baseline_period.to_string() }</th>
+                                <th>{ "Change" }</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                        {
+                            changes.iter().map(|change| html! {
+                                <tr>
+                                    <th scope={"row"}>{ change.category.clone() }</th>
+                                    <td>{ format(change.current) }</td>
+                                    <td>{ format(change.previous) }</td>
+                                    <td>
+                                        if is_highlighted(change) {
+                                            <mark>{ change_text(change, locale) }</mark>
+                                        } else {
+                                            { change_text(change, locale) }
+                                        }
+                                    </td>
+                                </tr>
+                            }).collect::<Html>()
+                        }
+                        </tbody>
+                    </table>
+                } else {
+                    <p id={"category_comparison"}>{ "Comparison unavailable" }</p>
+                }
+            </section>
+        </section>
+    }
+}
+
+fn comparison_periods(year: Year, month: Month) -> Vec<Period> {
+    let mut periods = vec![Period::Month(year, month)];
+    while let Some(&Period::Month(y, m)) = periods.last() {
+        match previous_month(y, m) {
+            (y, m) if y >= year - 1 => periods.push(Period::Month(y, m)),
+            _ => break,
+        }
+    }
+    periods.extend(
+        (0..COMPARISON_YEARS).map(|offset| Period::Year(year - offset)),
+    );
+    periods
+}
+
+fn change_text(change: &CategoryChange, locale: Locale) -> String {
+    let Some(difference) = change.difference() else {
+        return String::from("Unavailable");
+    };
+    let sign = if difference.minor > 0 { "+" } else { "" };
+    let amount = format!(
+        "{}{} {}",
+        sign,
+        difference.format(locale),
+        difference.currency
+    );
+    match (difference.minor, change.percent()) {
+        (0, _) => String::from("No change"),
+        (_, Some(percent)) => format!("{} ({}{}%)", amount, sign, percent),
+        (_, None) => format!("{} (new)", amount),
+    }
+}
+
+fn summary(total: Money, months: usize, locale: Locale) -> String {
+    let average = monthly_average(total, months);
+    let months = match months {
+        1 => String::from("1 month"),
+        months => format!("{} months", months),
+    };
+    match average {
+        Some(average) => format!(
+            "Spent {} {} over {}, {} {} a month on average",
+            total.format(locale),
+            total.currency,
+            months,
+            average.format(locale),
+            average.currency
+        ),
+        None => format!(
+            "Spent {} {} over {}",
+            total.format(locale),
+            total.currency,
+            months
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use web_sys::HtmlInputElement;
+
+    use super::{
+        change_text,
+        comparison_periods,
+        summary,
+        Reports,
+    };
+    use crate::{
+        dom::DOM,
+        types::{
+            currency::Currency,
+            datetime::{
+                Month,
+                Period,
+            },
+            locale::Locale,
+            money::{
+                eur,
+                Money,
+            },
+            report::CategoryChange,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    async fn render_reports() {
+        let output = DOM::get_element_by_id("output")
+            .expect("Element `output` to exist");
+        yew::Renderer::<Reports>::with_root(output).render();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    #[wasm_bindgen_test]
+    fn summary_describes_total_and_monthly_average() {
+        let tests = vec![
+            (
+                eur(600000),
+                3,
+                "Spent 6,000.00 EUR over 3 months, 2,000.00 EUR a month on \
+                 average",
+            ),
+            (
+                eur(1000),
+                1,
+                "Spent 10.00 EUR over 1 month, 10.00 EUR a month on average",
+            ),
+            (eur(0), 0, "Spent 0.00 EUR over 0 months"),
+        ];
+
+        for (total, months, expected) in tests {
+            assert_eq!(summary(total, months, Locale::default()), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn comparison_periods_list_recent_months_and_years() {
+        let periods = comparison_periods(2023, Month::March);
+
+        assert_eq!(periods.len(), 15 + 5);
+        assert_eq!(periods[0], Period::Month(2023, Month::March));
+        assert_eq!(periods[3], Period::Month(2022, Month::December));
+        assert_eq!(periods[14], Period::Month(2022, Month::January));
+        assert_eq!(periods[15], Period::Year(2023));
+        assert_eq!(periods[19], Period::Year(2019));
+    }
+
+    #[wasm_bindgen_test]
+    fn change_text_shows_difference_and_percentage() {
+        let change = |current, previous, currency| CategoryChange {
+            category: String::from("Food"),
+            current: Money::new(current, Currency::Eur),
+            previous: Money::new(previous, currency),
+        };
+        let tests = vec![
+            (change(12000, 10000, Currency::Eur), "+20.00 EUR (+20%)"),
+            (change(5000, 10000, Currency::Eur), "-50.00 EUR (-50%)"),
+            (change(500, 0, Currency::Eur), "+5.00 EUR (new)"),
+            (change(500, 500, Currency::Eur), "No change"),
+            (change(500, 500, Currency::Usd), "Unavailable"),
+        ];
+
+        for (change, expected) in tests {
+            assert_eq!(change_text(&change, Locale::default()), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn page_contains_period_selects_and_report() {
+        render_reports().await;
+
+        for id in ["report_start_select", "report_end_select"] {
+            for part in ["year", "month", "day"] {
+                let select_id = format!("{}_{}", id, part);
+                assert!(DOM::get_label_by_for(&select_id).is_some());
+            }
+        }
+        let by_month = DOM::get_input_by_id("report_by_month_input")
+            .expect("Checkbox to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        assert!(!by_month.checked());
+        assert!(DOM::get_element_by_id("category_report").is_some());
+        assert!(DOM::get_element_by_id("category_entries").is_none());
+        for id in ["comparison_period_select", "comparison_baseline_select"] {
+            assert!(DOM::get_label_by_for(id).is_some());
+        }
+        assert!(DOM::get_element_by_id("category_comparison").is_some());
+    }
+}
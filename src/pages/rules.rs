@@ -0,0 +1,502 @@
+use std::rc::Rc;
+
+use chrono::Weekday;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{
+    Event,
+    HtmlInputElement,
+    HtmlSelectElement,
+    InputEvent,
+    MouseEvent,
+};
+use yew::{
+    function_component,
+    html,
+    use_effect_with_deps,
+    use_memo,
+    use_state_eq,
+    AttrValue,
+    Callback,
+    Html,
+    TargetCast,
+    UseStateHandle,
+};
+
+use crate::{
+    components::{
+        reset_form,
+        Combobox,
+        Form,
+        FormValues,
+        Input,
+        MonetaryInput,
+        Select,
+    },
+    requests,
+    types::{
+        categorization::{
+            weekday_name,
+            CategoryRule,
+            Condition,
+            WEEKDAYS,
+        },
+        category,
+        currency::Currency,
+        locale::Locale,
+        money::Money,
+        select::SelectOption,
+        validation::ValidationError,
+    },
+};
+
+const FORM_ID: &str = "rule_form";
+
+// Rules to save and the form to reset once they are saved
+type SaveRequest = (Vec<CategoryRule>, Option<&'static str>);
+
+#[function_component(Rules)]
+pub fn rules() -> Html {
+    let rules = use_state_eq(Vec::<CategoryRule>::default);
+    let categories = use_state_eq(Vec::<SelectOption>::default);
+    let currency = use_state_eq(Currency::default);
+    {
+        let rules = rules.clone();
+        let categories = categories.clone();
+        let currency = currency.clone();
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    if let Ok(tree) = requests::get_categories().await {
+                        categories.set(category::select_options(&tree));
+                    }
+                    if let Ok(settings) = requests::get_settings().await {
+                        currency.set(settings.default_currency);
+                    }
+                    if let Ok(list) = requests::get_rules().await {
+                        rules.set(list);
+                    }
+                });
+            },
+            (),
+        );
+    }
+    let save_error = use_state_eq(|| None::<AttrValue>);
+    let save_rules = {
+        let rules = rules.clone();
+        let save_error = save_error.clone();
+        Callback::from(move |(list, form_id): SaveRequest| {
+            let rules = rules.clone();
+            let save_error = save_error.clone();
+            spawn_local(async move {
+                match requests::put_rules(&list).await {
+                    Ok(()) => {
+                        rules.set(list);
+                        save_error.set(None);
+                        if let Some(form_id) = form_id {
+                            reset_form(form_id);
+                        }
+                    },
+                    Err(_) => save_error.set(Some(AttrValue::from(
+                        "Rules could not be saved, try again",
+                    ))),
+                }
+            });
+        })
+    };
+
+    let rule_category = use_state_eq(|| None::<AttrValue>);
+    let contains = use_state_eq(String::default);
+    let pattern = use_state_eq(String::default);
+    let min = use_state_eq(|| None::<i64>);
+    let max = use_state_eq(|| None::<i64>);
+    let weekday = use_state_eq(|| None::<Weekday>);
+    let locale = Locale::from_browser();
+
+    // Patterns compile when the conditions are built, so only rebuild them
+    // when an input changes
+    let conditions = use_memo(
+        |(contains, pattern, min, max, weekday)| {
+            build_conditions(contains, pattern, *min, *max, *weekday)
+        },
+        (
+            (*contains).clone(),
+            (*pattern).clone(),
+            min.map(|minor| Money::new(minor, *currency)),
+            max.map(|minor| Money::new(minor, *currency)),
+            *weekday,
+        ),
+    );
+    let pattern_error = conditions
+        .iter()
+        .any(|c| matches!(c, Condition::DescriptionMatches(_)) && !c.is_valid())
+        .then(|| AttrValue::from(ValidationError::InvalidPattern.to_string()));
+    let amount_error = conditions
+        .iter()
+        .any(|c| matches!(c, Condition::AmountBetween(..)) && !c.is_valid())
+        .then(|| AttrValue::from(ValidationError::InvalidRange.to_string()));
+    let can_add = rule_category.is_some()
+        && !conditions.is_empty()
+        && conditions.iter().all(Condition::is_valid);
+
+    let category_options: Vec<SelectOption> = categories
+        .iter()
+        .map(|o| o.clone().selected(Some(&o.value) == rule_category.as_ref()))
+        .collect();
+    let any_day = SelectOption {
+        value: AttrValue::from(""),
+        inner_html: AttrValue::from("Any day"),
+        selected: weekday.is_none(),
+        ..Default::default()
+    };
+    let weekday_options: Vec<SelectOption> = std::iter::once(any_day)
+        .chain(WEEKDAYS.iter().map(|&day| SelectOption {
+            value: AttrValue::from(day.number_from_monday().to_string()),
+            inner_html: AttrValue::from(weekday_name(day)),
+            selected: Some(day) == *weekday,
+            ..Default::default()
+        }))
+        .collect();
+
+    // ONCHANGE
+    let onselect_category = {
+        let rule_category = rule_category.clone();
+        Callback::from(move |value| rule_category.set(Some(value)))
+    };
+    let oninput_text = |state: UseStateHandle<String>| {
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                state.set(input.value());
+            }
+        })
+    };
+    let onchange_min = {
+        let min = min.clone();
+        Callback::from(move |value| min.set(value))
+    };
+    let onchange_max = {
+        let max = max.clone();
+        Callback::from(move |value| max.set(value))
+    };
+    let onchange_weekday = {
+        let weekday = weekday.clone();
+        Callback::from(move |e: Event| {
+            let number = e
+                .target_dyn_into::<HtmlSelectElement>()
+                .and_then(|select| select.value().parse::<usize>().ok());
+            weekday.set(
+                number
+                    .and_then(|n| n.checked_sub(1))
+                    .and_then(|i| WEEKDAYS.get(i).copied()),
+            );
+        })
+    };
+
+    // SUBMIT
+    let onsubmit = {
+        let rules = rules.clone();
+        let save_rules = save_rules.clone();
+        let rule = rule_category.as_ref().map(|category| CategoryRule {
+            category: category.to_string(),
+            conditions: conditions.to_vec(),
+        });
+        Callback::from(move |_: FormValues| {
+            if let (Some(rule), true) = (rule.clone(), can_add) {
+                let mut list = (*rules).clone();
+                list.push(rule);
+                save_rules.emit((list, Some(FORM_ID)));
+            }
+        })
+    };
+    let onreset = {
+        let rule_category = rule_category.clone();
+        let contains = contains.clone();
+        let pattern = pattern.clone();
+        let min = min.clone();
+        let max = max.clone();
+        let weekday = weekday.clone();
+        Callback::from(move |_| {
+            rule_category.set(None);
+            contains.set(String::default());
+            pattern.set(String::default());
+            min.set(None);
+            max.set(None);
+            weekday.set(None);
+        })
+    };
+
+    html! {
+        <section id={"rules"}>
+            <ol id={"rules_list"} aria-label={"Rules, first match wins"}>
+            {
+                rules.iter().enumerate().map(|(index, rule)| {
+                    let onclick_up = {
+                        let rules = rules.clone();
+                        let save_rules = save_rules.clone();
+                        Callback::from(move |_: MouseEvent| {
+                            if index > 0 {
+                                let mut list = (*rules).clone();
+                                list.swap(index - 1, index);
+                                save_rules.emit((list, None));
+                            }
+                        })
+                    };
+                    let onclick_delete = {
+                        let rules = rules.clone();
+                        let save_rules = save_rules.clone();
+                        Callback::from(move |_: MouseEvent| {
+                            let mut list = (*rules).clone();
+                            list.remove(index);
+                            save_rules.emit((list, None));
+                        })
+                    };
+                    html! {
+                        <li id={format!("rule_{}", index)}>
+                            { rule.describe(locale) }
+                            <button
+                                id={format!("rule_up_{}", index)}
+                                type={"button"}
+                                disabled={index == 0}
+                                aria-label={format!("Move rule for {} up", rule.category)}
+                                onclick={onclick_up}
+                            >
+                                { "↑" }
+                            </button>
+                            <button
+                                id={format!("rule_delete_{}", index)}
+                                type={"button"}
+                                aria-label={format!("Delete rule for {}", rule.category)}
+                                onclick={onclick_delete}
+                            >
+                                { "×" }
+                            </button>
+                        </li>
+                    }
+                }).collect::<Html>()
+            }
+            </ol>
+            if let Some(error) = (*save_error).clone() {
+                <p id={"rules_error"} class={"error"} role={"alert"}>{ error }</p>
+            }
+            <Form id={FORM_ID} {onsubmit} {onreset}>
+                <Combobox
+                    id={"rule_category_select"}
+                    label={"Category"}
+                    options={Rc::from(category_options)}
+                    onselect={onselect_category}
+                />
+                <Input
+                    id={"rule_contains_input"}
+                    label={"Description contains"}
+                    value={AttrValue::from((*contains).clone())}
+                    oninput={oninput_text(contains.clone())}
+                />
+                <Input
+                    id={"rule_pattern_input"}
+                    label={"Description matches (regular expression)"}
+                    value={AttrValue::from((*pattern).clone())}
+                    oninput={oninput_text(pattern.clone())}
+                    error={pattern_error}
+                />
+                <MonetaryInput
+                    id={"rule_min_input"}
+                    label={"Minimum amount"}
+                    currency={*currency}
+                    {locale}
+                    value={*min}
+                    onvaluechange={onchange_min}
+                />
+                <MonetaryInput
+                    id={"rule_max_input"}
+                    label={"Maximum amount"}
+                    currency={*currency}
+                    {locale}
+                    value={*max}
+                    onvaluechange={onchange_max}
+                    error={amount_error}
+                />
+                <Select
+                    id={"rule_weekday_select"}
+                    label={"Weekday"}
+                    options={Rc::from(weekday_options)}
+                    onchange={onchange_weekday}
+                />
+                <button
+                    id={"add_rule_button"}
+                    type={"submit"}
+                    disabled={!can_add}
+                >
+                    { "Add rule" }
+                </button>
+            </Form>
+        </section>
+    }
+}
+
+fn build_conditions(
+    contains: &str,
+    pattern: &str,
+    min: Option<Money>,
+    max: Option<Money>,
+    weekday: Option<Weekday>,
+) -> Vec<Condition> {
+    let mut conditions = Vec::new();
+    if !contains.trim().is_empty() {
+        conditions.push(Condition::DescriptionContains(contains.to_owned()));
+    }
+    if !pattern.is_empty() {
+        conditions.push(Condition::DescriptionMatches(pattern.into()));
+    }
+    if min.is_some() || max.is_some() {
+        conditions.push(Condition::AmountBetween(min, max));
+    }
+    if let Some(weekday) = weekday {
+        conditions.push(Condition::Weekday(weekday));
+    }
+    conditions
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use chrono::Weekday;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use web_sys::{
+        Event,
+        HtmlInputElement,
+    };
+
+    use super::{
+        build_conditions,
+        Rules,
+    };
+    use crate::{
+        dom::DOM,
+        types::{
+            categorization::Condition,
+            money::eur,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    async fn render_rules() {
+        let output = DOM::get_element_by_id("output")
+            .expect("Element `output` to exist");
+        yew::Renderer::<Rules>::with_root(output).render();
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    async fn dispatch_input_event(target: &HtmlInputElement) {
+        let event = Event::new("input").expect("valid event");
+        target
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    static PATTERN_INPUT_ID: &str = "rule_pattern_input";
+    static ADD_RULE_BUTTON_ID: &str = "add_rule_button";
+
+    #[wasm_bindgen_test]
+    fn build_conditions_skips_empty_fields() {
+        let tests = vec![
+            (("", "", None, None, None), vec![]),
+            (
+                ("  Lidl ", "", None, None, None),
+                vec![Condition::DescriptionContains("  Lidl ".into())],
+            ),
+            (
+                ("", "^HSL", None, Some(eur(100)), Some(Weekday::Mon)),
+                vec![
+                    Condition::DescriptionMatches("^HSL".into()),
+                    Condition::AmountBetween(None, Some(eur(100))),
+                    Condition::Weekday(Weekday::Mon),
+                ],
+            ),
+        ];
+
+        for ((contains, pattern, min, max, weekday), expected) in tests {
+            assert_eq!(
+                build_conditions(contains, pattern, min, max, weekday),
+                expected
+            );
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn page_contains_rule_fields() {
+        render_rules().await;
+
+        let tests = vec![
+            ("rule_category_select", "Category"),
+            ("rule_contains_input", "Description contains"),
+            (PATTERN_INPUT_ID, "Description matches (regular expression)"),
+            ("rule_min_input", "Minimum amount"),
+            ("rule_max_input", "Maximum amount"),
+        ];
+
+        for (id, label) in tests {
+            let element =
+                DOM::get_input_by_id(id).expect("Input Element to exist");
+            let label_element =
+                DOM::get_label_by_for(id).expect("Label Element to exist");
+
+            assert!(DOM::is_element_visible(&element));
+            assert_eq!(&label_element.inner_html(), label);
+        }
+        assert!(DOM::get_select_by_id("rule_weekday_select").is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn add_rule_button_is_disabled_without_category() {
+        render_rules().await;
+
+        let button = DOM::get_button_by_id(ADD_RULE_BUTTON_ID)
+            .expect("Button Element to exist");
+
+        assert!(button.has_attribute("disabled"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn pattern_input_shows_error_for_invalid_pattern() {
+        render_rules().await;
+
+        let input = DOM::get_input_by_id(PATTERN_INPUT_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        input.set_value("[a-");
+        dispatch_input_event(&input).await;
+
+        let error =
+            DOM::get_element_by_id(&format!("{}_error", PATTERN_INPUT_ID))
+                .expect("Error Element to exist");
+
+        assert_eq!(&error.inner_html(), "Must be a valid regular expression");
+    }
+
+    #[wasm_bindgen_test]
+    async fn pattern_error_is_cleared_when_pattern_is_fixed() {
+        render_rules().await;
+
+        let input = DOM::get_input_by_id(PATTERN_INPUT_ID)
+            .expect("Input Element to exist")
+            .dyn_into::<HtmlInputElement>()
+            .expect("Element to be Input");
+        let tests = vec![("[a-", true), ("[a-z]", false), ("(", true)];
+
+        for (pattern, has_error) in tests {
+            input.set_value(pattern);
+            dispatch_input_event(&input).await;
+
+            let error =
+                DOM::get_element_by_id(&format!("{}_error", PATTERN_INPUT_ID));
+            assert_eq!(error.is_some(), has_error, "{}", pattern);
+        }
+    }
+}
@@ -1,5 +1,112 @@
-pub(crate) async fn get_categories() -> Result<Vec<String>, String> {
-    Ok(vec![String::from("Placeholder")])
+use chrono::{
+    NaiveDate,
+    Utc,
+};
+
+use crate::types::{
+    account::Account,
+    budget::Budget,
+    categorization::{
+        categorize_entries,
+        CategoryRule,
+    },
+    category::Category,
+    entry::Entry,
+    exchange_rate::ExchangeRate,
+    goal::Goal,
+    settings::Settings,
+};
+
+pub(crate) async fn get_categories() -> Result<Vec<Category>, String> {
+    Ok(vec![Category::from("Placeholder")])
+}
+
+pub(crate) async fn post_category(_name: &str) -> Result<(), String> {
+    Ok(())
+}
+
+pub(crate) async fn get_accounts() -> Result<Vec<Account>, String> {
+    Ok(vec![Account::from("Checking"), Account::from("Cash")])
+}
+
+pub(crate) async fn get_tags() -> Result<Vec<String>, String> {
+    Ok(Vec::new())
+}
+
+pub(crate) async fn get_entries() -> Result<Vec<Entry>, String> {
+    Ok(Vec::new())
+}
+
+pub(crate) async fn post_entry(_entry: &Entry) -> Result<(), String> {
+    Ok(())
+}
+
+pub(crate) async fn import_entries(
+    mut entries: Vec<Entry>,
+) -> Result<(), String> {
+    let rules = get_rules().await?;
+    categorize_entries(&mut entries, &rules);
+    for entry in &entries {
+        post_entry(entry).await?;
+    }
+    Ok(())
+}
+
+pub(crate) async fn get_rules() -> Result<Vec<CategoryRule>, String> {
+    Ok(Vec::new())
+}
+
+pub(crate) async fn put_rules(_rules: &[CategoryRule]) -> Result<(), String> {
+    Ok(())
+}
+
+pub(crate) async fn get_budgets() -> Result<Vec<Budget>, String> {
+    Ok(Vec::new())
+}
+
+pub(crate) async fn put_budgets(_budgets: &[Budget]) -> Result<(), String> {
+    Ok(())
+}
+
+pub(crate) async fn get_goals() -> Result<Vec<Goal>, String> {
+    Ok(Vec::new())
+}
+
+pub(crate) async fn put_goals(_goals: &[Goal]) -> Result<(), String> {
+    Ok(())
+}
+
+pub(crate) async fn get_settings() -> Result<Settings, String> {
+    Ok(Settings::default())
+}
+
+pub(crate) async fn get_exchange_rates(
+    _date: NaiveDate,
+) -> Result<Vec<ExchangeRate>, String> {
+    Ok(Vec::new())
+}
+
+pub(crate) async fn get_exchange_rates_between(
+    _start: NaiveDate,
+    _end: NaiveDate,
+) -> Result<Vec<ExchangeRate>, String> {
+    Ok(Vec::new())
+}
+
+pub(crate) async fn get_exchange_rates_for(
+    entries: &[Entry],
+) -> Result<Vec<ExchangeRate>, String> {
+    let (start, end) = rate_range(entries, Utc::now().date_naive());
+    get_exchange_rates_between(start, end).await
+}
+
+fn rate_range(entries: &[Entry], today: NaiveDate) -> (NaiveDate, NaiveDate) {
+    entries
+        .iter()
+        .map(|entry| entry.datetime.date_naive())
+        .fold((today, today), |(start, end), date| {
+            (start.min(date), end.max(date))
+        })
 }
 
 pub(crate) struct Backend {
@@ -25,3 +132,53 @@ pub(crate) async fn post_login(
 ) -> RequestResult<()> {
     RequestResult::Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use chrono::{
+        FixedOffset,
+        NaiveDate,
+        TimeZone,
+    };
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::rate_range;
+    use crate::types::{
+        currency::Currency,
+        entry::Entry,
+        money::Money,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn make_entry(month: u32, day: u32) -> Entry {
+        let datetime = FixedOffset::east_opt(0)
+            .unwrap()
+            .with_ymd_and_hms(2023, month, day, 12, 0, 0)
+            .unwrap();
+        Entry::fixture(datetime, Money::new(1000, Currency::Eur))
+    }
+
+    #[wasm_bindgen_test]
+    fn rate_range_spans_entry_dates_and_today() {
+        let date =
+            |month, day| NaiveDate::from_ymd_opt(2023, month, day).unwrap();
+        let today = date(6, 15);
+        let tests = vec![
+            (vec![], (today, today)),
+            (vec![make_entry(6, 1)], (date(6, 1), today)),
+            (
+                vec![make_entry(3, 20), make_entry(1, 5), make_entry(5, 9)],
+                (date(1, 5), today),
+            ),
+            (vec![make_entry(7, 2)], (today, date(7, 2))),
+        ];
+
+        for (entries, expected) in tests {
+            assert_eq!(rate_range(&entries, today), expected);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use yew_router::Routable;
+
+#[derive(Clone, PartialEq, Routable)]
+pub enum Route {
+    #[at("/")]
+    Login,
+    #[at("/dashboard")]
+    Dashboard,
+    #[at("/entries")]
+    Entries,
+    #[at("/entries/new")]
+    NewEntry,
+    #[at("/rules")]
+    Rules,
+    #[at("/budgets")]
+    Budgets,
+    #[at("/goals")]
+    Goals,
+    #[at("/reports")]
+    Reports,
+    #[not_found]
+    #[at("/404")]
+    NotFound,
+}
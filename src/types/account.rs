@@ -0,0 +1,173 @@
+use crate::types::{
+    currency::Currency,
+    entry::{
+        Entry,
+        EntryKind,
+    },
+    exchange_rate::ExchangeRate,
+    money::Money,
+    select::SelectOption,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Account {
+    pub(crate) name: String,
+    pub(crate) opening_balance: Money,
+}
+
+impl Account {
+    pub(crate) fn currency(&self) -> Currency {
+        self.opening_balance.currency
+    }
+}
+
+impl From<&str> for Account {
+    fn from(value: &str) -> Self {
+        Self {
+            name: value.to_owned(),
+            opening_balance: Money::zero(Currency::default()),
+        }
+    }
+}
+
+impl From<&Account> for SelectOption {
+    fn from(value: &Account) -> Self {
+        SelectOption::from(&value.name)
+    }
+}
+
+pub(crate) fn balance(
+    account: &Account,
+    entries: &[Entry],
+    rates: &[ExchangeRate],
+) -> Option<Money> {
+    let currency = account.currency();
+    entries
+        .iter()
+        .try_fold(account.opening_balance, |balance, entry| {
+            let amount = entry.amount_in(currency, rates);
+            let balance = match &entry.kind {
+                EntryKind::Income if entry.account == account.name => {
+                    balance.checked_add(amount?)?
+                },
+                _ if entry.account == account.name => {
+                    balance.checked_sub(amount?)?
+                },
+                // A goal keeps its savings like an account named after it
+                EntryKind::Transfer { to_account }
+                | EntryKind::Contribution { goal: to_account }
+                    if *to_account == account.name =>
+                {
+                    balance.checked_add(amount?)?
+                },
+                _ => balance,
+            };
+            Some(balance)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{
+        FixedOffset,
+        NaiveDate,
+        TimeZone,
+    };
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        balance,
+        Account,
+    };
+    use crate::types::{
+        currency::Currency,
+        entry::{
+            Entry,
+            EntryKind,
+        },
+        exchange_rate::{
+            ExchangeRate,
+            RateSource,
+        },
+        money::{
+            eur,
+            Money,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn make_entry(account: &str, kind: EntryKind, amount: Money) -> Entry {
+        Entry::fixture(
+            FixedOffset::east_opt(0)
+                .unwrap()
+                .with_ymd_and_hms(2023, 6, 1, 12, 0, 0)
+                .unwrap(),
+            amount,
+        )
+        .with_account(account)
+        .with_kind(kind)
+    }
+
+    fn make_account(name: &str, opening_balance: Money) -> Account {
+        Account {
+            name: String::from(name),
+            opening_balance,
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn balance_adds_income_subtracts_expenses_and_moves_transfers() {
+        let to_savings = EntryKind::Transfer {
+            to_account: String::from("Savings"),
+        };
+        let to_goal = EntryKind::Contribution {
+            goal: String::from("Bicycle"),
+        };
+        let entries = vec![
+            make_entry("Checking", EntryKind::Expense, eur(2500)),
+            make_entry("Checking", to_savings.clone(), eur(10000)),
+            make_entry("Checking", to_goal, eur(5000)),
+            make_entry("Cash", EntryKind::Expense, eur(500)),
+            make_entry("Savings", EntryKind::Expense, eur(1000)),
+            make_entry("Cash", EntryKind::Income, eur(300)),
+        ];
+        let tests = vec![
+            (make_account("Checking", eur(50000)), Some(eur(32500))),
+            (make_account("Savings", eur(0)), Some(eur(9000))),
+            (make_account("Cash", eur(2000)), Some(eur(1800))),
+            (make_account("Wallet", eur(100)), Some(eur(100))),
+            (make_account("Bicycle", eur(0)), Some(eur(5000))),
+        ];
+
+        for (account, expected) in tests {
+            assert_eq!(balance(&account, &entries, &[]), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn balance_is_in_account_currency() {
+        let rates = vec![ExchangeRate {
+            date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            from: Currency::Usd,
+            to: Currency::Eur,
+            rate: 900_000,
+            source: RateSource::Backend,
+        }];
+        let entries = vec![make_entry(
+            "Checking",
+            EntryKind::Expense,
+            Money::new(1000, Currency::Usd),
+        )];
+        let account = make_account("Checking", Money::zero(Currency::Eur));
+
+        assert_eq!(
+            balance(&account, &entries, &rates),
+            Some(Money::new(-900, Currency::Eur))
+        );
+        assert_eq!(balance(&account, &entries, &[]), None);
+    }
+}
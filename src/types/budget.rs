@@ -0,0 +1,324 @@
+use chrono::{
+    Datelike,
+    NaiveDate,
+    TimeZone,
+};
+
+use crate::types::{
+    category::is_in_category,
+    currency::Currency,
+    datetime::{
+        next_month,
+        DateTimeRange,
+        Month,
+        Year,
+    },
+    entry::Entry,
+    exchange_rate::ExchangeRate,
+    money::Money,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Budget {
+    pub(crate) category: String,
+    pub(crate) amount: Money,
+    pub(crate) rollover: bool,
+    pub(crate) since: NaiveDate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct BudgetStatus {
+    pub(crate) budgeted: Money,
+    pub(crate) carried: Money,
+    pub(crate) spent: Money,
+}
+
+impl BudgetStatus {
+    pub(crate) fn remaining(&self) -> Option<Money> {
+        self.budgeted.checked_sub(self.spent)
+    }
+
+    pub(crate) fn is_over(&self) -> bool {
+        self.spent.minor > self.budgeted.minor
+    }
+}
+
+pub(crate) fn spent_in_category<T: TimeZone>(
+    category: &str,
+    entries: &[Entry],
+    range: &DateTimeRange<T>,
+    currency: Currency,
+    rates: &[ExchangeRate],
+) -> Option<Money> {
+    let mut spent = Money::zero(currency);
+    let entries = entries
+        .iter()
+        .filter(|entry| entry.is_spending() && range.contains(&entry.datetime));
+    for entry in entries {
+        for (path, amount) in entry.parts() {
+            if is_in_category(path, category) {
+                spent = spent
+                    .checked_add(entry.convert(amount, currency, rates)?)?;
+            }
+        }
+    }
+    Some(spent)
+}
+
+pub(crate) fn budget_status<T: TimeZone>(
+    budget: &Budget,
+    entries: &[Entry],
+    rates: &[ExchangeRate],
+    timezone: &T,
+    year: Year,
+    month: Month,
+) -> Option<BudgetStatus> {
+    let currency = budget.amount.currency;
+    let spent_in = |year, month| {
+        let range = DateTimeRange::month(timezone, year, month)?;
+        spent_in_category(&budget.category, entries, &range, currency, rates)
+    };
+
+    let mut carried = Money::zero(currency);
+    if budget.rollover {
+        let mut current =
+            (budget.since.year(), Month::from_u32(budget.since.month()));
+        while (current.0, current.1 as u32) < (year, month as u32) {
+            let available = budget.amount.checked_add(carried)?;
+            let left =
+                available.checked_sub(spent_in(current.0, current.1)?)?;
+            carried = Money::new(left.minor.max(0), currency);
+            current = next_month(current.0, current.1);
+        }
+    }
+
+    Some(BudgetStatus {
+        budgeted: budget.amount.checked_add(carried)?,
+        carried,
+        spent: spent_in(year, month)?,
+    })
+}
+
+pub(crate) fn overspending<'a, T: TimeZone>(
+    budgets: &'a [Budget],
+    entries: &[Entry],
+    rates: &[ExchangeRate],
+    timezone: &T,
+    entry: &Entry,
+) -> Vec<(&'a Budget, Money)> {
+    let date = entry.datetime.with_timezone(timezone);
+    let (year, month) = (date.year(), Month::from_u32(date.month()));
+    let entries: Vec<Entry> =
+        entries.iter().cloned().chain([entry.clone()]).collect();
+
+    budgets
+        .iter()
+        .filter(|budget| {
+            entry.is_spending()
+                && entry
+                    .parts()
+                    .iter()
+                    .any(|(path, _)| is_in_category(path, &budget.category))
+        })
+        .filter_map(|budget| {
+            let status =
+                budget_status(budget, &entries, rates, timezone, year, month)?;
+            let over = status.spent.checked_sub(status.budgeted)?;
+            status.is_over().then_some((budget, over))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{
+        NaiveDate,
+        TimeZone,
+        Utc,
+    };
+    use chrono_tz::Tz;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        budget_status,
+        overspending,
+        spent_in_category,
+        Budget,
+        BudgetStatus,
+    };
+    use crate::types::{
+        currency::Currency,
+        datetime::{
+            DateTimeRange,
+            Month,
+        },
+        entry::{
+            Entry,
+            EntryKind,
+        },
+        money::{
+            eur,
+            Money,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn make_entry(
+        (month, day, hour): (u32, u32, u32),
+        category: &str,
+        minor: i64,
+    ) -> Entry {
+        Entry::fixture(
+            Utc.with_ymd_and_hms(2023, month, day, hour, 0, 0)
+                .unwrap()
+                .fixed_offset(),
+            eur(minor),
+        )
+        .with_category(category)
+    }
+
+    fn make_budget(category: &str, minor: i64, rollover: bool) -> Budget {
+        Budget {
+            category: String::from(category),
+            amount: eur(minor),
+            rollover,
+            since: NaiveDate::from_ymd_opt(2023, 4, 1).unwrap(),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn spent_in_category_counts_subcategories_within_range() {
+        let timezone = Tz::Europe__Helsinki;
+        let range = DateTimeRange::month(&timezone, 2023, Month::May).unwrap();
+        let mut transfer = make_entry((5, 10, 12), "", 9000);
+        transfer.kind = EntryKind::Transfer {
+            to_account: String::from("Savings"),
+        };
+        let entries = vec![
+            make_entry((5, 2, 12), "Food › Groceries", 1000),
+            make_entry((5, 3, 12), "Food", 500),
+            make_entry((5, 4, 12), "Rent", 70000),
+            // 2023-05-01 00:30 in Helsinki
+            make_entry((4, 30, 21), "Food", 200),
+            // 2023-06-01 00:30 in Helsinki
+            make_entry((5, 31, 21), "Food", 400),
+            transfer,
+        ];
+        let tests = vec![
+            ("Food", Some(eur(1700))),
+            ("Food › Groceries", Some(eur(1000))),
+            ("Rent", Some(eur(70000))),
+            ("Pets", Some(eur(0))),
+        ];
+
+        for (category, expected) in tests {
+            assert_eq!(
+                spent_in_category(
+                    category,
+                    &entries,
+                    &range,
+                    Currency::Eur,
+                    &[]
+                ),
+                expected,
+                "{}",
+                category
+            );
+        }
+        assert_eq!(
+            spent_in_category("Food", &entries, &range, Currency::Usd, &[]),
+            None
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn budget_status_carries_unspent_amounts_when_rolling_over() {
+        let timezone = Tz::Europe__Helsinki;
+        let entries = vec![
+            make_entry((3, 15, 12), "Food", 9000),
+            make_entry((4, 15, 12), "Food", 7000),
+            make_entry((5, 15, 12), "Food", 12000),
+            make_entry((6, 15, 12), "Food", 5000),
+        ];
+        let tests = vec![
+            // April leaves 30.00 and May uses 20.00 of it
+            (true, Month::June, eur(10000), eur(1000), eur(5000)),
+            (true, Month::May, eur(10000), eur(3000), eur(12000)),
+            (true, Month::April, eur(10000), eur(0), eur(7000)),
+            (false, Month::June, eur(10000), eur(0), eur(5000)),
+        ];
+
+        for (rollover, month, amount, carried, spent) in tests {
+            let budget = make_budget("Food", 10000, rollover);
+            let status =
+                budget_status(&budget, &entries, &[], &timezone, 2023, month);
+            assert_eq!(
+                status,
+                Some(BudgetStatus {
+                    budgeted: amount.checked_add(carried).unwrap(),
+                    carried,
+                    spent,
+                }),
+                "{} {}",
+                rollover,
+                month
+            );
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn budget_status_reports_remaining_and_overspending() {
+        let tests = vec![
+            (eur(5000), eur(3000), Some(eur(2000)), false),
+            (eur(5000), eur(5000), Some(eur(0)), false),
+            (eur(5000), eur(6500), Some(eur(-1500)), true),
+        ];
+
+        for (budgeted, spent, remaining, is_over) in tests {
+            let status = BudgetStatus {
+                budgeted,
+                carried: eur(0),
+                spent,
+            };
+            assert_eq!(status.remaining(), remaining);
+            assert_eq!(status.is_over(), is_over);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn overspending_lists_budgets_the_entry_would_exceed() {
+        let timezone = Tz::Europe__Helsinki;
+        let budgets = vec![
+            make_budget("Food", 10000, false),
+            make_budget("Food › Groceries", 5000, false),
+            make_budget("Rent", 100, false),
+        ];
+        let entries = vec![make_entry((6, 1, 12), "Food › Groceries", 4000)];
+        let tests = vec![
+            (make_entry((6, 2, 12), "Food › Groceries", 500), vec![]),
+            (
+                make_entry((6, 2, 12), "Food › Groceries", 2000),
+                vec![("Food › Groceries", eur(1000))],
+            ),
+            (
+                make_entry((6, 2, 12), "Food › Groceries", 7000),
+                vec![("Food", eur(1000)), ("Food › Groceries", eur(6000))],
+            ),
+            (make_entry((7, 2, 12), "Food › Groceries", 4500), vec![]),
+            (make_entry((6, 2, 12), "Leisure", 9000), vec![]),
+        ];
+
+        for (entry, expected) in tests {
+            let over: Vec<(&str, Money)> =
+                overspending(&budgets, &entries, &[], &timezone, &entry)
+                    .into_iter()
+                    .map(|(budget, over)| (budget.category.as_str(), over))
+                    .collect();
+            assert_eq!(over, expected, "{}", entry.amount);
+        }
+    }
+}
@@ -0,0 +1,442 @@
+use chrono::{
+    DateTime,
+    Datelike,
+    FixedOffset,
+    Weekday,
+};
+use regex::{
+    Regex,
+    RegexBuilder,
+};
+
+use crate::types::{
+    entry::Entry,
+    locale::Locale,
+    money::Money,
+};
+
+pub(crate) const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+pub(crate) fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Pattern {
+    source: String,
+    regex: Option<Regex>,
+}
+
+impl Pattern {
+    pub(crate) fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    pub(crate) fn is_valid(&self) -> bool {
+        !self.source.is_empty() && self.regex.is_some()
+    }
+
+    fn is_match(&self, text: &str) -> bool {
+        self.regex
+            .as_ref()
+            .is_some_and(|regex| regex.is_match(text))
+    }
+}
+
+impl From<&str> for Pattern {
+    fn from(value: &str) -> Self {
+        Self {
+            source: value.to_owned(),
+            regex: RegexBuilder::new(value).case_insensitive(true).build().ok(),
+        }
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Condition {
+    DescriptionContains(String),
+    DescriptionMatches(Pattern),
+    AmountBetween(Option<Money>, Option<Money>),
+    Weekday(Weekday),
+}
+
+impl Condition {
+    pub(crate) fn is_valid(&self) -> bool {
+        match self {
+            Self::DescriptionContains(text) => !text.trim().is_empty(),
+            Self::DescriptionMatches(pattern) => pattern.is_valid(),
+            Self::AmountBetween(None, None) => false,
+            Self::AmountBetween(Some(min), Some(max)) => {
+                min.currency == max.currency && min.minor <= max.minor
+            },
+            Self::AmountBetween(..) | Self::Weekday(_) => true,
+        }
+    }
+
+    pub(crate) fn describe(&self, locale: Locale) -> String {
+        let amount = |money: &Money| money.format(locale);
+        match self {
+            Self::DescriptionContains(text) => {
+                format!("description contains “{}”", text.trim())
+            },
+            Self::DescriptionMatches(pattern) => {
+                format!("description matches /{}/", pattern.as_str())
+            },
+            Self::AmountBetween(Some(min), Some(max)) => format!(
+                "amount is between {} and {} {}",
+                amount(min),
+                amount(max),
+                max.currency
+            ),
+            Self::AmountBetween(Some(min), None) => {
+                format!("amount is at least {} {}", amount(min), min.currency)
+            },
+            Self::AmountBetween(None, Some(max)) => {
+                format!("amount is at most {} {}", amount(max), max.currency)
+            },
+            Self::AmountBetween(None, None) => String::from("any amount"),
+            Self::Weekday(weekday) => {
+                format!("date is on a {}", weekday_name(*weekday))
+            },
+        }
+    }
+
+    fn matches(&self, subject: &Subject) -> bool {
+        match self {
+            Self::DescriptionContains(text) => subject
+                .description
+                .to_lowercase()
+                .contains(text.trim().to_lowercase().as_str()),
+            Self::DescriptionMatches(pattern) => {
+                pattern.is_match(subject.description)
+            },
+            Self::AmountBetween(min, max) => match subject.amount {
+                Some(amount) => {
+                    let above = |bound: &Option<Money>| match bound {
+                        Some(min) => {
+                            min.currency == amount.currency
+                                && min.minor <= amount.minor
+                        },
+                        None => true,
+                    };
+                    let below = |bound: &Option<Money>| match bound {
+                        Some(max) => {
+                            max.currency == amount.currency
+                                && amount.minor <= max.minor
+                        },
+                        None => true,
+                    };
+                    above(min) && below(max)
+                },
+                None => false,
+            },
+            Self::Weekday(weekday) => subject
+                .datetime
+                .is_some_and(|datetime| datetime.weekday() == *weekday),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CategoryRule {
+    pub(crate) category: String,
+    pub(crate) conditions: Vec<Condition>,
+}
+
+impl CategoryRule {
+    fn conditions_text(&self, locale: Locale) -> String {
+        let conditions: Vec<String> = self
+            .conditions
+            .iter()
+            .map(|condition| condition.describe(locale))
+            .collect();
+        conditions.join(" and ")
+    }
+
+    pub(crate) fn describe(&self, locale: Locale) -> String {
+        format!("{} when {}", self.category, self.conditions_text(locale))
+    }
+
+    pub(crate) fn explain(&self, locale: Locale) -> String {
+        format!("“{}” because {}", self.category, self.conditions_text(locale))
+    }
+}
+
+pub(crate) struct Subject<'a> {
+    pub(crate) description: &'a str,
+    pub(crate) amount: Option<Money>,
+    pub(crate) datetime: Option<DateTime<FixedOffset>>,
+}
+
+impl<'a> From<&'a Entry> for Subject<'a> {
+    fn from(value: &'a Entry) -> Self {
+        Self {
+            description: &value.description,
+            amount: Some(value.amount),
+            datetime: Some(value.datetime),
+        }
+    }
+}
+
+pub(crate) fn categorize<'a>(
+    rules: &'a [CategoryRule],
+    subject: &Subject,
+) -> Option<&'a CategoryRule> {
+    rules.iter().find(|rule| {
+        !rule.conditions.is_empty()
+            && rule
+                .conditions
+                .iter()
+                .all(|condition| condition.matches(subject))
+    })
+}
+
+pub(crate) fn categorize_entries(
+    entries: &mut [Entry],
+    rules: &[CategoryRule],
+) {
+    for entry in entries.iter_mut() {
+        if !entry.category.is_empty() {
+            continue;
+        }
+        if let Some(rule) = categorize(rules, &Subject::from(&*entry)) {
+            entry.category = rule.category.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{
+        FixedOffset,
+        TimeZone,
+        Weekday,
+    };
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        categorize,
+        categorize_entries,
+        CategoryRule,
+        Condition,
+        Pattern,
+        Subject,
+    };
+    use crate::types::{
+        currency::Currency,
+        entry::Entry,
+        locale::Locale,
+        money::{
+            eur,
+            Money,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn make_rule(category: &str, conditions: Vec<Condition>) -> CategoryRule {
+        CategoryRule {
+            category: String::from(category),
+            conditions,
+        }
+    }
+
+    // 2023-06-05 is a Monday
+    fn make_entry(day: u32, description: &str, minor: i64) -> Entry {
+        Entry::fixture(
+            FixedOffset::east_opt(0)
+                .unwrap()
+                .with_ymd_and_hms(2023, 6, day, 12, 0, 0)
+                .unwrap(),
+            eur(minor),
+        )
+        .with_description(description)
+    }
+
+    #[wasm_bindgen_test]
+    fn conditions_match_subject() {
+        let entry = make_entry(5, "HSL monthly ticket", 6400);
+        let subject = Subject::from(&entry);
+        let tests = vec![
+            (Condition::DescriptionContains("hsl".into()), true),
+            (Condition::DescriptionContains(" ticket ".into()), true),
+            (Condition::DescriptionContains("Lidl".into()), false),
+            (Condition::DescriptionMatches("^hsl.*ticket$".into()), true),
+            (Condition::DescriptionMatches("^ticket".into()), false),
+            (Condition::DescriptionMatches("(".into()), false),
+            (Condition::AmountBetween(Some(eur(5000)), Some(eur(7000))), true),
+            (Condition::AmountBetween(Some(eur(6400)), None), true),
+            (Condition::AmountBetween(None, Some(eur(6399))), false),
+            (
+                Condition::AmountBetween(
+                    Some(Money::new(0, Currency::Usd)),
+                    None,
+                ),
+                false,
+            ),
+            (Condition::Weekday(Weekday::Mon), true),
+            (Condition::Weekday(Weekday::Sun), false),
+        ];
+
+        for (condition, expected) in tests {
+            assert_eq!(
+                condition.matches(&subject),
+                expected,
+                "{:?}",
+                condition
+            );
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn conditions_without_subject_data_do_not_match() {
+        let subject = Subject {
+            description: "",
+            amount: None,
+            datetime: None,
+        };
+        let tests = vec![
+            Condition::AmountBetween(None, Some(eur(100))),
+            Condition::Weekday(Weekday::Mon),
+        ];
+
+        for condition in tests {
+            assert!(!condition.matches(&subject), "{:?}", condition);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn condition_validity_works() {
+        let tests = vec![
+            (Condition::DescriptionContains("  ".into()), false),
+            (Condition::DescriptionContains("Lidl".into()), true),
+            (Condition::DescriptionMatches("".into()), false),
+            (Condition::DescriptionMatches("[a-".into()), false),
+            (Condition::DescriptionMatches("^K-".into()), true),
+            (Condition::AmountBetween(None, None), false),
+            (Condition::AmountBetween(Some(eur(200)), Some(eur(100))), false),
+            (Condition::AmountBetween(Some(eur(100)), Some(eur(200))), true),
+            (Condition::Weekday(Weekday::Fri), true),
+        ];
+
+        for (condition, expected) in tests {
+            assert_eq!(condition.is_valid(), expected, "{:?}", condition);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn first_rule_with_all_conditions_matching_is_chosen() {
+        let rules = vec![
+            make_rule("Empty", vec![]),
+            make_rule(
+                "Food › Lunch",
+                vec![
+                    Condition::DescriptionContains("Lidl".into()),
+                    Condition::AmountBetween(None, Some(eur(1500))),
+                ],
+            ),
+            make_rule(
+                "Food › Groceries",
+                vec![Condition::DescriptionContains("Lidl".into())],
+            ),
+            make_rule("Leisure", vec![Condition::Weekday(Weekday::Sat)]),
+        ];
+        let tests = vec![
+            (make_entry(5, "Lidl", 1200), Some("Food › Lunch")),
+            (make_entry(5, "Lidl", 4500), Some("Food › Groceries")),
+            (make_entry(10, "Cinema", 1400), Some("Leisure")),
+            (make_entry(5, "Cinema", 1400), None),
+        ];
+
+        for (entry, expected) in tests {
+            let chosen = categorize(&rules, &Subject::from(&entry))
+                .map(|rule| rule.category.as_str());
+            assert_eq!(chosen, expected, "{}", entry.description);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn categorize_entries_fills_only_missing_categories() {
+        let rules = vec![make_rule(
+            "Food",
+            vec![Condition::DescriptionContains("Lidl".into())],
+        )];
+        let mut entries = vec![
+            make_entry(5, "Lidl", 100),
+            make_entry(5, "Lidl", 100),
+            make_entry(5, "Cinema", 100),
+        ];
+        entries[1].category = String::from("Gifts");
+
+        categorize_entries(&mut entries, &rules);
+
+        let categories: Vec<&str> =
+            entries.iter().map(|e| e.category.as_str()).collect();
+
+        assert_eq!(categories, vec!["Food", "Gifts", ""]);
+    }
+
+    #[wasm_bindgen_test]
+    fn rule_describes_and_explains_its_conditions() {
+        let rule = make_rule(
+            "Transport",
+            vec![
+                Condition::DescriptionMatches("^HSL".into()),
+                Condition::AmountBetween(Some(eur(100)), Some(eur(10000))),
+                Condition::Weekday(Weekday::Mon),
+            ],
+        );
+
+        assert_eq!(
+            rule.describe(Locale::EnUs),
+            "Transport when description matches /^HSL/ and amount is between \
+             1.00 and 100.00 EUR and date is on a Monday"
+        );
+        assert_eq!(
+            rule.explain(Locale::EnUs),
+            "“Transport” because description matches /^HSL/ and amount is \
+             between 1.00 and 100.00 EUR and date is on a Monday"
+        );
+        assert_eq!(
+            Condition::AmountBetween(Some(eur(123450)), None)
+                .describe(Locale::FiFi),
+            "amount is at least 1\u{a0}234,50 EUR"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn pattern_is_compiled_once_and_compared_by_source() {
+        let pattern = Pattern::from("^hsl");
+
+        assert!(pattern.is_valid());
+        assert!(pattern.is_match("HSL ticket"));
+        assert_eq!(pattern, Pattern::from("^hsl"));
+        assert!(!Pattern::from("(").is_valid());
+        assert!(!Pattern::from("").is_valid());
+    }
+}
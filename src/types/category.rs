@@ -0,0 +1,301 @@
+use std::collections::BTreeMap;
+
+use yew::AttrValue;
+
+use crate::types::{
+    currency::Currency,
+    entry::Entry,
+    exchange_rate::ExchangeRate,
+    money::Money,
+    select::SelectOption,
+};
+
+pub(crate) const PATH_SEPARATOR: &str = " › ";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Category {
+    pub(crate) name: String,
+    pub(crate) children: Vec<Category>,
+}
+
+impl From<&str> for Category {
+    fn from(value: &str) -> Self {
+        Self {
+            name: value.to_owned(),
+            children: Vec::new(),
+        }
+    }
+}
+
+fn join_path(parent: Option<&str>, name: &str) -> String {
+    match parent {
+        Some(parent) => format!("{}{}{}", parent, PATH_SEPARATOR, name),
+        None => name.to_owned(),
+    }
+}
+
+fn ancestor_paths(path: &str) -> Vec<String> {
+    let names: Vec<&str> = path.split(PATH_SEPARATOR).collect();
+    (1..=names.len())
+        .map(|depth| names[..depth].join(PATH_SEPARATOR))
+        .collect()
+}
+
+pub(crate) fn is_in_category(path: &str, category: &str) -> bool {
+    ancestor_paths(path)
+        .iter()
+        .any(|ancestor| ancestor == category)
+}
+
+pub(crate) fn select_options(categories: &[Category]) -> Vec<SelectOption> {
+    fn option(parent: Option<&str>, category: &Category) -> SelectOption {
+        SelectOption {
+            value: AttrValue::from(join_path(parent, &category.name)),
+            inner_html: AttrValue::from(category.name.clone()),
+            ..Default::default()
+        }
+        .group(parent.map(|p| AttrValue::from(p.to_owned())))
+    }
+
+    fn push_descendants(
+        options: &mut Vec<SelectOption>,
+        category: &Category,
+        path: &str,
+    ) {
+        for child in &category.children {
+            options.push(option(Some(path), child));
+        }
+        for child in &category.children {
+            let child_path = join_path(Some(path), &child.name);
+            push_descendants(options, child, &child_path);
+        }
+    }
+
+    let mut options = Vec::new();
+    for category in categories {
+        options.push(option(None, category));
+        push_descendants(&mut options, category, &category.name);
+    }
+    options
+}
+
+pub(crate) fn totals_by_category(
+    entries: &[Entry],
+    currency: Currency,
+    rates: &[ExchangeRate],
+) -> Option<BTreeMap<String, Money>> {
+    let mut totals = BTreeMap::new();
+    for entry in entries.iter().filter(|entry| entry.is_spending()) {
+        for (category, amount) in entry.parts() {
+            let amount = entry.convert(amount, currency, rates)?;
+            for path in ancestor_paths(category) {
+                let total = totals.entry(path).or_insert(Money::zero(currency));
+                *total = total.checked_add(amount)?;
+            }
+        }
+    }
+    Some(totals)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{
+        FixedOffset,
+        TimeZone,
+    };
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use yew::AttrValue;
+
+    use super::{
+        ancestor_paths,
+        is_in_category,
+        select_options,
+        totals_by_category,
+        Category,
+    };
+    use crate::types::{
+        currency::Currency,
+        entry::{
+            Entry,
+            EntryKind,
+            EntryLine,
+        },
+        money::Money,
+        select::SelectOption,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn make_category(name: &str, children: Vec<Category>) -> Category {
+        Category {
+            name: String::from(name),
+            children,
+        }
+    }
+
+    fn make_tree() -> Vec<Category> {
+        vec![
+            make_category(
+                "Food",
+                vec![
+                    Category::from("Groceries"),
+                    make_category("Restaurants", vec![Category::from("Lunch")]),
+                    Category::from("Snacks"),
+                ],
+            ),
+            Category::from("Rent"),
+        ]
+    }
+
+    fn make_entry(category: &str, minor: i64) -> Entry {
+        Entry::fixture(
+            FixedOffset::east_opt(0)
+                .unwrap()
+                .with_ymd_and_hms(2023, 6, 1, 12, 0, 0)
+                .unwrap(),
+            Money::new(minor, Currency::Eur),
+        )
+        .with_category(category)
+    }
+
+    #[wasm_bindgen_test]
+    fn ancestor_paths_works() {
+        let tests = vec![
+            ("Rent", vec!["Rent"]),
+            ("Food › Groceries", vec!["Food", "Food › Groceries"]),
+            (
+                "Food › Restaurants › Lunch",
+                vec![
+                    "Food",
+                    "Food › Restaurants",
+                    "Food › Restaurants › Lunch",
+                ],
+            ),
+        ];
+
+        for (path, expected) in tests {
+            assert_eq!(ancestor_paths(path), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn is_in_category_includes_descendants() {
+        let tests = vec![
+            ("Food › Groceries", "Food", true),
+            ("Food › Groceries", "Food › Groceries", true),
+            ("Food", "Food › Groceries", false),
+            ("Foodstuff", "Food", false),
+            ("Rent", "Food", false),
+        ];
+
+        for (path, category, expected) in tests {
+            assert_eq!(is_in_category(path, category), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn select_options_are_grouped_by_parent_path() {
+        let option =
+            |value: &str, inner_html: &str, group: Option<&str>| SelectOption {
+                value: AttrValue::from(value.to_owned()),
+                inner_html: AttrValue::from(inner_html.to_owned()),
+                group: group.map(|g| AttrValue::from(g.to_owned())),
+                ..Default::default()
+            };
+        let expected = vec![
+            option("Food", "Food", None),
+            option("Food › Groceries", "Groceries", Some("Food")),
+            option("Food › Restaurants", "Restaurants", Some("Food")),
+            option("Food › Snacks", "Snacks", Some("Food")),
+            option(
+                "Food › Restaurants › Lunch",
+                "Lunch",
+                Some("Food › Restaurants"),
+            ),
+            option("Rent", "Rent", None),
+        ];
+
+        assert_eq!(select_options(&make_tree()), expected);
+    }
+
+    #[wasm_bindgen_test]
+    fn totals_by_category_rolls_children_up_to_parents() {
+        let entries = vec![
+            make_entry("Food › Groceries", 1000),
+            make_entry("Food › Restaurants › Lunch", 1250),
+            make_entry("Food", 100),
+            make_entry("Rent", 50000),
+        ];
+        let totals = totals_by_category(&entries, Currency::Eur, &[])
+            .expect("Totals to be computed");
+
+        let expected = vec![
+            ("Food", 2350),
+            ("Food › Groceries", 1000),
+            ("Food › Restaurants", 1250),
+            ("Food › Restaurants › Lunch", 1250),
+            ("Rent", 50000),
+        ];
+        let totals: Vec<(&str, i64)> = totals
+            .iter()
+            .map(|(path, money)| (path.as_str(), money.minor))
+            .collect();
+
+        assert_eq!(totals, expected);
+    }
+
+    #[wasm_bindgen_test]
+    fn totals_by_category_uses_split_lines() {
+        let mut entry = make_entry("Food", 1000);
+        entry.lines = vec![
+            EntryLine {
+                category: String::from("Food › Groceries"),
+                description: String::new(),
+                amount: Money::new(600, Currency::Eur),
+            },
+            EntryLine {
+                category: String::from("Household"),
+                description: String::new(),
+                amount: Money::new(400, Currency::Eur),
+            },
+        ];
+        let totals = totals_by_category(&[entry], Currency::Eur, &[])
+            .expect("Totals to be computed");
+
+        let totals: Vec<(&str, i64)> = totals
+            .iter()
+            .map(|(path, money)| (path.as_str(), money.minor))
+            .collect();
+
+        assert_eq!(
+            totals,
+            vec![("Food", 600), ("Food › Groceries", 600), ("Household", 400)]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn totals_by_category_skips_transfers() {
+        let mut transfer = make_entry("Food", 1000);
+        transfer.kind = EntryKind::Transfer {
+            to_account: String::from("Savings"),
+        };
+        let entries = vec![make_entry("Food", 250), transfer];
+        let totals = totals_by_category(&entries, Currency::Eur, &[])
+            .expect("Totals to be computed");
+
+        assert_eq!(totals.get("Food"), Some(&Money::new(250, Currency::Eur)));
+    }
+
+    #[wasm_bindgen_test]
+    fn totals_by_category_is_none_on_overflow() {
+        let entries = vec![
+            make_entry("Food › Groceries", i64::MAX),
+            make_entry("Food › Restaurants", 1),
+        ];
+
+        assert_eq!(totals_by_category(&entries, Currency::Eur, &[]), None);
+    }
+}
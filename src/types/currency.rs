@@ -0,0 +1,153 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub(crate) enum Currency {
+    #[default]
+    Eur,
+    Usd,
+    Gbp,
+    Sek,
+    Nok,
+    Dkk,
+    Chf,
+    Brl,
+    Inr,
+    Jpy,
+    Krw,
+    Isk,
+    Bhd,
+    Kwd,
+    Jod,
+    Omr,
+    Tnd,
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Eur => write!(f, "EUR"),
+            Self::Usd => write!(f, "USD"),
+            Self::Gbp => write!(f, "GBP"),
+            Self::Sek => write!(f, "SEK"),
+            Self::Nok => write!(f, "NOK"),
+            Self::Dkk => write!(f, "DKK"),
+            Self::Chf => write!(f, "CHF"),
+            Self::Brl => write!(f, "BRL"),
+            Self::Inr => write!(f, "INR"),
+            Self::Jpy => write!(f, "JPY"),
+            Self::Krw => write!(f, "KRW"),
+            Self::Isk => write!(f, "ISK"),
+            Self::Bhd => write!(f, "BHD"),
+            Self::Kwd => write!(f, "KWD"),
+            Self::Jod => write!(f, "JOD"),
+            Self::Omr => write!(f, "OMR"),
+            Self::Tnd => write!(f, "TND"),
+        }
+    }
+}
+
+impl Currency {
+    pub(crate) const ALL: [Self; 17] = [
+        Self::Eur,
+        Self::Usd,
+        Self::Gbp,
+        Self::Sek,
+        Self::Nok,
+        Self::Dkk,
+        Self::Chf,
+        Self::Brl,
+        Self::Inr,
+        Self::Jpy,
+        Self::Krw,
+        Self::Isk,
+        Self::Bhd,
+        Self::Kwd,
+        Self::Jod,
+        Self::Omr,
+        Self::Tnd,
+    ];
+
+    pub(crate) fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|currency| currency.to_string().eq_ignore_ascii_case(code))
+    }
+
+    pub(crate) fn minor_units(&self) -> u32 {
+        match self {
+            Self::Jpy | Self::Krw | Self::Isk => 0,
+            Self::Bhd | Self::Kwd | Self::Jod | Self::Omr | Self::Tnd => 3,
+            _ => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::Currency;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn currency_is_displayed_as_iso_4217_code() {
+        let tests = vec![
+            (Currency::Eur, "EUR"),
+            (Currency::Usd, "USD"),
+            (Currency::Brl, "BRL"),
+            (Currency::Jpy, "JPY"),
+            (Currency::Bhd, "BHD"),
+        ];
+
+        for (currency, expected) in tests {
+            assert_eq!(currency.to_string(), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn currency_has_expected_minor_units() {
+        let tests = vec![
+            (Currency::Eur, 2),
+            (Currency::Usd, 2),
+            (Currency::Sek, 2),
+            (Currency::Jpy, 0),
+            (Currency::Krw, 0),
+            (Currency::Isk, 0),
+            (Currency::Bhd, 3),
+            (Currency::Kwd, 3),
+            (Currency::Tnd, 3),
+        ];
+
+        for (currency, expected) in tests {
+            assert_eq!(currency.minor_units(), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn currency_can_be_constructed_from_code() {
+        let tests = vec![
+            ("EUR", Some(Currency::Eur)),
+            ("eur", Some(Currency::Eur)),
+            ("JPY", Some(Currency::Jpy)),
+            ("BHD", Some(Currency::Bhd)),
+            ("XXX", None),
+            ("", None),
+        ];
+
+        for (code, expected) in tests {
+            assert_eq!(Currency::from_code(code), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn all_currencies_roundtrip_through_code() {
+        for currency in Currency::ALL {
+            assert_eq!(
+                Currency::from_code(&currency.to_string()),
+                Some(currency)
+            );
+        }
+    }
+}
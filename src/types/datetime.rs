@@ -46,6 +46,80 @@ impl std::fmt::Display for Month {
     }
 }
 
+pub(crate) fn previous_month(year: Year, month: Month) -> (Year, Month) {
+    match month {
+        Month::January => (year - 1, Month::December),
+        _ => (year, Month::from_u32(month as u32 - 1)),
+    }
+}
+
+pub(crate) fn next_month(year: Year, month: Month) -> (Year, Month) {
+    match month {
+        Month::December => (year + 1, Month::January),
+        _ => (year, Month::from_u32(month as u32 + 1)),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Period {
+    Month(Year, Month),
+    Year(Year),
+}
+
+impl std::fmt::Display for Period {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Month(year, month) => write!(f, "{} {}", month, year),
+            Self::Year(year) => write!(f, "{}", year),
+        }
+    }
+}
+
+impl Period {
+    pub(crate) fn range<T: TimeZone>(
+        &self,
+        timezone: &T,
+    ) -> Option<DateTimeRange<T>> {
+        match *self {
+            Self::Month(year, month) => {
+                DateTimeRange::month(timezone, year, month)
+            },
+            Self::Year(year) => DateTimeRange::year(timezone, year),
+        }
+    }
+
+    pub(crate) fn previous(&self) -> Self {
+        match *self {
+            Self::Month(year, month) => {
+                let (year, month) = previous_month(year, month);
+                Self::Month(year, month)
+            },
+            Self::Year(year) => Self::Year(year - 1),
+        }
+    }
+
+    pub(crate) fn year_before(&self) -> Self {
+        match *self {
+            Self::Month(year, month) => Self::Month(year - 1, month),
+            Self::Year(year) => Self::Year(year - 1),
+        }
+    }
+}
+
+fn start_of_day<T: TimeZone>(
+    timezone: &T,
+    date: NaiveDate,
+) -> Option<DateTime<T>> {
+    let midnight = date.and_hms_opt(0, 0, 0)?;
+    timezone
+        .from_local_datetime(&midnight)
+        .earliest()
+        .or_else(|| {
+            let after_gap = midnight + chrono::Duration::hours(1);
+            timezone.from_local_datetime(&after_gap).earliest()
+        })
+}
+
 impl Month {
     pub(crate) fn from_u32(month: u32) -> Self {
         match month % 12 {
@@ -79,6 +153,44 @@ impl<T: TimeZone> DateTimeRange<T> {
         }
     }
 
+    pub(crate) fn month(
+        timezone: &T,
+        year: Year,
+        month: Month,
+    ) -> Option<Self> {
+        let (next_year, next) = next_month(year, month);
+        let start = NaiveDate::from_ymd_opt(year, month as u32, 1)?;
+        let end = NaiveDate::from_ymd_opt(next_year, next as u32, 1)?;
+        Some(Self::from(
+            start_of_day(timezone, start)?,
+            start_of_day(timezone, end)? - chrono::Duration::nanoseconds(1),
+        ))
+    }
+
+    pub(crate) fn year(timezone: &T, year: Year) -> Option<Self> {
+        Self::days(
+            timezone,
+            NaiveDate::from_ymd_opt(year, 1, 1)?,
+            NaiveDate::from_ymd_opt(year, 12, 31)?,
+        )
+    }
+
+    pub(crate) fn days(
+        timezone: &T,
+        first: NaiveDate,
+        last: NaiveDate,
+    ) -> Option<Self> {
+        Some(Self::from(
+            start_of_day(timezone, first)?,
+            start_of_day(timezone, last.succ_opt()?)?
+                - chrono::Duration::nanoseconds(1),
+        ))
+    }
+
+    pub(crate) fn contains<U: TimeZone>(&self, datetime: &DateTime<U>) -> bool {
+        *self.start <= *datetime && *datetime <= *self.end
+    }
+
     pub(crate) fn list_years(&self) -> Vec<Year> {
         if self.start > self.end {
             return Vec::new();
@@ -114,6 +226,17 @@ impl<T: TimeZone> DateTimeRange<T> {
         }
     }
 
+    pub(crate) fn list_months(&self) -> Vec<(Year, Month)> {
+        self.list_years()
+            .into_iter()
+            .flat_map(|year| {
+                self.list_months_for_year(year)
+                    .into_iter()
+                    .map(move |month| (year, month))
+            })
+            .collect()
+    }
+
     pub(crate) fn get_month_or_last_for_year(
         &self,
         month: Option<Month>,
@@ -177,17 +300,22 @@ impl<T: TimeZone> DateTimeRange<T> {
 mod test {
     use chrono::{
         DateTime,
+        NaiveDate,
         TimeZone,
         Utc,
     };
+    use chrono_tz::Tz;
     use wasm_bindgen_test::{
         wasm_bindgen_test,
         wasm_bindgen_test_configure,
     };
 
     use super::{
+        next_month,
+        previous_month,
         DateTimeRange,
         Month,
+        Period,
     };
 
     wasm_bindgen_test_configure!(run_in_browser);
@@ -224,6 +352,148 @@ mod test {
         }
     }
 
+    #[wasm_bindgen_test]
+    fn previous_and_next_month_wrap_around_years() {
+        let tests = vec![
+            ((2023, Month::June), (2023, Month::May), (2023, Month::July)),
+            (
+                (2023, Month::January),
+                (2022, Month::December),
+                (2023, Month::February),
+            ),
+            (
+                (2023, Month::December),
+                (2023, Month::November),
+                (2024, Month::January),
+            ),
+        ];
+
+        for ((year, month), previous, next) in tests {
+            assert_eq!(previous_month(year, month), previous);
+            assert_eq!(next_month(year, month), next);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn datetimerange_month_follows_timezone() {
+        let timezone = Tz::Europe__Helsinki;
+        let range =
+            DateTimeRange::month(&timezone, 2023, Month::March).unwrap();
+        let tests = vec![
+            (Utc.with_ymd_and_hms(2023, 2, 28, 21, 59, 59).unwrap(), false),
+            (Utc.with_ymd_and_hms(2023, 2, 28, 22, 0, 0).unwrap(), true),
+            (Utc.with_ymd_and_hms(2023, 3, 31, 20, 59, 59).unwrap(), true),
+            (Utc.with_ymd_and_hms(2023, 3, 31, 21, 0, 0).unwrap(), false),
+        ];
+
+        for (datetime, expected) in tests {
+            assert_eq!(range.contains(&datetime), expected, "{}", datetime);
+        }
+
+        let december =
+            DateTimeRange::month(&timezone, 2023, Month::December).unwrap();
+
+        assert_eq!(december.end.format("%Y-%m-%d").to_string(), "2023-12-31");
+    }
+
+    #[wasm_bindgen_test]
+    fn period_has_previous_and_year_before() {
+        let tests = vec![
+            (
+                Period::Month(2023, Month::January),
+                Period::Month(2022, Month::December),
+                Period::Month(2022, Month::January),
+                "January 2023",
+            ),
+            (
+                Period::Month(2023, Month::June),
+                Period::Month(2023, Month::May),
+                Period::Month(2022, Month::June),
+                "June 2023",
+            ),
+            (
+                Period::Year(2023),
+                Period::Year(2022),
+                Period::Year(2022),
+                "2023",
+            ),
+        ];
+
+        for (period, previous, year_before, text) in tests {
+            assert_eq!(period.previous(), previous);
+            assert_eq!(period.year_before(), year_before);
+            assert_eq!(period.to_string(), text);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn period_range_follows_timezone() {
+        let timezone = Tz::Europe__Helsinki;
+        let tests = vec![
+            (Period::Year(2023), (2022, 12, 31, 22), true),
+            (Period::Year(2023), (2023, 12, 31, 21), true),
+            (Period::Year(2023), (2023, 12, 31, 22), false),
+            (Period::Month(2023, Month::June), (2023, 5, 31, 21), true),
+            (Period::Month(2023, Month::June), (2023, 6, 30, 21), false),
+        ];
+
+        for (period, (y, m, d, h), expected) in tests {
+            let range = period.range(&timezone).unwrap();
+            let datetime = Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap();
+            assert_eq!(range.contains(&datetime), expected, "{}", datetime);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn datetimerange_days_includes_whole_days_in_timezone() {
+        let timezone = Tz::Europe__Helsinki;
+        let range = DateTimeRange::days(
+            &timezone,
+            NaiveDate::from_ymd_opt(2023, 3, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 3, 26).unwrap(),
+        )
+        .unwrap();
+        let tests = vec![
+            (Utc.with_ymd_and_hms(2023, 2, 28, 21, 59, 59).unwrap(), false),
+            (Utc.with_ymd_and_hms(2023, 2, 28, 22, 0, 0).unwrap(), true),
+            // DST starts on 2023-03-26
+            (Utc.with_ymd_and_hms(2023, 3, 26, 20, 59, 59).unwrap(), true),
+            (Utc.with_ymd_and_hms(2023, 3, 26, 21, 0, 0).unwrap(), false),
+        ];
+
+        for (datetime, expected) in tests {
+            assert_eq!(range.contains(&datetime), expected, "{}", datetime);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn datetimerange_list_months_lists_months_across_years() {
+        let tests = vec![
+            (make_date(2000, 1, 1), make_date(1999, 1, 1), vec![]),
+            (
+                make_date(1999, 11, 30),
+                make_date(2000, 2, 1),
+                vec![
+                    (1999, Month::November),
+                    (1999, Month::December),
+                    (2000, Month::January),
+                    (2000, Month::February),
+                ],
+            ),
+            (
+                make_date(2000, 3, 1),
+                make_date(2000, 3, 31),
+                vec![(2000, Month::March)],
+            ),
+        ];
+
+        for (date1, date2, expected) in tests {
+            let range = DateTimeRange::from(date1, date2);
+
+            assert_eq!(range.list_months(), expected);
+        }
+    }
+
     #[wasm_bindgen_test]
     fn datetimerange_is_constructed_from_two_datetimes() {
         let date1 = Utc::now();
@@ -231,6 +501,23 @@ mod test {
         let _ = DateTimeRange::from(date1, date2);
     }
 
+    #[wasm_bindgen_test]
+    fn datetimerange_contains_datetimes_between_its_dates() {
+        let range =
+            DateTimeRange::from(make_date(2020, 1, 1), make_date(2020, 12, 31));
+        let tests = vec![
+            (make_date(2019, 12, 31), false),
+            (make_date(2020, 1, 1), true),
+            (make_date(2020, 6, 15), true),
+            (make_date(2020, 12, 31), true),
+            (make_date(2021, 1, 1), false),
+        ];
+
+        for (datetime, expected) in tests {
+            assert_eq!(range.contains(&datetime), expected);
+        }
+    }
+
     #[wasm_bindgen_test]
     fn datetimerange_list_years_lists_years_between_its_dates() {
         let tests = vec![
@@ -0,0 +1,318 @@
+use chrono::{
+    DateTime,
+    FixedOffset,
+};
+
+use crate::types::{
+    currency::Currency,
+    exchange_rate::{
+        find_rate,
+        ExchangeRate,
+    },
+    money::Money,
+};
+
+pub(crate) const DESCRIPTION_MAX_LENGTH: usize = 255;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct EntryLine {
+    pub(crate) category: String,
+    pub(crate) description: String,
+    pub(crate) amount: Money,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) enum EntryKind {
+    #[default]
+    Expense,
+    Income,
+    Transfer {
+        to_account: String,
+    },
+    Contribution {
+        goal: String,
+    },
+}
+
+impl EntryKind {
+    pub(crate) fn is_internal(&self) -> bool {
+        matches!(self, Self::Transfer { .. } | Self::Contribution { .. })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Entry {
+    pub(crate) datetime: DateTime<FixedOffset>,
+    pub(crate) account: String,
+    pub(crate) kind: EntryKind,
+    pub(crate) category: String,
+    pub(crate) description: String,
+    pub(crate) tags: Vec<String>,
+    pub(crate) amount: Money,
+    pub(crate) exchange_rate: Option<ExchangeRate>,
+    pub(crate) lines: Vec<EntryLine>,
+}
+
+impl Entry {
+    pub(crate) fn is_spending(&self) -> bool {
+        self.kind == EntryKind::Expense
+    }
+
+    pub(crate) fn parts(&self) -> Vec<(&str, Money)> {
+        match self.lines.is_empty() {
+            true => vec![(self.category.as_str(), self.amount)],
+            false => self
+                .lines
+                .iter()
+                .map(|line| (line.category.as_str(), line.amount))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn amount_in(
+        &self,
+        currency: Currency,
+        rates: &[ExchangeRate],
+    ) -> Option<Money> {
+        self.convert(self.amount, currency, rates)
+    }
+
+    pub(crate) fn convert(
+        &self,
+        money: Money,
+        currency: Currency,
+        rates: &[ExchangeRate],
+    ) -> Option<Money> {
+        if money.currency == currency {
+            return Some(money);
+        }
+
+        let rate = self
+            .exchange_rate
+            .filter(|r| r.from == money.currency && r.to == currency)
+            .or_else(|| {
+                let date = self.datetime.date_naive();
+                find_rate(rates, date, money.currency, currency).copied()
+            })?;
+        rate.convert(money)
+    }
+}
+
+pub(crate) fn split_remainder(total: Money, lines: &[Money]) -> Option<Money> {
+    let sum = Money::checked_sum(lines.iter().copied(), total.currency)?;
+    total.checked_sub(sum)
+}
+
+pub(crate) fn total_in(
+    entries: &[Entry],
+    currency: Currency,
+    rates: &[ExchangeRate],
+) -> Option<Money> {
+    let amounts = entries
+        .iter()
+        .map(|entry| entry.amount_in(currency, rates))
+        .collect::<Option<Vec<Money>>>()?;
+    Money::checked_sum(amounts, currency)
+}
+
+#[cfg(test)]
+impl Entry {
+    pub(crate) fn fixture(
+        datetime: DateTime<FixedOffset>,
+        amount: Money,
+    ) -> Self {
+        Self {
+            datetime,
+            account: String::new(),
+            kind: EntryKind::Expense,
+            category: String::new(),
+            description: String::new(),
+            tags: Vec::new(),
+            amount,
+            exchange_rate: None,
+            lines: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_account(mut self, account: &str) -> Self {
+        self.account = String::from(account);
+        self
+    }
+
+    pub(crate) fn with_kind(mut self, kind: EntryKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub(crate) fn with_category(mut self, category: &str) -> Self {
+        self.category = String::from(category);
+        self
+    }
+
+    pub(crate) fn with_description(mut self, description: &str) -> Self {
+        self.description = String::from(description);
+        self
+    }
+
+    pub(crate) fn with_exchange_rate(
+        mut self,
+        exchange_rate: Option<ExchangeRate>,
+    ) -> Self {
+        self.exchange_rate = exchange_rate;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{
+        DateTime,
+        FixedOffset,
+        NaiveDate,
+        TimeZone,
+    };
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        split_remainder,
+        total_in,
+        Entry,
+        EntryLine,
+    };
+    use crate::types::{
+        currency::Currency,
+        exchange_rate::{
+            ExchangeRate,
+            RateSource,
+        },
+        money::{
+            eur,
+            Money,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn make_datetime(day: u32) -> DateTime<FixedOffset> {
+        FixedOffset::east_opt(3 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2023, 6, day, 12, 0, 0)
+            .unwrap()
+    }
+
+    fn make_rate(day: u32, from: Currency, rate: i64) -> ExchangeRate {
+        ExchangeRate {
+            date: NaiveDate::from_ymd_opt(2023, 6, day).unwrap(),
+            from,
+            to: Currency::Eur,
+            rate,
+            source: RateSource::Backend,
+        }
+    }
+
+    fn make_entry(
+        day: u32,
+        amount: Money,
+        exchange_rate: Option<ExchangeRate>,
+    ) -> Entry {
+        Entry::fixture(make_datetime(day), amount)
+            .with_category("Placeholder")
+            .with_exchange_rate(exchange_rate)
+    }
+
+    #[wasm_bindgen_test]
+    fn amount_in_same_currency_is_unchanged() {
+        let entry = make_entry(1, Money::new(1234, Currency::Eur), None);
+        let amount = entry.amount_in(Currency::Eur, &[]);
+        assert_eq!(amount, Some(Money::new(1234, Currency::Eur)));
+    }
+
+    #[wasm_bindgen_test]
+    fn amount_in_prefers_rate_saved_on_entry() {
+        let saved = make_rate(1, Currency::Usd, 500_000);
+        let rates = vec![make_rate(1, Currency::Usd, 900_000)];
+        let entry = make_entry(1, Money::new(1000, Currency::Usd), Some(saved));
+        let amount = entry.amount_in(Currency::Eur, &rates);
+        assert_eq!(amount, Some(Money::new(500, Currency::Eur)));
+    }
+
+    #[wasm_bindgen_test]
+    fn amount_in_falls_back_to_rate_of_the_day() {
+        let rates = vec![
+            make_rate(1, Currency::Usd, 900_000),
+            make_rate(2, Currency::Usd, 800_000),
+        ];
+        let entry = make_entry(1, Money::new(1000, Currency::Usd), None);
+        let amount = entry.amount_in(Currency::Eur, &rates);
+        assert_eq!(amount, Some(Money::new(900, Currency::Eur)));
+    }
+
+    #[wasm_bindgen_test]
+    fn amount_in_is_none_without_rate() {
+        let rates = vec![make_rate(2, Currency::Usd, 800_000)];
+        let entry = make_entry(1, Money::new(1000, Currency::Usd), None);
+        assert_eq!(entry.amount_in(Currency::Eur, &rates), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn total_in_converts_and_sums_entries() {
+        let rates = vec![
+            make_rate(1, Currency::Usd, 900_000),
+            make_rate(1, Currency::Jpy, 6_400),
+        ];
+        let entries = vec![
+            make_entry(1, Money::new(1000, Currency::Eur), None),
+            make_entry(1, Money::new(1000, Currency::Usd), None),
+            make_entry(1, Money::new(10_000, Currency::Jpy), None),
+        ];
+        let total = total_in(&entries, Currency::Eur, &rates);
+        assert_eq!(total, Some(Money::new(1000 + 900 + 6_400, Currency::Eur)));
+
+        let entries =
+            vec![make_entry(1, Money::new(1000, Currency::Gbp), None)];
+        assert_eq!(total_in(&entries, Currency::Eur, &rates), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn parts_are_lines_when_split() {
+        let mut entry = make_entry(1, eur(1000), None);
+
+        assert_eq!(entry.parts(), vec![("Placeholder", eur(1000))]);
+
+        entry.lines = vec![
+            EntryLine {
+                category: String::from("Food › Groceries"),
+                description: String::new(),
+                amount: eur(700),
+            },
+            EntryLine {
+                category: String::from("Household"),
+                description: String::from("Detergent"),
+                amount: eur(300),
+            },
+        ];
+
+        assert_eq!(
+            entry.parts(),
+            vec![("Food › Groceries", eur(700)), ("Household", eur(300))]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn split_remainder_uses_exact_minor_units() {
+        let tests = vec![
+            (eur(1000), vec![], Some(eur(1000))),
+            (eur(1000), vec![eur(333), eur(333), eur(334)], Some(eur(0))),
+            (eur(1000), vec![eur(700), eur(400)], Some(eur(-100))),
+            (eur(1000), vec![Money::new(1000, Currency::Usd)], None),
+            (eur(1000), vec![eur(i64::MAX), eur(1)], None),
+        ];
+
+        for (total, lines, expected) in tests {
+            assert_eq!(split_remainder(total, &lines), expected);
+        }
+    }
+}
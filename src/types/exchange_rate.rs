@@ -0,0 +1,219 @@
+use chrono::NaiveDate;
+
+use crate::types::{
+    currency::Currency,
+    money::{
+        round_half_even,
+        Money,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum RateSource {
+    Backend,
+    Manual,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct ExchangeRate {
+    pub(crate) date: NaiveDate,
+    pub(crate) from: Currency,
+    pub(crate) to: Currency,
+    pub(crate) rate: i64,
+    pub(crate) source: RateSource,
+}
+
+impl ExchangeRate {
+    pub(crate) const DECIMALS: u32 = 6;
+
+    pub(crate) fn parse_rate(string: &str) -> Option<i64> {
+        let string = string.trim().replace(',', ".");
+        let (units, fraction) = string.split_once('.').unwrap_or((&string, ""));
+        let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if (units.is_empty() && fraction.is_empty())
+            || !is_digits(units)
+            || !is_digits(fraction)
+            || fraction.len() > Self::DECIMALS as usize
+        {
+            return None;
+        }
+
+        let fraction =
+            format!("{:0<width$}", fraction, width = Self::DECIMALS as usize);
+        let rate = format!("{}{}", units, fraction).parse::<i64>().ok()?;
+        (rate > 0).then_some(rate)
+    }
+
+    pub(crate) fn format_rate(&self) -> String {
+        let scale = 10_i64.pow(Self::DECIMALS);
+        let units = self.rate / scale;
+        let fraction = format!(
+            "{:0>width$}",
+            self.rate % scale,
+            width = Self::DECIMALS as usize
+        );
+        match fraction.trim_end_matches('0') {
+            "" => units.to_string(),
+            fraction => format!("{}.{}", units, fraction),
+        }
+    }
+
+    pub(crate) fn convert(&self, money: Money) -> Option<Money> {
+        if money.currency != self.from {
+            return None;
+        }
+
+        let numerator = i128::from(money.minor)
+            .checked_mul(i128::from(self.rate))?
+            .checked_mul(10_i128.pow(self.to.minor_units()))?;
+        let denominator = 10_i128.pow(Self::DECIMALS + self.from.minor_units());
+        let minor = round_half_even(numerator, denominator)?;
+        Some(Money::new(minor, self.to))
+    }
+}
+
+pub(crate) fn find_rate(
+    rates: &[ExchangeRate],
+    date: NaiveDate,
+    from: Currency,
+    to: Currency,
+) -> Option<&ExchangeRate> {
+    rates
+        .iter()
+        .filter(|r| r.from == from && r.to == to && r.date <= date)
+        .max_by_key(|r| r.date)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        find_rate,
+        ExchangeRate,
+        RateSource,
+    };
+    use crate::types::{
+        currency::Currency,
+        money::Money,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn make_rate(
+        day: u32,
+        from: Currency,
+        to: Currency,
+        rate: i64,
+    ) -> ExchangeRate {
+        ExchangeRate {
+            date: NaiveDate::from_ymd_opt(2023, 6, day).unwrap(),
+            from,
+            to,
+            rate,
+            source: RateSource::Backend,
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn parse_rate_works() {
+        let tests = vec![
+            ("1", Some(1_000_000)),
+            ("1.0832", Some(1_083_200)),
+            ("1,0832", Some(1_083_200)),
+            (" 0.5 ", Some(500_000)),
+            (".5", Some(500_000)),
+            ("156.123456", Some(156_123_456)),
+            ("156.1234567", None),
+            ("0", None),
+            ("0.000000", None),
+            ("", None),
+            (".", None),
+            ("1.2.3", None),
+            ("-1", None),
+            ("abc", None),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(ExchangeRate::parse_rate(input), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn format_rate_works() {
+        let tests = vec![
+            (1_000_000, "1"),
+            (1_083_200, "1.0832"),
+            (500_000, "0.5"),
+            (156_123_456, "156.123456"),
+            (1, "0.000001"),
+        ];
+
+        for (rate, expected) in tests {
+            let rate = make_rate(1, Currency::Usd, Currency::Eur, rate);
+            assert_eq!(rate.format_rate(), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn convert_works() {
+        let tests = vec![
+            // same number of minor units
+            (Currency::Usd, Currency::Eur, 923_400, 1000, Some(923)),
+            // rounds half to even
+            (Currency::Usd, Currency::Eur, 500_000, 1, Some(0)),
+            (Currency::Usd, Currency::Eur, 1_500_000, 1, Some(2)),
+            (Currency::Usd, Currency::Eur, 2_500_000, 1, Some(2)),
+            (Currency::Usd, Currency::Eur, 2_500_000, -1, Some(-2)),
+            // from zero minor units
+            (Currency::Jpy, Currency::Eur, 6_400, 10_000, Some(6_400)),
+            // to zero minor units
+            (Currency::Eur, Currency::Jpy, 156_123_456, 1234, Some(1927)),
+            // to three minor units
+            (Currency::Eur, Currency::Bhd, 410_000, 1000, Some(4100)),
+            // overflow
+            (Currency::Usd, Currency::Eur, 2_000_000, i64::MAX, None),
+        ];
+
+        for (from, to, rate, minor, expected) in tests {
+            let rate = make_rate(1, from, to, rate);
+            let expected = expected.map(|m| Money::new(m, to));
+            assert_eq!(rate.convert(Money::new(minor, from)), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn convert_rejects_money_in_other_currency() {
+        let rate = make_rate(1, Currency::Usd, Currency::Eur, 1_000_000);
+        assert_eq!(rate.convert(Money::new(100, Currency::Gbp)), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn find_rate_returns_latest_rate_on_or_before_date() {
+        let rates = vec![
+            make_rate(1, Currency::Usd, Currency::Eur, 1),
+            make_rate(5, Currency::Usd, Currency::Eur, 5),
+            make_rate(3, Currency::Usd, Currency::Eur, 3),
+            make_rate(4, Currency::Gbp, Currency::Eur, 4),
+        ];
+        let tests = vec![
+            (1, Currency::Usd, Some(1)),
+            (2, Currency::Usd, Some(1)),
+            (4, Currency::Usd, Some(3)),
+            (30, Currency::Usd, Some(5)),
+            (3, Currency::Gbp, None),
+            (4, Currency::Gbp, Some(4)),
+            (4, Currency::Jpy, None),
+        ];
+
+        for (day, from, expected) in tests {
+            let date = NaiveDate::from_ymd_opt(2023, 6, day).unwrap();
+            let rate = find_rate(&rates, date, from, Currency::Eur);
+            assert_eq!(rate.map(|r| r.rate), expected);
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use std::{
+    iter::Peekable,
+    str::Chars,
+};
+
+use crate::types::{
+    locale::Locale,
+    money::round_half_even,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Rational {
+    pub(crate) numerator: i128,
+    pub(crate) denominator: i128,
+}
+
+impl Rational {
+    fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let divisor = gcd(numerator, denominator).max(1);
+        let sign = denominator.signum();
+        Some(Self {
+            numerator: sign.checked_mul(numerator / divisor)?,
+            denominator: sign.checked_mul(denominator / divisor)?,
+        })
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        let a = self.numerator.checked_mul(other.denominator)?;
+        let b = other.numerator.checked_mul(self.denominator)?;
+        let denominator = self.denominator.checked_mul(other.denominator)?;
+        Self::new(a.checked_add(b)?, denominator)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        let numerator = self.numerator.checked_mul(other.numerator)?;
+        let denominator = self.denominator.checked_mul(other.denominator)?;
+        Self::new(numerator, denominator)
+    }
+
+    fn checked_div(self, other: Self) -> Option<Self> {
+        let numerator = self.numerator.checked_mul(other.denominator)?;
+        let denominator = self.denominator.checked_mul(other.numerator)?;
+        Self::new(numerator, denominator)
+    }
+
+    fn checked_neg(self) -> Option<Self> {
+        Self::new(self.numerator.checked_neg()?, self.denominator)
+    }
+
+    pub(crate) fn to_minor_units(self, minor_units: u32) -> Option<i64> {
+        let scale = 10_i128.checked_pow(minor_units)?;
+        round_half_even(self.numerator.checked_mul(scale)?, self.denominator)
+    }
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    i128::try_from(a).unwrap_or(1)
+}
+
+pub(crate) fn is_expression(string: &str) -> bool {
+    string.chars().any(|c| "+-*/()".contains(c))
+}
+
+pub(crate) fn evaluate(expression: &str, locale: Locale) -> Option<Rational> {
+    let mut parser = Parser::new(expression, locale);
+    let value = parser.expression()?;
+    parser.skip_whitespace();
+    match parser.chars.next() {
+        Some(_) => None,
+        None => Some(value),
+    }
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    grouping_separator: char,
+    decimal_separator: char,
+}
+
+impl<'a> Parser<'a> {
+    fn new(expression: &'a str, locale: Locale) -> Self {
+        let number_format = locale.number_format();
+        Self {
+            chars: expression.chars().peekable(),
+            grouping_separator: number_format.grouping_separator,
+            decimal_separator: number_format.decimal_separator,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    fn next_operator(&mut self, operators: &str) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.next_if(|c| operators.contains(*c))
+    }
+
+    fn expression(&mut self) -> Option<Rational> {
+        let mut value = self.term()?;
+        while let Some(operator) = self.next_operator("+-") {
+            let term = self.term()?;
+            value = match operator {
+                '+' => value.checked_add(term)?,
+                _ => value.checked_sub(term)?,
+            };
+        }
+        Some(value)
+    }
+
+    fn term(&mut self) -> Option<Rational> {
+        let mut value = self.factor()?;
+        while let Some(operator) = self.next_operator("*/") {
+            let factor = self.factor()?;
+            value = match operator {
+                '*' => value.checked_mul(factor)?,
+                _ => value.checked_div(factor)?,
+            };
+        }
+        Some(value)
+    }
+
+    fn factor(&mut self) -> Option<Rational> {
+        self.skip_whitespace();
+        match self.chars.peek()? {
+            '-' => {
+                self.chars.next();
+                self.factor()?.checked_neg()
+            },
+            '+' => {
+                self.chars.next();
+                self.factor()
+            },
+            '(' => {
+                self.chars.next();
+                let value = self.expression()?;
+                self.next_operator(")")?;
+                Some(value)
+            },
+            _ => self.number(),
+        }
+    }
+
+    fn is_decimal_separator(&self, c: char) -> bool {
+        c == self.decimal_separator
+            || (c == '.' && self.grouping_separator != '.')
+    }
+
+    fn number(&mut self) -> Option<Rational> {
+        let mut numerator: i128 = 0;
+        let mut denominator: i128 = 1;
+        let mut has_digits = false;
+        let mut in_fraction = false;
+        while let Some(&c) = self.chars.peek() {
+            if let Some(digit) = c.to_digit(10) {
+                numerator = numerator
+                    .checked_mul(10)?
+                    .checked_add(i128::from(digit))?;
+                if in_fraction {
+                    denominator = denominator.checked_mul(10)?;
+                }
+                has_digits = true;
+            } else if self.is_decimal_separator(c) && !in_fraction {
+                in_fraction = true;
+            } else if c != self.grouping_separator || in_fraction {
+                break;
+            }
+            self.chars.next();
+        }
+
+        match has_digits {
+            true => Rational::new(numerator, denominator),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        evaluate,
+        is_expression,
+        Rational,
+    };
+    use crate::types::locale::Locale;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn to_cents(expression: &str, locale: Locale) -> Option<i64> {
+        evaluate(expression, locale).and_then(|r| r.to_minor_units(2))
+    }
+
+    #[wasm_bindgen_test]
+    fn is_expression_works() {
+        let tests = vec![
+            ("45.20+12.80", true),
+            ("3*4", true),
+            ("(1)", true),
+            ("-5", true),
+            ("10/3", true),
+            ("12,349.00", false),
+            ("1234", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(is_expression(input), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn rational_is_reduced_with_positive_denominator() {
+        let tests = vec![
+            ((2, 4), Some((1, 2))),
+            ((-2, 4), Some((-1, 2))),
+            ((2, -4), Some((-1, 2))),
+            ((0, 5), Some((0, 1))),
+            ((1, 0), None),
+        ];
+
+        for ((numerator, denominator), expected) in tests {
+            let rational = Rational::new(numerator, denominator)
+                .map(|r| (r.numerator, r.denominator));
+            assert_eq!(rational, expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn evaluate_works() {
+        let tests = vec![
+            ("45.20+12.80", Some(5800)),
+            ("45.2 + 12.8", Some(5800)),
+            ("100-0.01", Some(9999)),
+            ("2*3.5", Some(700)),
+            ("10/4", Some(250)),
+            ("1+2*3", Some(700)),
+            ("(1+2)*3", Some(900)),
+            ("((1+2))*(3)", Some(900)),
+            ("-5+10", Some(500)),
+            ("-(5+10)", Some(-1500)),
+            ("1,234.50+0.50", Some(123500)),
+            ("3", Some(300)),
+            ("", None),
+            ("1+", None),
+            ("(1+2", None),
+            ("1+2)", None),
+            ("1..2", None),
+            ("1/0", None),
+            ("abc", None),
+            ("5 €", None),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(to_cents(input, Locale::EnUs), expected, "{}", input);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn evaluate_rounds_half_to_even() {
+        let tests = vec![
+            ("0.125+0", Some(12)),
+            ("0.135+0", Some(14)),
+            ("1/8", Some(12)),
+            ("-1/8", Some(-12)),
+            ("10/3", Some(333)),
+            ("20/3", Some(667)),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(to_cents(input, Locale::EnUs), expected, "{}", input);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn evaluate_follows_locale_separators() {
+        let tests = vec![
+            ("45,20+12,80", Locale::FiFi, Some(5800)),
+            ("45.20+12.80", Locale::FiFi, Some(5800)),
+            ("1\u{a0}234,50+0,50", Locale::FiFi, Some(123500)),
+            ("1.234,50+0,50", Locale::PtBr, Some(123500)),
+            ("45,20+12,80", Locale::EnUs, Some(580000)),
+        ];
+
+        for (input, locale, expected) in tests {
+            assert_eq!(to_cents(input, locale), expected, "{}", input);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn evaluate_rejects_overflow_beyond_i64() {
+        let tests = vec![
+            ("92233720368547758.07+0", Some(i64::MAX)),
+            ("92233720368547758.07+0.01", None),
+            ("-92233720368547758.08+0", Some(i64::MIN)),
+            ("99999999999999999999*99999999999999999999", None),
+            ("999999999999999999999999999999999999999999", None),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(to_cents(input, Locale::EnUs), expected, "{}", input);
+        }
+    }
+}
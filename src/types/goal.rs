@@ -0,0 +1,175 @@
+use chrono::{
+    Datelike,
+    NaiveDate,
+};
+
+use crate::types::{
+    account::{
+        balance,
+        Account,
+    },
+    entry::Entry,
+    exchange_rate::ExchangeRate,
+    money::Money,
+    select::SelectOption,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Goal {
+    pub(crate) name: String,
+    pub(crate) target: Money,
+    pub(crate) target_date: NaiveDate,
+}
+
+impl Goal {
+    pub(crate) fn saved(
+        &self,
+        entries: &[Entry],
+        rates: &[ExchangeRate],
+    ) -> Option<Money> {
+        let piggy_bank = Account {
+            name: self.name.clone(),
+            opening_balance: Money::zero(self.target.currency),
+        };
+        balance(&piggy_bank, entries, rates)
+    }
+
+    pub(crate) fn monthly_contribution(
+        &self,
+        saved: Money,
+        today: NaiveDate,
+    ) -> Option<Money> {
+        let remaining = self.target.checked_sub(saved)?;
+        if remaining.minor <= 0 {
+            return Some(Money::zero(self.target.currency));
+        }
+        let months = i64::from(months_left(today, self.target_date));
+        if months == 0 {
+            return None;
+        }
+        let minor = remaining.minor.checked_add(months - 1)? / months;
+        Some(Money::new(minor, self.target.currency))
+    }
+}
+
+impl From<&Goal> for SelectOption {
+    fn from(value: &Goal) -> Self {
+        SelectOption::from(&value.name)
+    }
+}
+
+pub(crate) fn months_left(today: NaiveDate, target_date: NaiveDate) -> u32 {
+    if target_date < today {
+        return 0;
+    }
+    let years = (target_date.year() - today.year()) as u32;
+    years * 12 + target_date.month() + 1 - today.month()
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{
+        FixedOffset,
+        NaiveDate,
+        TimeZone,
+    };
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        months_left,
+        Goal,
+    };
+    use crate::types::{
+        currency::Currency,
+        entry::{
+            Entry,
+            EntryKind,
+        },
+        money::{
+            eur,
+            Money,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn make_date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn make_goal(target: i64) -> Goal {
+        Goal {
+            name: String::from("Bicycle"),
+            target: eur(target),
+            target_date: make_date(2024, 5, 31),
+        }
+    }
+
+    fn make_entry(account: &str, kind: EntryKind, minor: i64) -> Entry {
+        Entry::fixture(
+            FixedOffset::east_opt(0)
+                .unwrap()
+                .with_ymd_and_hms(2023, 6, 1, 12, 0, 0)
+                .unwrap(),
+            eur(minor),
+        )
+        .with_account(account)
+        .with_kind(kind)
+    }
+
+    #[wasm_bindgen_test]
+    fn saved_sums_contributions_and_withdrawals() {
+        let to_goal = |goal: &str| EntryKind::Contribution {
+            goal: String::from(goal),
+        };
+        let entries = vec![
+            make_entry("Checking", to_goal("Bicycle"), 20000),
+            make_entry("Cash", to_goal("Bicycle"), 5000),
+            make_entry("Cash", to_goal("Holiday"), 7000),
+            make_entry("Bicycle", EntryKind::Expense, 3000),
+            make_entry("Checking", EntryKind::Expense, 9900),
+        ];
+
+        assert_eq!(make_goal(100000).saved(&entries, &[]), Some(eur(22000)));
+        assert_eq!(make_goal(100000).saved(&[], &[]), Some(eur(0)));
+    }
+
+    #[wasm_bindgen_test]
+    fn months_left_counts_current_and_target_month() {
+        let tests = vec![
+            (make_date(2023, 6, 15), make_date(2024, 5, 31), 12),
+            (make_date(2023, 6, 15), make_date(2023, 6, 30), 1),
+            (make_date(2023, 12, 1), make_date(2024, 1, 1), 2),
+            (make_date(2023, 6, 15), make_date(2023, 6, 14), 0),
+        ];
+
+        for (today, target_date, expected) in tests {
+            assert_eq!(months_left(today, target_date), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn monthly_contribution_rounds_up() {
+        let today = make_date(2023, 6, 15);
+        let tests = vec![
+            (make_goal(120000), eur(0), Some(eur(10000))),
+            (make_goal(100000), eur(0), Some(eur(8334))),
+            (make_goal(100000), eur(100000), Some(eur(0))),
+            (make_goal(100000), eur(150000), Some(eur(0))),
+            (make_goal(100000), Money::zero(Currency::Usd), None),
+        ];
+
+        for (goal, saved, expected) in tests {
+            assert_eq!(goal.monthly_contribution(saved, today), expected);
+        }
+
+        let overdue = make_date(2024, 6, 1);
+        assert_eq!(
+            make_goal(100000).monthly_contribution(eur(0), overdue),
+            None
+        );
+    }
+}
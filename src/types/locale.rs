@@ -0,0 +1,189 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub(crate) enum Locale {
+    #[default]
+    EnUs,
+    EnGb,
+    EnIn,
+    FiFi,
+    SvSe,
+    DeDe,
+    FrFr,
+    PtBr,
+    JaJp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct NumberFormat {
+    pub(crate) grouping_separator: char,
+    pub(crate) decimal_separator: char,
+    pub(crate) primary_grouping: usize,
+    pub(crate) secondary_grouping: usize,
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EnUs => write!(f, "en-US"),
+            Self::EnGb => write!(f, "en-GB"),
+            Self::EnIn => write!(f, "en-IN"),
+            Self::FiFi => write!(f, "fi-FI"),
+            Self::SvSe => write!(f, "sv-SE"),
+            Self::DeDe => write!(f, "de-DE"),
+            Self::FrFr => write!(f, "fr-FR"),
+            Self::PtBr => write!(f, "pt-BR"),
+            Self::JaJp => write!(f, "ja-JP"),
+        }
+    }
+}
+
+impl Locale {
+    pub(crate) fn from_tag(tag: &str) -> Option<Self> {
+        let tag = tag.replace('_', "-").to_lowercase();
+        let language = tag.split('-').next().unwrap_or_default();
+        match (tag.as_str(), language) {
+            ("en-us", _) => Some(Self::EnUs),
+            ("en-gb", _) => Some(Self::EnGb),
+            ("en-in", _) => Some(Self::EnIn),
+            (_, "en") => Some(Self::EnUs),
+            (_, "fi") => Some(Self::FiFi),
+            (_, "sv") => Some(Self::SvSe),
+            (_, "de") => Some(Self::DeDe),
+            (_, "fr") => Some(Self::FrFr),
+            (_, "pt") => Some(Self::PtBr),
+            (_, "ja") => Some(Self::JaJp),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn from_browser() -> Self {
+        web_sys::window()
+            .and_then(|window| window.navigator().language())
+            .and_then(|tag| Self::from_tag(&tag))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn number_format(&self) -> NumberFormat {
+        let (grouping_separator, decimal_separator) = match self {
+            Self::EnUs | Self::EnGb | Self::EnIn | Self::JaJp => (',', '.'),
+            Self::FiFi | Self::SvSe => ('\u{a0}', ','),
+            Self::FrFr => ('\u{202f}', ','),
+            Self::DeDe | Self::PtBr => ('.', ','),
+        };
+        let secondary_grouping = match self {
+            Self::EnIn => 2,
+            _ => 3,
+        };
+        NumberFormat {
+            grouping_separator,
+            decimal_separator,
+            primary_grouping: 3,
+            secondary_grouping,
+        }
+    }
+}
+
+impl NumberFormat {
+    pub(crate) fn group_digits(&self, string: &str) -> String {
+        let chars: Vec<char> = string.chars().collect();
+        if chars.len() <= self.primary_grouping {
+            return string.to_string();
+        }
+
+        let (head, tail) = chars.split_at(chars.len() - self.primary_grouping);
+        let mut groups: Vec<String> = head
+            .rchunks(self.secondary_grouping)
+            .map(|chunk| chunk.iter().collect())
+            .collect();
+        groups.reverse();
+        groups.push(tail.iter().collect());
+        groups.join(&self.grouping_separator.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::Locale;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn locale_can_be_constructed_from_language_tag() {
+        let tests = vec![
+            ("en-US", Some(Locale::EnUs)),
+            ("en-GB", Some(Locale::EnGb)),
+            ("en_IN", Some(Locale::EnIn)),
+            ("en-AU", Some(Locale::EnUs)),
+            ("en", Some(Locale::EnUs)),
+            ("fi-FI", Some(Locale::FiFi)),
+            ("fi", Some(Locale::FiFi)),
+            ("pt-BR", Some(Locale::PtBr)),
+            ("pt-PT", Some(Locale::PtBr)),
+            ("de-AT", Some(Locale::DeDe)),
+            ("FR-fr", Some(Locale::FrFr)),
+            ("zz", None),
+            ("", None),
+        ];
+
+        for (tag, expected) in tests {
+            assert_eq!(Locale::from_tag(tag), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn locale_is_displayed_as_language_tag() {
+        let locales = vec![
+            Locale::EnUs,
+            Locale::EnGb,
+            Locale::EnIn,
+            Locale::FiFi,
+            Locale::SvSe,
+            Locale::DeDe,
+            Locale::FrFr,
+            Locale::PtBr,
+            Locale::JaJp,
+        ];
+
+        for locale in locales {
+            assert_eq!(Locale::from_tag(&locale.to_string()), Some(locale));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn group_digits_works() {
+        let tests = vec![
+            (Locale::EnUs, "", ""),
+            (Locale::EnUs, "123", "123"),
+            (Locale::EnUs, "1234", "1,234"),
+            (Locale::EnUs, "12345", "12,345"),
+            (Locale::EnUs, "dkasmdsakjsdkjas", "d,kas,mds,akj,sdk,jas"),
+            (
+                Locale::EnUs,
+                "999999999999999999999999",
+                "999,999,999,999,999,999,999,999",
+            ),
+            (
+                Locale::EnUs,
+                "9999999999999999999999999",
+                "9,999,999,999,999,999,999,999,999",
+            ),
+            (Locale::FiFi, "12349", "12\u{a0}349"),
+            (Locale::PtBr, "12349", "12.349"),
+            (Locale::PtBr, "1234567", "1.234.567"),
+            (Locale::FrFr, "1234567", "1\u{202f}234\u{202f}567"),
+            (Locale::EnIn, "123", "123"),
+            (Locale::EnIn, "1234", "1,234"),
+            (Locale::EnIn, "123456", "1,23,456"),
+            (Locale::EnIn, "1234567", "12,34,567"),
+            (Locale::EnIn, "123456789", "12,34,56,789"),
+        ];
+
+        for (locale, input, expected) in tests {
+            assert_eq!(locale.number_format().group_digits(input), expected);
+        }
+    }
+}
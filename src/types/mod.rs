@@ -1,2 +1,18 @@
+pub(crate) mod account;
+pub(crate) mod budget;
+pub(crate) mod categorization;
+pub(crate) mod category;
+pub(crate) mod currency;
 pub(crate) mod datetime;
+pub(crate) mod entry;
+pub(crate) mod exchange_rate;
+pub(crate) mod expression;
+pub(crate) mod goal;
+pub(crate) mod locale;
+pub(crate) mod money;
+pub(crate) mod report;
 pub(crate) mod select;
+pub(crate) mod settings;
+pub(crate) mod suggestion;
+pub(crate) mod summary;
+pub(crate) mod validation;
@@ -0,0 +1,502 @@
+use crate::types::{
+    currency::Currency,
+    expression,
+    locale::Locale,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Money {
+    pub(crate) minor: i64,
+    pub(crate) currency: Currency,
+}
+
+impl Money {
+    pub(crate) fn new(minor: i64, currency: Currency) -> Self {
+        Self { minor, currency }
+    }
+
+    pub(crate) fn zero(currency: Currency) -> Self {
+        Self::new(0, currency)
+    }
+
+    pub(crate) fn checked_add(self, other: Self) -> Option<Self> {
+        if self.currency != other.currency {
+            return None;
+        }
+
+        let minor = self.minor.checked_add(other.minor)?;
+        Some(Self::new(minor, self.currency))
+    }
+
+    pub(crate) fn checked_sub(self, other: Self) -> Option<Self> {
+        if self.currency != other.currency {
+            return None;
+        }
+
+        let minor = self.minor.checked_sub(other.minor)?;
+        Some(Self::new(minor, self.currency))
+    }
+
+    pub(crate) fn checked_neg(self) -> Option<Self> {
+        let minor = self.minor.checked_neg()?;
+        Some(Self::new(minor, self.currency))
+    }
+
+    pub(crate) fn checked_sum<I>(iter: I, currency: Currency) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        iter.into_iter()
+            .try_fold(Self::zero(currency), |sum, money| sum.checked_add(money))
+    }
+
+    pub(crate) fn from_input(string: &str, currency: Currency) -> Option<Self> {
+        match filter_digits(string) {
+            s if s.is_empty() => None,
+            s => truncate_to_valid_i64(&s)
+                .parse::<i64>()
+                .ok()
+                .map(|minor| Self::new(minor, currency)),
+        }
+    }
+
+    pub(crate) fn from_expression(
+        string: &str,
+        currency: Currency,
+        locale: Locale,
+    ) -> Option<Self> {
+        expression::evaluate(string, locale)?
+            .to_minor_units(currency.minor_units())
+            .map(|minor| Self::new(minor, currency))
+    }
+
+    pub(crate) fn from_pasted(
+        string: &str,
+        currency: Currency,
+        locale: Locale,
+    ) -> Option<Self> {
+        // Only a minus before the first digit is a sign, any other operator
+        // makes the text an expression
+        let (sign, string) = match string.find(|c: char| c.is_ascii_digit()) {
+            Some(index) => match string[..index].rsplit_once('-') {
+                Some((prefix, _)) if !prefix.contains('-') => {
+                    (-1, &string[prefix.len() + 1..])
+                },
+                _ => (1, string),
+            },
+            None => (1, string),
+        };
+        if expression::is_expression(string) {
+            return None;
+        }
+
+        let number = string
+            .chars()
+            .filter(|c| c.is_ascii_digit() || *c == '.' || *c == ',')
+            .collect::<String>();
+        let number = number.trim_matches(|c| c == '.' || c == ',');
+        if number.is_empty() {
+            return None;
+        }
+
+        let (units, fraction) =
+            match find_decimal_separator(number, currency, locale) {
+                Some(index) => (&number[..index], &number[index + 1..]),
+                None => (number, ""),
+            };
+        let numerator = format!("{}{}", filter_digits(units), fraction)
+            .parse::<i128>()
+            .ok()?;
+        let denominator = 10_i128.checked_pow(fraction.len() as u32)?;
+        let scale = 10_i128.checked_pow(currency.minor_units())?;
+        round_half_even(numerator.checked_mul(scale)? * sign, denominator)
+            .map(|minor| Self::new(minor, currency))
+    }
+
+    pub(crate) fn format(&self, locale: Locale) -> String {
+        let number_format = locale.number_format();
+        let sign = if self.minor < 0 { "-" } else { "" };
+        let decimals = self.currency.minor_units() as usize;
+        let digits = format!(
+            "{:0>width$}",
+            self.minor.unsigned_abs(),
+            width = decimals + 1
+        );
+        let (units, fraction) = digits.split_at(digits.len() - decimals);
+        let units = number_format.group_digits(units);
+
+        match decimals {
+            0 => format!("{}{}", sign, units),
+            _ => format!(
+                "{}{}{}{}",
+                sign, units, number_format.decimal_separator, fraction
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format(Locale::default()))
+    }
+}
+
+pub(crate) fn round_half_even(
+    numerator: i128,
+    denominator: i128,
+) -> Option<i64> {
+    let quotient = numerator.checked_div(denominator)?;
+    let remainder = numerator.checked_rem(denominator)?;
+    let sign = numerator.signum() * denominator.signum();
+    let quotient =
+        match (remainder.unsigned_abs() * 2).cmp(&denominator.unsigned_abs()) {
+            std::cmp::Ordering::Less => quotient,
+            std::cmp::Ordering::Greater => quotient.checked_add(sign)?,
+            std::cmp::Ordering::Equal if quotient % 2 == 0 => quotient,
+            std::cmp::Ordering::Equal => quotient.checked_add(sign)?,
+        };
+    i64::try_from(quotient).ok()
+}
+
+#[cfg(test)]
+pub(crate) fn eur(minor: i64) -> Money {
+    Money::new(minor, Currency::Eur)
+}
+
+fn find_decimal_separator(
+    number: &str,
+    currency: Currency,
+    locale: Locale,
+) -> Option<usize> {
+    let index = number.rfind(['.', ','])?;
+    let separator = number[index..].chars().next()?;
+    if number.matches(separator).count() > 1 {
+        return None;
+    }
+
+    let has_grouping = number[..index].contains(['.', ',']);
+    let fraction_digits = number.len() - index - 1;
+    let is_decimal = match (has_grouping, fraction_digits) {
+        (true, _) => true,
+        (false, 3) if number.starts_with('0') => true,
+        (false, 3) => {
+            currency.minor_units() == 3
+                && separator != locale.number_format().grouping_separator
+        },
+        (false, _) => true,
+    };
+    match is_decimal {
+        true => Some(index),
+        false => None,
+    }
+}
+
+fn truncate_to_valid_i64(string: &str) -> String {
+    let mut string = match filter_digits(string).trim_start_matches('0') {
+        "" => String::from("0"),
+        s => s.to_owned(),
+    };
+    let max = i64::MAX.to_string();
+    string.truncate(max.len());
+    while string.parse::<i64>().is_err() {
+        string.truncate(string.len() - 1);
+    }
+    string
+}
+
+fn filter_digits(string: &str) -> String {
+    string
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .collect::<String>()
+}
+
+#[cfg(test)]
+mod test {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        eur,
+        filter_digits,
+        round_half_even,
+        truncate_to_valid_i64,
+        Money,
+    };
+    use crate::types::{
+        currency::Currency,
+        locale::Locale,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn filter_digits_works() {
+        let tests = vec![
+            ("092019828832l", "092019828832"),
+            ("232jhdsa878dsa", "232878"),
+            ("+242jhdsa878ds", "242878"),
+            ("2f", "2"),
+            ("-", ""),
+            ("三", ""),
+            ("1¾", "1"),
+            ("8.34", "834"),
+            ("128.97 €", "12897"),
+        ];
+
+        for case in tests {
+            assert_eq!(&filter_digits(case.0), case.1);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn truncate_to_valid_i64_works() {
+        let tests = vec![
+            ("9223372036854775807", "9223372036854775807"),
+            ("9223372036854775808", "922337203685477580"),
+            ("999999999999999999", "999999999999999999"),
+            ("9999999999999999999", "999999999999999999"),
+            (
+                "9999999999999999999999999999999999999999999999999999",
+                "999999999999999999",
+            ),
+        ];
+
+        for case in tests {
+            assert_eq!(&truncate_to_valid_i64(case.0), case.1);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn round_half_even_works() {
+        let tests = vec![
+            (10, 4, Some(2)),
+            (14, 4, Some(4)),
+            (15, 10, Some(2)),
+            (25, 10, Some(2)),
+            (26, 10, Some(3)),
+            (24, 10, Some(2)),
+            (-15, 10, Some(-2)),
+            (-25, 10, Some(-2)),
+            (-26, 10, Some(-3)),
+            (25, -10, Some(-2)),
+            (0, 7, Some(0)),
+            (1, 0, None),
+            (i64::MAX as i128 * 2, 1, None),
+        ];
+
+        for (numerator, denominator, expected) in tests {
+            assert_eq!(round_half_even(numerator, denominator), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn checked_add_works() {
+        let tests = vec![
+            (eur(0), eur(0), Some(eur(0))),
+            (eur(1250), eur(-250), Some(eur(1000))),
+            (eur(i64::MAX), eur(0), Some(eur(i64::MAX))),
+            (eur(i64::MAX), eur(1), None),
+            (eur(i64::MIN), eur(-1), None),
+        ];
+
+        for (a, b, expected) in tests {
+            assert_eq!(a.checked_add(b), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn checked_sub_works() {
+        let tests = vec![
+            (eur(0), eur(0), Some(eur(0))),
+            (eur(1250), eur(250), Some(eur(1000))),
+            (eur(250), eur(1250), Some(eur(-1000))),
+            (eur(i64::MIN), eur(1), None),
+            (eur(i64::MAX), eur(-1), None),
+        ];
+
+        for (a, b, expected) in tests {
+            assert_eq!(a.checked_sub(b), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn checked_neg_works() {
+        let tests = vec![
+            (eur(0), Some(eur(0))),
+            (eur(1250), Some(eur(-1250))),
+            (eur(-1250), Some(eur(1250))),
+            (eur(i64::MAX), Some(eur(-i64::MAX))),
+            (eur(i64::MIN), None),
+        ];
+
+        for (a, expected) in tests {
+            assert_eq!(a.checked_neg(), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn checked_sum_works() {
+        let tests = vec![
+            (vec![], Some(eur(0))),
+            (vec![eur(10), eur(20), eur(-5)], Some(eur(25))),
+            (vec![eur(i64::MAX), eur(1), eur(-1)], None),
+        ];
+
+        for (list, expected) in tests {
+            assert_eq!(Money::checked_sum(list, Currency::Eur), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn from_input_works() {
+        let tests = vec![
+            ("", None),
+            ("-", None),
+            ("jdsjakhsd", None),
+            ("0", Some(eur(0))),
+            ("0.00", Some(eur(0))),
+            ("12", Some(eur(12))),
+            ("12,349.00", Some(eur(1234900))),
+            ("fas0001s234juhda9", Some(eur(12349))),
+            ("9223372036854775807", Some(eur(i64::MAX))),
+            ("9223372036854775808", Some(eur(922337203685477580))),
+        ];
+
+        for case in tests {
+            assert_eq!(Money::from_input(case.0, Currency::Eur), case.1);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn from_expression_works() {
+        let tests = vec![
+            ("45.20+12.80", Currency::Eur, Some(eur(5800))),
+            ("45.20+12.80", Currency::Jpy, Some(Money::new(58, Currency::Jpy))),
+            ("1/3", Currency::Bhd, Some(Money::new(333, Currency::Bhd))),
+            ("1/0", Currency::Eur, None),
+            ("92233720368547758.07+0.01", Currency::Eur, None),
+        ];
+
+        for (input, currency, expected) in tests {
+            let money = Money::from_expression(input, currency, Locale::EnUs);
+            assert_eq!(money, expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn from_pasted_detects_decimal_separator() {
+        let tests = vec![
+            ("12.5", Some(eur(1250))),
+            ("12,5", Some(eur(1250))),
+            ("12.", Some(eur(1200))),
+            ("1.234,56 €", Some(eur(123456))),
+            ("€12.50", Some(eur(1250))),
+            ("1,234.56 USD", Some(eur(123456))),
+            ("12 349,00", Some(eur(1234900))),
+            ("12\u{a0}349,00 €", Some(eur(1234900))),
+            ("1'234.50", Some(eur(123450))),
+            ("1.234", Some(eur(123400))),
+            ("1,234,567", Some(eur(123456700))),
+            ("1.234.567,8", Some(eur(123456780))),
+            ("0.125", Some(eur(12))),
+            ("0.135", Some(eur(14))),
+            ("92233720368547758.07", Some(eur(i64::MAX))),
+            ("92233720368547758.08", None),
+            ("-12.50", Some(eur(-1250))),
+            ("-€12.50", Some(eur(-1250))),
+            ("€ -1.234,56", Some(eur(-123456))),
+            ("-0.135", Some(eur(-14))),
+            ("-92233720368547758.08", Some(eur(i64::MIN))),
+            ("--12.50", None),
+            ("12.50-", None),
+            ("", None),
+            ("€", None),
+            ("-", None),
+            ("45.20+12.80", None),
+            ("-45.20+12.80", None),
+        ];
+
+        for (input, expected) in tests {
+            let money = Money::from_pasted(input, Currency::Eur, Locale::EnUs);
+            assert_eq!(money, expected, "{}", input);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn from_pasted_follows_currency_and_locale() {
+        let tests = vec![
+            ("1.234", Currency::Jpy, Locale::EnUs, Some(1234)),
+            ("¥1,234", Currency::Jpy, Locale::EnUs, Some(1234)),
+            ("12.5", Currency::Jpy, Locale::EnUs, Some(12)),
+            ("12.345", Currency::Bhd, Locale::EnUs, Some(12345)),
+            ("1,234", Currency::Bhd, Locale::EnUs, Some(1234000)),
+            ("1.234", Currency::Bhd, Locale::DeDe, Some(1234000)),
+            ("1,234", Currency::Bhd, Locale::DeDe, Some(1234)),
+            ("R$ 1.234,56", Currency::Brl, Locale::PtBr, Some(123456)),
+        ];
+
+        for (input, currency, locale, expected) in tests {
+            let money = Money::from_pasted(input, currency, locale);
+            assert_eq!(money, expected.map(|m| Money::new(m, currency)));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn display_works() {
+        let tests = vec![
+            (eur(0), "0.00"),
+            (eur(1), "0.01"),
+            (eur(12), "0.12"),
+            (eur(128), "1.28"),
+            (eur(128900), "1,289.00"),
+            (eur(-128900123), "-1,289,001.23"),
+            (eur(i64::MAX), "92,233,720,368,547,758.07"),
+            (eur(i64::MIN), "-92,233,720,368,547,758.08"),
+        ];
+
+        for (money, expected) in tests {
+            assert_eq!(money.to_string(), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn display_follows_currency_minor_units() {
+        let tests = vec![
+            (Money::new(0, Currency::Jpy), "0"),
+            (Money::new(1234, Currency::Jpy), "1,234"),
+            (Money::new(-1234, Currency::Jpy), "-1,234"),
+            (Money::new(0, Currency::Bhd), "0.000"),
+            (Money::new(1, Currency::Bhd), "0.001"),
+            (Money::new(1234567, Currency::Bhd), "1,234.567"),
+        ];
+
+        for (money, expected) in tests {
+            assert_eq!(money.to_string(), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn format_follows_locale_separators() {
+        let tests = vec![
+            (eur(1234900), Locale::EnUs, "12,349.00"),
+            (eur(1234900), Locale::FiFi, "12\u{a0}349,00"),
+            (eur(1234900), Locale::PtBr, "12.349,00"),
+            (eur(1234900), Locale::DeDe, "12.349,00"),
+            (eur(-1234900), Locale::FrFr, "-12\u{202f}349,00"),
+            (eur(123456789), Locale::EnIn, "12,34,567.89"),
+            (Money::new(1234567, Currency::Jpy), Locale::PtBr, "1.234.567"),
+            (
+                Money::new(1234567, Currency::Bhd),
+                Locale::FiFi,
+                "1\u{a0}234,567",
+            ),
+        ];
+
+        for (money, locale, expected) in tests {
+            assert_eq!(money.format(locale), expected);
+        }
+    }
+}
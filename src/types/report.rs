@@ -0,0 +1,457 @@
+use std::collections::BTreeSet;
+
+use chrono::TimeZone;
+
+use crate::types::{
+    category::{
+        is_in_category,
+        totals_by_category,
+        PATH_SEPARATOR,
+    },
+    currency::Currency,
+    datetime::{
+        DateTimeRange,
+        Month,
+        Year,
+    },
+    entry::Entry,
+    exchange_rate::ExchangeRate,
+    money::{
+        round_half_even,
+        Money,
+    },
+    summary::{
+        entries_in,
+        percent_change,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CategoryReport {
+    pub(crate) category: String,
+    pub(crate) total: Money,
+    pub(crate) monthly_average: Money,
+    pub(crate) percent: i64,
+}
+
+pub(crate) type MonthlyReport = ((Year, Month), Vec<CategoryReport>);
+
+impl CategoryReport {
+    pub(crate) fn is_top_level(&self) -> bool {
+        !self.category.contains(PATH_SEPARATOR)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CategoryChange {
+    pub(crate) category: String,
+    pub(crate) current: Money,
+    pub(crate) previous: Money,
+}
+
+impl CategoryChange {
+    pub(crate) fn difference(&self) -> Option<Money> {
+        self.current.checked_sub(self.previous)
+    }
+
+    pub(crate) fn percent(&self) -> Option<i64> {
+        percent_change(self.current, self.previous)
+    }
+
+    pub(crate) fn is_top_level(&self) -> bool {
+        !self.category.contains(PATH_SEPARATOR)
+    }
+}
+
+pub(crate) fn monthly_average(total: Money, months: usize) -> Option<Money> {
+    let months = i128::try_from(months).ok()?;
+    let minor = round_half_even(i128::from(total.minor), months)?;
+    Some(Money::new(minor, total.currency))
+}
+
+pub(crate) fn category_report(
+    entries: &[Entry],
+    currency: Currency,
+    rates: &[ExchangeRate],
+    months: usize,
+) -> Option<Vec<CategoryReport>> {
+    let totals = totals_by_category(entries, currency, rates)?;
+    let grand_total: i128 = totals
+        .iter()
+        .filter(|(path, _)| !path.contains(PATH_SEPARATOR))
+        .map(|(_, total)| i128::from(total.minor))
+        .sum();
+
+    totals
+        .into_iter()
+        .map(|(category, total)| {
+            let percent = i128::from(total.minor) * 100;
+            Some(CategoryReport {
+                category,
+                total,
+                monthly_average: monthly_average(total, months.max(1))?,
+                percent: round_half_even(percent, grand_total).unwrap_or(0),
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn monthly_reports<T: TimeZone>(
+    entries: &[Entry],
+    range: &DateTimeRange<T>,
+    currency: Currency,
+    rates: &[ExchangeRate],
+) -> Option<Vec<MonthlyReport>> {
+    let timezone = range.start.timezone();
+    let entries = entries_in(entries, range);
+    range
+        .list_months()
+        .into_iter()
+        .map(|(year, month)| {
+            let month_range = DateTimeRange::month(&timezone, year, month)?;
+            let report = category_report(
+                &entries_in(&entries, &month_range),
+                currency,
+                rates,
+                1,
+            )?;
+            Some(((year, month), report))
+        })
+        .collect()
+}
+
+pub(crate) fn compare_categories(
+    current: &[Entry],
+    previous: &[Entry],
+    currency: Currency,
+    rates: &[ExchangeRate],
+) -> Option<Vec<CategoryChange>> {
+    let current = totals_by_category(current, currency, rates)?;
+    let previous = totals_by_category(previous, currency, rates)?;
+    let zero = Money::zero(currency);
+    let categories: BTreeSet<&String> =
+        current.keys().chain(previous.keys()).collect();
+    Some(
+        categories
+            .into_iter()
+            .map(|category| CategoryChange {
+                category: category.clone(),
+                current: current.get(category).copied().unwrap_or(zero),
+                previous: previous.get(category).copied().unwrap_or(zero),
+            })
+            .collect(),
+    )
+}
+
+pub(crate) fn largest_changes(
+    changes: &[CategoryChange],
+    count: usize,
+) -> (Vec<&CategoryChange>, Vec<&CategoryChange>) {
+    let mut top_level: Vec<(&CategoryChange, i64)> = changes
+        .iter()
+        .filter(|change| change.is_top_level())
+        .filter_map(|change| Some((change, change.difference()?.minor)))
+        .collect();
+    top_level
+        .sort_by(|a, b| b.1.cmp(&a.1).then(a.0.category.cmp(&b.0.category)));
+
+    let increases = top_level
+        .iter()
+        .filter(|(_, difference)| *difference > 0)
+        .take(count)
+        .map(|(change, _)| *change)
+        .collect();
+    let decreases = top_level
+        .iter()
+        .rev()
+        .filter(|(_, difference)| *difference < 0)
+        .take(count)
+        .map(|(change, _)| *change)
+        .collect();
+    (increases, decreases)
+}
+
+pub(crate) fn category_entries<'a>(
+    entries: &'a [Entry],
+    category: &str,
+    currency: Currency,
+    rates: &[ExchangeRate],
+) -> Option<Vec<(&'a Entry, Money)>> {
+    let mut list = Vec::new();
+    for entry in entries.iter().filter(|entry| entry.is_spending()) {
+        let amounts = entry
+            .parts()
+            .into_iter()
+            .filter(|(path, _)| is_in_category(path, category))
+            .map(|(_, amount)| entry.convert(amount, currency, rates))
+            .collect::<Option<Vec<Money>>>()?;
+        if !amounts.is_empty() {
+            list.push((entry, Money::checked_sum(amounts, currency)?));
+        }
+    }
+    list.sort_by_key(|(entry, _)| entry.datetime);
+    Some(list)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{
+        Datelike,
+        NaiveDate,
+        TimeZone,
+        Utc,
+    };
+    use chrono_tz::Tz;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        category_entries,
+        category_report,
+        compare_categories,
+        largest_changes,
+        monthly_average,
+        monthly_reports,
+        CategoryChange,
+        CategoryReport,
+    };
+    use crate::types::{
+        currency::Currency,
+        datetime::{
+            DateTimeRange,
+            Month,
+        },
+        entry::{
+            Entry,
+            EntryKind,
+            EntryLine,
+        },
+        money::{
+            eur,
+            Money,
+        },
+        summary::entries_in,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn make_entry(
+        (month, day, hour): (u32, u32, u32),
+        kind: EntryKind,
+        category: &str,
+        minor: i64,
+    ) -> Entry {
+        Entry::fixture(
+            Utc.with_ymd_and_hms(2023, month, day, hour, 0, 0)
+                .unwrap()
+                .fixed_offset(),
+            eur(minor),
+        )
+        .with_account("Checking")
+        .with_kind(kind)
+        .with_category(category)
+    }
+
+    fn make_entries() -> Vec<Entry> {
+        let mut split = make_entry((6, 10, 12), EntryKind::Expense, "", 3000);
+        split.lines = vec![
+            EntryLine {
+                category: String::from("Food › Lunch"),
+                description: String::new(),
+                amount: eur(1000),
+            },
+            EntryLine {
+                category: String::from("Home"),
+                description: String::new(),
+                amount: eur(2000),
+            },
+        ];
+        vec![
+            make_entry((5, 2, 12), EntryKind::Expense, "Food", 4000),
+            // 2023-06-01 00:00 in Helsinki
+            make_entry((5, 31, 21), EntryKind::Expense, "Food › Lunch", 1000),
+            split,
+            make_entry((6, 11, 12), EntryKind::Income, "Salary", 300000),
+        ]
+    }
+
+    fn make_report(
+        category: &str,
+        total: i64,
+        monthly_average: i64,
+        percent: i64,
+    ) -> CategoryReport {
+        CategoryReport {
+            category: String::from(category),
+            total: eur(total),
+            monthly_average: eur(monthly_average),
+            percent,
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn category_report_has_totals_averages_and_percentages() {
+        let report =
+            category_report(&make_entries(), Currency::Eur, &[], 3).unwrap();
+
+        assert_eq!(
+            report,
+            vec![
+                make_report("Food", 6000, 2000, 75),
+                make_report("Food › Lunch", 2000, 667, 25),
+                make_report("Home", 2000, 667, 25),
+            ]
+        );
+        assert!(report[0].is_top_level());
+        assert!(!report[1].is_top_level());
+        assert_eq!(category_report(&[], Currency::Eur, &[], 0), Some(vec![]));
+        assert_eq!(
+            category_report(&make_entries(), Currency::Usd, &[], 1),
+            None
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn monthly_average_rounds_half_to_even() {
+        let tests = vec![
+            (eur(2000), 3, Some(eur(667))),
+            (eur(1000), 3, Some(eur(333))),
+            (eur(-1000), 3, Some(eur(-333))),
+            (eur(-2000), 3, Some(eur(-667))),
+            (eur(5), 2, Some(eur(2))),
+            (eur(7), 2, Some(eur(4))),
+            (eur(-5), 2, Some(eur(-2))),
+            (eur(1000), 0, None),
+        ];
+
+        for (total, months, expected) in tests {
+            assert_eq!(monthly_average(total, months), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn monthly_reports_split_months_in_timezone() {
+        let timezone = Tz::Europe__Helsinki;
+        let range = DateTimeRange::days(
+            &timezone,
+            NaiveDate::from_ymd_opt(2023, 5, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 6, 30).unwrap(),
+        )
+        .unwrap();
+
+        let reports =
+            monthly_reports(&make_entries(), &range, Currency::Eur, &[])
+                .unwrap();
+
+        assert_eq!(
+            reports,
+            vec![
+                (
+                    (2023, Month::May),
+                    vec![make_report("Food", 4000, 4000, 100)]
+                ),
+                (
+                    (2023, Month::June),
+                    vec![
+                        make_report("Food", 2000, 2000, 50),
+                        make_report("Food › Lunch", 2000, 2000, 50),
+                        make_report("Home", 2000, 2000, 50),
+                    ]
+                ),
+            ]
+        );
+    }
+
+    fn make_change(
+        category: &str,
+        current: i64,
+        previous: i64,
+    ) -> CategoryChange {
+        CategoryChange {
+            category: String::from(category),
+            current: eur(current),
+            previous: eur(previous),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn compare_categories_includes_categories_from_both_periods() {
+        let timezone = Tz::Europe__Helsinki;
+        let entries = make_entries();
+        let period = |month| {
+            let range = DateTimeRange::month(&timezone, 2023, month).unwrap();
+            entries_in(&entries, &range)
+        };
+
+        let changes = compare_categories(
+            &period(Month::June),
+            &period(Month::May),
+            Currency::Eur,
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            changes,
+            vec![
+                make_change("Food", 2000, 4000),
+                make_change("Food › Lunch", 2000, 0),
+                make_change("Home", 2000, 0),
+            ]
+        );
+        assert_eq!(changes[0].difference(), Some(eur(-2000)));
+        assert_eq!(changes[0].percent(), Some(-50));
+        assert_eq!(changes[2].percent(), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn largest_changes_are_top_level_and_ordered_by_size() {
+        let changes = vec![
+            make_change("Food", 2000, 4000),
+            make_change("Food › Lunch", 9000, 0),
+            make_change("Home", 3000, 1000),
+            make_change("Hobbies", 500, 0),
+            make_change("Rent", 90000, 90000),
+            make_change("Transport", 0, 500),
+            make_change("Travel", 0, 9999),
+        ];
+
+        let tests = vec![
+            (2, vec!["Home", "Hobbies"], vec!["Travel", "Food"]),
+            (1, vec!["Home"], vec!["Travel"]),
+            (0, vec![], vec![]),
+        ];
+
+        for (count, expected_increases, expected_decreases) in tests {
+            let (increases, decreases) = largest_changes(&changes, count);
+            let names = |list: Vec<&CategoryChange>| -> Vec<String> {
+                list.iter().map(|change| change.category.clone()).collect()
+            };
+            assert_eq!(names(increases), expected_increases);
+            assert_eq!(names(decreases), expected_decreases);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn category_entries_lists_amounts_in_category() {
+        let entries = make_entries();
+        let tests = vec![
+            ("Food", vec![(2, eur(4000)), (31, eur(1000)), (10, eur(1000))]),
+            ("Food › Lunch", vec![(31, eur(1000)), (10, eur(1000))]),
+            ("Home", vec![(10, eur(2000))]),
+            ("Salary", vec![]),
+        ];
+
+        for (category, expected) in tests {
+            let list: Vec<(u32, Money)> =
+                category_entries(&entries, category, Currency::Eur, &[])
+                    .unwrap()
+                    .into_iter()
+                    .map(|(entry, amount)| (entry.datetime.day(), amount))
+                    .collect();
+            assert_eq!(list, expected, "{}", category);
+        }
+    }
+}
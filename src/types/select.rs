@@ -1,9 +1,14 @@
 use yew::AttrValue;
 
-use crate::types::datetime::{
-    Day,
-    Month,
-    Year,
+use crate::types::{
+    category::PATH_SEPARATOR,
+    currency::Currency,
+    datetime::{
+        Day,
+        Month,
+        Period,
+        Year,
+    },
 };
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -12,6 +17,7 @@ pub(crate) struct SelectOption {
     pub(crate) inner_html: AttrValue,
     pub(crate) selected: bool,
     pub(crate) disabled: bool,
+    pub(crate) group: Option<AttrValue>,
 }
 
 impl SelectOption {
@@ -24,6 +30,20 @@ impl SelectOption {
         self.disabled = disabled;
         self
     }
+
+    pub(crate) fn group(mut self, group: Option<AttrValue>) -> Self {
+        self.group = group;
+        self
+    }
+
+    pub(crate) fn text(&self) -> String {
+        match &self.group {
+            Some(group) => {
+                format!("{}{}{}", group, PATH_SEPARATOR, self.inner_html)
+            },
+            None => self.inner_html.to_string(),
+        }
+    }
 }
 
 impl From<&str> for SelectOption {
@@ -76,6 +96,32 @@ impl From<Day> for SelectOption {
     }
 }
 
+impl From<Period> for SelectOption {
+    fn from(value: Period) -> Self {
+        let key = match value {
+            Period::Month(year, month) => {
+                format!("{}-{:02}", year, month as u32)
+            },
+            Period::Year(year) => year.to_string(),
+        };
+        Self {
+            value: AttrValue::from(key),
+            inner_html: AttrValue::from(value.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Currency> for SelectOption {
+    fn from(value: Currency) -> Self {
+        Self {
+            value: AttrValue::from(value.to_string()),
+            inner_html: AttrValue::from(value.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use wasm_bindgen_test::{
@@ -85,10 +131,14 @@ mod test {
     use yew::AttrValue;
 
     use super::SelectOption;
-    use crate::types::datetime::{
-        Day,
-        Month,
-        Year,
+    use crate::types::{
+        currency::Currency,
+        datetime::{
+            Day,
+            Month,
+            Period,
+            Year,
+        },
     };
 
     wasm_bindgen_test_configure!(run_in_browser);
@@ -111,6 +161,30 @@ mod test {
         }
     }
 
+    #[wasm_bindgen_test]
+    fn associated_function_group_returns_struct_with_group_set_as_expected() {
+        for expected in [Some(AttrValue::from("Food")), None] {
+            let test = SelectOption::default().group(expected.clone());
+            assert_eq!(test.group, expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn text_is_prefixed_with_group() {
+        let tests = vec![
+            (SelectOption::from("Groceries"), "Groceries"),
+            (
+                SelectOption::from("Groceries")
+                    .group(Some(AttrValue::from("Food"))),
+                "Food › Groceries",
+            ),
+        ];
+
+        for (option, expected) in tests {
+            assert_eq!(option.text(), expected);
+        }
+    }
+
     #[wasm_bindgen_test]
     fn constructor_from_exists_for_str_borrow() {
         let input: &str = "test";
@@ -170,4 +244,33 @@ mod test {
         let test = SelectOption::from(input);
         assert_eq!(test, expected);
     }
+
+    #[wasm_bindgen_test]
+    fn constructor_from_exists_for_period() {
+        let tests = vec![
+            (Period::Month(2023, Month::June), "2023-06", "June 2023"),
+            (Period::Year(2023), "2023", "2023"),
+        ];
+
+        for (input, value, inner_html) in tests {
+            let expected = SelectOption {
+                value: AttrValue::from(value),
+                inner_html: AttrValue::from(inner_html),
+                ..Default::default()
+            };
+            assert_eq!(SelectOption::from(input), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn constructor_from_exists_for_currency() {
+        let input: Currency = Currency::Jpy;
+        let expected = SelectOption {
+            value: AttrValue::from("JPY"),
+            inner_html: AttrValue::from("JPY"),
+            ..Default::default()
+        };
+        let test = SelectOption::from(input);
+        assert_eq!(test, expected);
+    }
 }
@@ -0,0 +1,20 @@
+use chrono_tz::Tz;
+
+use crate::types::currency::Currency;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Settings {
+    pub(crate) default_currency: Currency,
+    pub(crate) home_currency: Currency,
+    pub(crate) timezone: Tz,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            default_currency: Currency::default(),
+            home_currency: Currency::default(),
+            timezone: Tz::Europe__Helsinki,
+        }
+    }
+}
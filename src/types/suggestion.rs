@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use chrono::{
+    DateTime,
+    FixedOffset,
+};
+use yew::AttrValue;
+
+use crate::types::{
+    entry::Entry,
+    money::Money,
+    select::SelectOption,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Suggestion {
+    pub(crate) description: String,
+    pub(crate) category: String,
+    pub(crate) amount: Money,
+    pub(crate) count: usize,
+    pub(crate) last_used: DateTime<FixedOffset>,
+}
+
+impl From<&Suggestion> for SelectOption {
+    fn from(value: &Suggestion) -> Self {
+        Self {
+            value: AttrValue::from(value.description.clone()),
+            inner_html: AttrValue::from(value.category.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+fn normalize(description: &str) -> String {
+    description.trim().to_lowercase()
+}
+
+pub(crate) fn description_suggestions(entries: &[Entry]) -> Vec<Suggestion> {
+    let mut suggestions: HashMap<String, Suggestion> = HashMap::new();
+    for entry in entries {
+        let key = normalize(&entry.description);
+        if key.is_empty() {
+            continue;
+        }
+        let latest = Suggestion {
+            description: entry.description.trim().to_owned(),
+            category: entry.category.clone(),
+            amount: entry.amount,
+            count: 1,
+            last_used: entry.datetime,
+        };
+        match suggestions.get_mut(&key) {
+            Some(suggestion) if suggestion.last_used <= entry.datetime => {
+                *suggestion = Suggestion {
+                    count: suggestion.count + 1,
+                    ..latest
+                };
+            },
+            Some(suggestion) => suggestion.count += 1,
+            None => {
+                suggestions.insert(key, latest);
+            },
+        }
+    }
+
+    let mut suggestions: Vec<Suggestion> = suggestions.into_values().collect();
+    suggestions.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then(b.last_used.cmp(&a.last_used))
+            .then(a.description.cmp(&b.description))
+    });
+    suggestions
+}
+
+pub(crate) fn find_suggestion<'a>(
+    suggestions: &'a [Suggestion],
+    description: &str,
+) -> Option<&'a Suggestion> {
+    let key = normalize(description);
+    suggestions
+        .iter()
+        .find(|suggestion| normalize(&suggestion.description) == key)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{
+        FixedOffset,
+        TimeZone,
+    };
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        description_suggestions,
+        find_suggestion,
+    };
+    use crate::types::{
+        currency::Currency,
+        entry::Entry,
+        money::Money,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn make_entry(day: u32, description: &str, category: &str) -> Entry {
+        Entry::fixture(
+            FixedOffset::east_opt(0)
+                .unwrap()
+                .with_ymd_and_hms(2023, 6, day, 12, 0, 0)
+                .unwrap(),
+            Money::new(day as i64 * 100, Currency::Eur),
+        )
+        .with_category(category)
+        .with_description(description)
+    }
+
+    #[wasm_bindgen_test]
+    fn suggestions_are_ranked_by_frequency_then_recency() {
+        let entries = vec![
+            make_entry(1, "Lidl", "Food"),
+            make_entry(2, "HSL monthly ticket", "Transport"),
+            make_entry(3, "lidl ", "Food › Groceries"),
+            make_entry(4, "Cinema", "Leisure"),
+            make_entry(5, "", "Rent"),
+            make_entry(6, "Pharmacy", "Health"),
+        ];
+        let suggestions = description_suggestions(&entries);
+
+        let descriptions: Vec<(&str, usize)> = suggestions
+            .iter()
+            .map(|s| (s.description.as_str(), s.count))
+            .collect();
+
+        assert_eq!(
+            descriptions,
+            vec![
+                ("lidl", 2),
+                ("Pharmacy", 1),
+                ("Cinema", 1),
+                ("HSL monthly ticket", 1),
+            ]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn suggestions_keep_category_and_amount_last_used() {
+        let entries = vec![
+            make_entry(9, "Lidl", "Food › Groceries"),
+            make_entry(3, "Lidl", "Food"),
+        ];
+        let suggestions = description_suggestions(&entries);
+
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].category, "Food › Groceries");
+        assert_eq!(suggestions[0].amount, Money::new(900, Currency::Eur));
+        assert_eq!(suggestions[0].count, 2);
+    }
+
+    #[wasm_bindgen_test]
+    fn find_suggestion_ignores_case_and_whitespace() {
+        let entries = vec![make_entry(1, "Lidl", "Food")];
+        let suggestions = description_suggestions(&entries);
+        let tests = vec![
+            ("Lidl", true),
+            (" LIDL ", true),
+            ("Lid", false),
+            ("", false),
+        ];
+
+        for (description, expected) in tests {
+            assert_eq!(
+                find_suggestion(&suggestions, description).is_some(),
+                expected,
+                "{}",
+                description
+            );
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use std::{
+    cmp::Reverse,
+    collections::BTreeMap,
+};
+
+use chrono::{
+    NaiveDate,
+    TimeZone,
+};
+
+use crate::types::{
+    category::{
+        totals_by_category,
+        PATH_SEPARATOR,
+    },
+    currency::Currency,
+    datetime::DateTimeRange,
+    entry::{
+        total_in,
+        Entry,
+        EntryKind,
+    },
+    exchange_rate::ExchangeRate,
+    money::Money,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Summary {
+    pub(crate) spending: Money,
+    pub(crate) income: Money,
+}
+
+impl Summary {
+    pub(crate) fn balance(&self) -> Option<Money> {
+        self.income.checked_sub(self.spending)
+    }
+}
+
+pub(crate) fn entries_in<T: TimeZone>(
+    entries: &[Entry],
+    range: &DateTimeRange<T>,
+) -> Vec<Entry> {
+    entries
+        .iter()
+        .filter(|entry| range.contains(&entry.datetime))
+        .cloned()
+        .collect()
+}
+
+pub(crate) fn summarize(
+    entries: &[Entry],
+    currency: Currency,
+    rates: &[ExchangeRate],
+) -> Option<Summary> {
+    let (income, spending): (Vec<Entry>, Vec<Entry>) = entries
+        .iter()
+        .filter(|entry| !entry.kind.is_internal())
+        .cloned()
+        .partition(|entry| entry.kind == EntryKind::Income);
+    Some(Summary {
+        spending: total_in(&spending, currency, rates)?,
+        income: total_in(&income, currency, rates)?,
+    })
+}
+
+pub(crate) fn top_categories(
+    entries: &[Entry],
+    currency: Currency,
+    rates: &[ExchangeRate],
+    count: usize,
+) -> Option<Vec<(String, Money)>> {
+    let mut totals: Vec<(String, Money)> =
+        totals_by_category(entries, currency, rates)?
+            .into_iter()
+            .filter(|(path, _)| !path.contains(PATH_SEPARATOR))
+            .collect();
+    totals.sort_by(|a, b| b.1.minor.cmp(&a.1.minor).then(a.0.cmp(&b.0)));
+    totals.truncate(count);
+    Some(totals)
+}
+
+pub(crate) fn latest_entries(entries: &[Entry], count: usize) -> Vec<&Entry> {
+    let mut latest: Vec<&Entry> = entries.iter().collect();
+    latest.sort_by_key(|entry| Reverse(entry.datetime));
+    latest.truncate(count);
+    latest
+}
+
+pub(crate) fn running_balance<T: TimeZone>(
+    entries: &[Entry],
+    currency: Currency,
+    rates: &[ExchangeRate],
+    timezone: &T,
+) -> Option<Vec<(NaiveDate, Money)>> {
+    let mut daily: BTreeMap<NaiveDate, Money> = BTreeMap::new();
+    for entry in entries {
+        let amount = entry.amount_in(currency, rates)?;
+        let net = match entry.kind {
+            EntryKind::Income => amount,
+            EntryKind::Expense => amount.checked_neg()?,
+            EntryKind::Transfer { .. } | EntryKind::Contribution { .. } => {
+                continue
+            },
+        };
+        let date = entry.datetime.with_timezone(timezone).date_naive();
+        let total = daily.entry(date).or_insert(Money::zero(currency));
+        *total = total.checked_add(net)?;
+    }
+
+    let mut balance = Money::zero(currency);
+    daily
+        .into_iter()
+        .map(|(date, net)| {
+            balance = balance.checked_add(net)?;
+            Some((date, balance))
+        })
+        .collect()
+}
+
+pub(crate) fn percent_change(current: Money, previous: Money) -> Option<i64> {
+    if previous.currency != current.currency || previous.minor == 0 {
+        return None;
+    }
+    let difference = i128::from(current.minor) - i128::from(previous.minor);
+    let percent = difference * 100 / i128::from(previous.minor).abs();
+    i64::try_from(percent).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{
+        Datelike,
+        TimeZone,
+        Utc,
+    };
+    use chrono_tz::Tz;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        entries_in,
+        latest_entries,
+        percent_change,
+        running_balance,
+        summarize,
+        top_categories,
+        Summary,
+    };
+    use crate::types::{
+        currency::Currency,
+        datetime::{
+            DateTimeRange,
+            Month,
+        },
+        entry::{
+            Entry,
+            EntryKind,
+        },
+        money::{
+            eur,
+            Money,
+        },
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn make_entry(
+        (month, day, hour): (u32, u32, u32),
+        kind: EntryKind,
+        category: &str,
+        minor: i64,
+    ) -> Entry {
+        Entry::fixture(
+            Utc.with_ymd_and_hms(2023, month, day, hour, 0, 0)
+                .unwrap()
+                .fixed_offset(),
+            eur(minor),
+        )
+        .with_account("Checking")
+        .with_kind(kind)
+        .with_category(category)
+    }
+
+    fn make_entries() -> Vec<Entry> {
+        let transfer = EntryKind::Transfer {
+            to_account: String::from("Savings"),
+        };
+        vec![
+            make_entry((6, 1, 12), EntryKind::Income, "Salary", 300000),
+            make_entry((6, 2, 12), EntryKind::Expense, "Rent", 90000),
+            make_entry((6, 3, 12), EntryKind::Expense, "Food › Lunch", 1200),
+            make_entry((6, 4, 12), EntryKind::Expense, "Food", 4500),
+            make_entry((6, 5, 12), EntryKind::Expense, "Transport", 6400),
+            make_entry((6, 6, 12), transfer, "", 50000),
+        ]
+    }
+
+    #[wasm_bindgen_test]
+    fn entries_in_uses_range_in_timezone() {
+        let timezone = Tz::Europe__Helsinki;
+        let range = DateTimeRange::month(&timezone, 2023, Month::July).unwrap();
+        let entries = vec![
+            // 2023-07-01 01:00 in Helsinki
+            make_entry((6, 30, 22), EntryKind::Expense, "Food", 100),
+            // 2023-06-30 23:00 in Helsinki
+            make_entry((6, 30, 20), EntryKind::Expense, "Food", 200),
+        ];
+
+        let amounts: Vec<Money> = entries_in(&entries, &range)
+            .iter()
+            .map(|entry| entry.amount)
+            .collect();
+
+        assert_eq!(amounts, vec![eur(100)]);
+    }
+
+    #[wasm_bindgen_test]
+    fn summarize_separates_income_and_skips_transfers() {
+        let summary = summarize(&make_entries(), Currency::Eur, &[]);
+
+        assert_eq!(
+            summary,
+            Some(Summary {
+                spending: eur(102100),
+                income: eur(300000),
+            })
+        );
+        assert_eq!(summary.unwrap().balance(), Some(eur(197900)));
+        assert_eq!(summarize(&make_entries(), Currency::Usd, &[]), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn top_categories_are_top_level_by_amount() {
+        let tests = vec![
+            (
+                3,
+                vec![
+                    ("Rent", eur(90000)),
+                    ("Transport", eur(6400)),
+                    ("Food", eur(5700)),
+                ],
+            ),
+            (1, vec![("Rent", eur(90000))]),
+        ];
+
+        for (count, expected) in tests {
+            let top =
+                top_categories(&make_entries(), Currency::Eur, &[], count)
+                    .unwrap();
+            let top: Vec<(&str, Money)> = top
+                .iter()
+                .map(|(category, money)| (category.as_str(), *money))
+                .collect();
+            assert_eq!(top, expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn latest_entries_are_newest_first() {
+        let entries = make_entries();
+        let latest: Vec<u32> = latest_entries(&entries, 2)
+            .iter()
+            .map(|entry| entry.datetime.day())
+            .collect();
+
+        assert_eq!(latest, vec![6, 5]);
+    }
+
+    #[wasm_bindgen_test]
+    fn running_balance_accumulates_daily_net_in_timezone() {
+        let timezone = Tz::Europe__Helsinki;
+        let mut entries = make_entries();
+        // 2023-06-03 01:00 in Helsinki
+        entries.push(make_entry((6, 2, 22), EntryKind::Expense, "Food", 100));
+
+        let balance: Vec<(u32, Money)> =
+            running_balance(&entries, Currency::Eur, &[], &timezone)
+                .unwrap()
+                .into_iter()
+                .map(|(date, money)| (date.day(), money))
+                .collect();
+
+        assert_eq!(
+            balance,
+            vec![
+                (1, eur(300000)),
+                (2, eur(210000)),
+                (3, eur(208700)),
+                (4, eur(204200)),
+                (5, eur(197800)),
+            ]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn percent_change_is_relative_to_previous() {
+        let tests = vec![
+            (eur(11000), eur(10000), Some(10)),
+            (eur(5000), eur(10000), Some(-50)),
+            (eur(10000), eur(10000), Some(0)),
+            (eur(10000), eur(0), None),
+            (eur(-500), eur(-1000), Some(50)),
+            (eur(100), Money::new(100, Currency::Usd), None),
+        ];
+
+        for (current, previous, expected) in tests {
+            assert_eq!(percent_change(current, previous), expected);
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use chrono::{
+    DateTime,
+    TimeZone,
+};
+
+use crate::types::datetime::DateTimeRange;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Rule {
+    Required,
+    MinLength(usize),
+    MaxLength(usize),
+    PositiveAmount,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ValidationError {
+    Required,
+    TooShort(usize),
+    TooLong(usize),
+    NotPositive,
+    OutOfRange,
+    InvalidPattern,
+    InvalidRange,
+    SplitMismatch,
+    SameAccount,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Required => write!(f, "This field is required"),
+            Self::TooShort(min) => {
+                write!(f, "Must be at least {} characters", min)
+            },
+            Self::TooLong(max) => {
+                write!(f, "Must be at most {} characters", max)
+            },
+            Self::NotPositive => write!(f, "Must be greater than zero"),
+            Self::OutOfRange => write!(f, "Must be within the allowed dates"),
+            Self::InvalidPattern => {
+                write!(f, "Must be a valid regular expression")
+            },
+            Self::InvalidRange => {
+                write!(f, "Must not be less than the minimum")
+            },
+            Self::SplitMismatch => write!(f, "Lines must add up to the total"),
+            Self::SameAccount => write!(f, "Must be a different account"),
+        }
+    }
+}
+
+pub(crate) fn validate_text(
+    value: &str,
+    rules: &[Rule],
+) -> Result<(), ValidationError> {
+    let length = value.trim().chars().count();
+    for rule in rules {
+        match rule {
+            Rule::Required if length == 0 => {
+                return Err(ValidationError::Required)
+            },
+            Rule::MinLength(min) if length > 0 && length < *min => {
+                return Err(ValidationError::TooShort(*min))
+            },
+            Rule::MaxLength(max) if value.chars().count() > *max => {
+                return Err(ValidationError::TooLong(*max))
+            },
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_amount(
+    value: Option<i64>,
+    rules: &[Rule],
+) -> Result<(), ValidationError> {
+    for rule in rules {
+        match (rule, value) {
+            (Rule::Required, None) => return Err(ValidationError::Required),
+            (Rule::PositiveAmount, Some(minor)) if minor <= 0 => {
+                return Err(ValidationError::NotPositive)
+            },
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_split(
+    total: Option<i64>,
+    lines: &[Option<i64>],
+) -> Result<(), ValidationError> {
+    let lines = lines
+        .iter()
+        .copied()
+        .collect::<Option<Vec<i64>>>()
+        .ok_or(ValidationError::Required)?;
+    let total = total.ok_or(ValidationError::Required)?;
+    let sum = lines
+        .iter()
+        .try_fold(0_i64, |sum, &minor| sum.checked_add(minor));
+    match sum {
+        Some(sum) if sum == total => Ok(()),
+        _ => Err(ValidationError::SplitMismatch),
+    }
+}
+
+pub(crate) fn validate_datetime<T: TimeZone>(
+    value: Option<&DateTime<T>>,
+    range: &DateTimeRange<T>,
+) -> Result<(), ValidationError> {
+    match value {
+        Some(datetime) if range.contains(datetime) => Ok(()),
+        Some(_) => Err(ValidationError::OutOfRange),
+        None => Err(ValidationError::Required),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{
+        TimeZone,
+        Utc,
+    };
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        validate_amount,
+        validate_datetime,
+        validate_split,
+        validate_text,
+        Rule,
+        ValidationError,
+    };
+    use crate::types::datetime::DateTimeRange;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn validate_text_works() {
+        let rules = [Rule::Required, Rule::MinLength(3), Rule::MaxLength(5)];
+        let tests = vec![
+            ("", Err(ValidationError::Required)),
+            ("   ", Err(ValidationError::Required)),
+            ("ab", Err(ValidationError::TooShort(3))),
+            ("abc", Ok(())),
+            ("äöåäö", Ok(())),
+            ("abcdef", Err(ValidationError::TooLong(5))),
+        ];
+
+        for (value, expected) in tests {
+            assert_eq!(validate_text(value, &rules), expected, "{}", value);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_text_allows_empty_optional_field() {
+        let rules = [Rule::MinLength(3), Rule::MaxLength(5)];
+
+        assert_eq!(validate_text("", &rules), Ok(()));
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_amount_works() {
+        let rules = [Rule::Required, Rule::PositiveAmount];
+        let tests = vec![
+            (None, Err(ValidationError::Required)),
+            (Some(0), Err(ValidationError::NotPositive)),
+            (Some(-100), Err(ValidationError::NotPositive)),
+            (Some(1), Ok(())),
+        ];
+
+        for (value, expected) in tests {
+            assert_eq!(validate_amount(value, &rules), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_split_works() {
+        let tests = vec![
+            (Some(1000), vec![Some(600), Some(400)], Ok(())),
+            (Some(1000), vec![Some(333), Some(333), Some(334)], Ok(())),
+            (
+                Some(1000),
+                vec![Some(600), Some(399)],
+                Err(ValidationError::SplitMismatch),
+            ),
+            (Some(1000), vec![Some(600), None], Err(ValidationError::Required)),
+            (None, vec![Some(600)], Err(ValidationError::Required)),
+            (
+                Some(-1),
+                vec![Some(i64::MAX), Some(i64::MAX)],
+                Err(ValidationError::SplitMismatch),
+            ),
+        ];
+
+        for (total, lines, expected) in tests {
+            assert_eq!(validate_split(total, &lines), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_datetime_works() {
+        let date = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 12, 0, 0).unwrap();
+        let range = DateTimeRange::from(date(2020, 1, 1), date(2020, 12, 31));
+        let tests = vec![
+            (None, Err(ValidationError::Required)),
+            (Some(date(2019, 12, 31)), Err(ValidationError::OutOfRange)),
+            (Some(date(2020, 6, 1)), Ok(())),
+            (Some(date(2021, 1, 1)), Err(ValidationError::OutOfRange)),
+        ];
+
+        for (value, expected) in tests {
+            assert_eq!(validate_datetime(value.as_ref(), &range), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn validation_error_has_message() {
+        let tests = vec![
+            (ValidationError::Required, "This field is required"),
+            (ValidationError::TooShort(3), "Must be at least 3 characters"),
+            (ValidationError::TooLong(5), "Must be at most 5 characters"),
+            (ValidationError::NotPositive, "Must be greater than zero"),
+            (ValidationError::OutOfRange, "Must be within the allowed dates"),
+            (
+                ValidationError::InvalidPattern,
+                "Must be a valid regular expression",
+            ),
+            (
+                ValidationError::InvalidRange,
+                "Must not be less than the minimum",
+            ),
+            (ValidationError::SplitMismatch, "Lines must add up to the total"),
+            (ValidationError::SameAccount, "Must be a different account"),
+        ];
+
+        for (error, expected) in tests {
+            assert_eq!(error.to_string(), expected);
+        }
+    }
+}